@@ -20,7 +20,8 @@ pub struct XmlAttribute {
     /// Flatten child fields
     #[serde(default)]
     pub flatten: bool,
-    /// Declare all namespaces with prefix/URL
+    /// Declare all namespaces with prefix/URL. On a field, only used to resolve the
+    /// field's own prefix
     #[serde(default)]
     pub namespaces: BTreeMap<String, String>,
     /// Set the prefix for the scope
@@ -43,6 +44,12 @@ pub struct XmlAttribute {
     /// Set the field as an XML CDATA content
     #[serde(default)]
     pub cdata: bool,
+    /// Collect unknown attributes and child elements into the field
+    #[serde(default)]
+    pub extras: bool,
+    /// Capture values that match no other variant of the enum as a string
+    #[serde(default)]
+    pub other: bool,
 }
 
 impl TryFrom<&Attribute> for XmlAttribute {
@@ -133,7 +140,7 @@ impl XmlAttribute {
               bad_namespace => {
                 let msg =
                   ::std::format!("bad namespace for {}, found {}", #element_name, bad_namespace);
-                return Err(msg);
+                return Err(reader.error(crate::error::ErrorKind::InvalidValue, msg));
               }
             }
           }
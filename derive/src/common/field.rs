@@ -36,6 +36,10 @@ impl XmlField {
         self.attributes.flatten
     }
 
+    pub fn is_extras(&self) -> bool {
+        self.attributes.extras
+    }
+
     pub fn label(&self) -> Option<Ident> {
         self.syn_field.ident.clone()
     }
@@ -137,10 +141,13 @@ impl XmlField {
             .map(|skip_serializing_if| Ident::new(skip_serializing_if, self.get_span()))
     }
 
+    /// The namespace URL of the field's prefix, declared on the field itself or on
+    /// the struct that contains it.
     pub fn prefix_namespace(&self, root_attributes: &XmlAttribute) -> String {
-        root_attributes
+        self.attributes
             .namespaces
             .iter()
+            .chain(root_attributes.namespaces.iter())
             .find_map(|(prefix, namespace)| {
                 if self.attributes.prefix.as_deref().eq(&Some(prefix)) {
                     Some(namespace.clone())
@@ -151,6 +158,29 @@ impl XmlField {
             .unwrap_or_default()
     }
 
+    /// The namespace URL an attribute field is read from, as an `Option<&str>`
+    /// expression comparable with `attr.name.namespace.as_deref()`. Unprefixed
+    /// attributes have no namespace; the well-known Android prefixes fall back to
+    /// their standard URLs when the struct doesn't declare them.
+    pub fn attribute_namespace(&self, root_attributes: &XmlAttribute) -> TokenStream {
+        let Some(prefix) = self.attributes.prefix.as_deref() else {
+            return quote!(::std::option::Option::None);
+        };
+        let namespace = self.prefix_namespace(root_attributes);
+        let namespace = if namespace.is_empty() {
+            match prefix {
+                "android" => "http://schemas.android.com/apk/res/android",
+                "tools" => "http://schemas.android.com/tools",
+                "dist" => "http://schemas.android.com/apk/distribution",
+                prefix => panic!("namespace of prefix {prefix:?} is not declared"),
+            }
+            .to_string()
+        } else {
+            namespace
+        };
+        quote!(::std::option::Option::Some(#namespace))
+    }
+
     pub fn get_namespace_matching(
         &self,
         root_attributes: &XmlAttribute,
@@ -20,9 +20,68 @@ pub fn parse(
     let match_to_enum: TokenStream = data_enum
         .variants
         .iter()
+        .filter(|variant| !XmlAttribute::from(&variant.attrs).other)
         .filter_map(|variant| parse_variant(variant, name))
         .collect();
 
+    let other_variant = data_enum
+        .variants
+        .iter()
+        .find(|variant| XmlAttribute::from(&variant.attrs).other);
+
+    let unknown_values = other_variant.map(|variant| {
+        let label = &variant.ident;
+        quote! {
+          fn unknown_values(&self) -> ::std::vec::Vec<&str> {
+            match self {
+              #name::#label(value) => ::std::vec![value.as_str()],
+              _ => ::std::vec::Vec::new(),
+            }
+          }
+        }
+    });
+
+    let match_other = match other_variant {
+        Some(variant) => {
+            let label = &variant.ident;
+            quote! {
+              other => {
+                enum_value = ::std::option::Option::Some(#name::#label(other.to_string()));
+                break;
+              }
+            }
+        }
+        None => quote! {
+          _ => {
+            return ::std::result::Result::Err(reader.error(
+              crate::error::ErrorKind::InvalidValue,
+              ::std::format!("unknown {} value {:?}", stringify!(#name), content),
+            ));
+          }
+        },
+    };
+
+    // Enums with a `#[default]` variant fall back to it when no value is
+    // given; the others, such as flags without a meaningful default, reject it.
+    let has_default = data_enum.variants.iter().any(|variant| {
+        variant
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("default"))
+    });
+    let finish = if has_default {
+        quote! {
+          ::std::result::Result::Ok(enum_value.unwrap_or(<#name as ::std::default::Default>::default()))
+        }
+    } else {
+        quote! {
+          enum_value.ok_or_else(|| reader.error(
+            crate::error::ErrorKind::InvalidValue,
+            ::std::format!("missing {} value", stringify!(#name)),
+          ))
+        }
+    };
+
     let flatten = root_attributes.flatten;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -45,7 +104,7 @@ pub fn parse(
         #[allow(unused_variables)]
         fn deserialize<R: ::std::io::Read>(
           reader: &mut crate::xml::de::Deserializer<R>,
-        ) -> ::std::result::Result<Self, ::std::string::String> {
+        ) -> ::std::result::Result<Self, crate::error::DeserializeError> {
           let (named_element, enum_namespace) =
             if let crate::xml::__xml::reader::XmlEvent::StartElement{ name, .. } = reader.peek()?.to_owned() {
               (name.local_name.to_owned(), name.namespace.clone())
@@ -76,7 +135,7 @@ pub fn parse(
                 if let crate::xml::__xml::reader::XmlEvent::Characters(content) = reader.peek()?.to_owned() {
                   match content.as_str() {
                     #match_to_enum
-                    _ => {}
+                    #match_other
                   }
                 }
               }
@@ -94,19 +153,25 @@ pub fn parse(
                   break;
                 }
 
-                return ::std::result::Result::Err(
-                  ::std::format!("End of document, missing some content ?"),
-                );
+                return ::std::result::Result::Err(reader.error(
+                  crate::error::ErrorKind::Syntax,
+                  "End of document, missing some content ?",
+                ));
               }
               event => {
-                return ::std::result::Result::Err(::std::format!("unknown event {:?}", event))
+                return ::std::result::Result::Err(reader.error(
+                  crate::error::ErrorKind::Syntax,
+                  ::std::format!("unknown event {:?}", event),
+                ))
               }
             }
           }
 
           crate::xml::__derive_debug!("Enum {} @ {}: success", stringify!(#name), start_depth);
-          ::std::result::Result::Ok(enum_value.unwrap_or(<#name as ::std::default::Default>::default()))
+          #finish
         }
+
+        #unknown_values
       }
     }
 }
@@ -205,7 +270,7 @@ fn build_unnamed_field_visitors(fields: &syn::FieldsUnnamed) -> TokenStream {
             &quote! {
               let content = "<".to_string() + #struct_id + ">" + v + "</" + #struct_id + ">";
               let value: ::std::result::Result<#struct_name, ::std::string::String> =
-                crate::xml::de::from_str(&content);
+                crate::xml::de::from_str(&content).map_err(|error| error.message);
               value
             },
           ))
@@ -243,16 +308,16 @@ fn build_unnamed_visitor_calls(
 
           let result = reader.read_inner_value::<#field_type, _>(|reader| {
             if let crate::xml::__xml::reader::XmlEvent::EndElement { .. } = *reader.peek()? {
-              return visitor.#visitor("");
+              return visitor.#visitor("").map_err(::std::convert::Into::into);
             }
 
             if let ::std::result::Result::Ok(crate::xml::__xml::reader::XmlEvent::Characters(s))
               = reader.next_event()
             {
-              visitor.#visitor(&s)
+              visitor.#visitor(&s).map_err(::std::convert::Into::into)
             } else {
               ::std::result::Result::Err(
-                ::std::format!("unable to parse content for {}", #label_name),
+                ::std::format!("unable to parse content for {}", #label_name).into(),
               )
             }
           });
@@ -289,7 +354,7 @@ fn build_unnamed_visitor_calls(
             #variant_name(ref mut v) => v.push(value),
             _ => {
               return ::std::result::Result::Err(
-                ::std::string::String::from("Got sequence of different types"),
+                ::std::string::String::from("Got sequence of different types").into(),
               );
             }
           }
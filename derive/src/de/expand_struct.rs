@@ -23,6 +23,7 @@ pub fn parse(
         .fields
         .iter()
         .map(|field| XmlField::new(field.clone()))
+        .filter(|field| !field.is_extras())
         .filter_map(|field| match field.get_type() {
             Field::FieldStruct { struct_name } => {
                 build_default_value(&field, Some(quote!(#struct_name)))
@@ -94,7 +95,14 @@ pub fn parse(
 
                 Some(quote! {
                   #[allow(non_snake_case, non_camel_case_types)]
-                  struct #visitor_label;
+                  struct #visitor_label {
+                    options: crate::ParseOptions,
+                  }
+                  impl #visitor_label {
+                    fn new(options: &crate::ParseOptions) -> Self {
+                      #visitor_label { options: options.clone() }
+                    }
+                  }
                   impl<'de> crate::xml::Visitor<'de> for #visitor_label {
                     type Value = #struct_name;
 
@@ -104,7 +112,8 @@ pub fn parse(
                     ) -> ::std::result::Result<Self::Value, ::std::string::String> {
                       let value = crate::xml::__xml::escape::escape_str_pcdata(v);
                       let content = format!("{}{}{}", #xml_opening, value, #xml_closing);
-                      crate::xml::de::from_str(&content)
+                      crate::xml::de::from_str_with_options(&content, &self.options)
+                        .map_err(|error| error.message)
                     }
                   }
                 })
@@ -128,6 +137,11 @@ pub fn parse(
                 Some(quote! {
                   #[allow(non_snake_case, non_camel_case_types)]
                   struct #visitor_label;
+                  impl #visitor_label {
+                    fn new(_options: &crate::ParseOptions) -> Self {
+                      #visitor_label
+                    }
+                  }
                   impl<'de> crate::xml::Visitor<'de> for #visitor_label {
                     type Value = #field_type;
 
@@ -167,14 +181,15 @@ pub fn parse(
     .fields
     .iter()
     .map(|field| XmlField::new(field.clone()))
-    .filter(|field| !field.is_attribute() && !field.is_flatten())
+    .filter(|field| !field.is_attribute() && !field.is_flatten() && !field.is_extras())
     .filter_map(|field| {
       let value_label = field.get_value_label();
       let label_name = field.renamed_label_without_namespace();
 
       let namespace = field.prefix_namespace(root_attributes);
 
-      let visit_struct = |struct_name: syn::Path, action: TokenStream| {
+      let visit_struct = |struct_name: syn::Path, action: TokenStream, repeated: bool| {
+        let mark_repeated = repeated.then(|| quote!(reader.set_next_repeated();));
         Some(quote! {
           (#namespace, #label_name) => {
             if depth == 0 {
@@ -183,6 +198,7 @@ pub fn parse(
             }
             if let Ok(crate::xml::__xml::reader::XmlEvent::StartElement { .. }) = reader.peek() {
               // If substruct's start element found then deserialize substruct
+              #mark_repeated
               let value = <#struct_name as crate::xml::XmlDeserialize>::deserialize(reader)?;
               #value_label #action;
               // read EndElement
@@ -204,15 +220,15 @@ pub fn parse(
         )
       };
 
-      let visit_sub = |sub_type: Box<Field>, action: TokenStream| match *sub_type {
+      let visit_sub = |sub_type: Box<Field>, action: TokenStream, repeated: bool| match *sub_type {
         Field::FieldOption { .. } | Field::FieldVec { .. } => unimplemented!(),
-        Field::FieldStruct { struct_name } => visit_struct(struct_name, action),
+        Field::FieldStruct { struct_name } => visit_struct(struct_name, action, repeated),
         simple_type => visit_simple(simple_type, action),
       };
 
       match field.get_type() {
         Field::FieldStruct { struct_name } => {
-          visit_struct(struct_name, quote! { = ::std::option::Option::Some(value) })
+          visit_struct(struct_name, quote! { = ::std::option::Option::Some(value) }, false)
         }
         Field::FieldOption { data_type } => match *data_type {
           Field::FieldVec {
@@ -230,6 +246,7 @@ pub fn parse(
                     Some(vec![value])
                   }
                 },
+                true,
               )
             }
             simple_type => {
@@ -247,9 +264,9 @@ pub fn parse(
               )
             }
           },
-          _ => visit_sub(data_type, quote! { = ::std::option::Option::Some(value) }),
+          _ => visit_sub(data_type, quote! { = ::std::option::Option::Some(value) }, false),
         },
-        Field::FieldVec { data_type } => visit_sub(data_type, quote! { .push(value) }),
+        Field::FieldVec { data_type } => visit_sub(data_type, quote! { .push(value) }, true),
         simple_type => visit_simple(simple_type, quote! { = ::std::option::Option::Some(value) }),
       }
     })
@@ -290,27 +307,56 @@ pub fn parse(
         .filter_map(|field| {
             let label = field.get_value_label();
             let label_name = field.renamed_label_without_namespace();
+            let namespace = field.attribute_namespace(root_attributes);
             let visitor_label = field.get_visitor_ident(None);
 
-            let visit = |action: &TokenStream, visitor: &Ident, visitor_label: &Ident| {
+            // Values parsed by a struct visitor may hold enum values the model
+            // doesn't recognise; simple types never do.
+            let check_unknown = quote! {
+              for unknown in crate::xml::XmlDeserialize::unknown_values(&value) {
+                reader.check_unknown_value(&attr.name, unknown)?;
+              }
+            };
+
+            let visit = |action: &TokenStream,
+                         visitor: &Ident,
+                         visitor_label: &Ident,
+                         check: &TokenStream| {
                 Some(quote! {
                   for attr in attributes {
-                    if attr.name.local_name == #label_name {
-                      let visitor = #visitor_label{};
-                      let value = visitor.#visitor(&attr.value)?;
+                    if attr.name.local_name == #label_name && attr.name.namespace.as_deref() == #namespace {
+                      let visitor = #visitor_label::new(reader.options());
+                      let value = match visitor.#visitor(&attr.value) {
+                        ::std::result::Result::Ok(value) => value,
+                        ::std::result::Result::Err(error) => {
+                          reader.recover(reader.attribute_error(&attr.name, &attr.value, error))?;
+                          continue;
+                        }
+                      };
+                      #check
                       #label #action;
                     }
                   }
                 })
             };
 
-            let visit_vec = |action: &TokenStream, visitor: &Ident, visitor_label: &Ident| {
+            let visit_vec = |action: &TokenStream,
+                             visitor: &Ident,
+                             visitor_label: &Ident,
+                             check: &TokenStream| {
                 Some(quote! {
                   for attr in attributes {
-                    if attr.name.local_name == #label_name {
+                    if attr.name.local_name == #label_name && attr.name.namespace.as_deref() == #namespace {
                       for value in attr.value.split_whitespace() {
-                        let visitor = #visitor_label{};
-                        let value = visitor.#visitor(value)?;
+                        let visitor = #visitor_label::new(reader.options());
+                        let value = match visitor.#visitor(value) {
+                          ::std::result::Result::Ok(value) => value,
+                          ::std::result::Result::Err(error) => {
+                            reader.recover(reader.attribute_error(&attr.name, value, error))?;
+                            continue;
+                          }
+                        };
+                        #check
                         #label #action;
                       }
                     }
@@ -318,16 +364,23 @@ pub fn parse(
                 })
             };
 
-            let visit_option_vec = |visitor: &Ident, visitor_label: &Ident| {
+            let visit_option_vec = |visitor: &Ident, visitor_label: &Ident, check: &TokenStream| {
                 Some(quote! {
                   for attr in attributes {
-                    if attr.name.local_name == #label_name {
+                    if attr.name.local_name == #label_name && attr.name.namespace.as_deref() == #namespace {
                       if #label.is_none() {
                         #label = Some(Vec::new());
                       }
                       for value in attr.value.split_whitespace() {
-                        let visitor = #visitor_label{};
-                        let value = visitor.#visitor(value)?;
+                        let visitor = #visitor_label::new(reader.options());
+                        let value = match visitor.#visitor(value) {
+                          ::std::result::Result::Ok(value) => value,
+                          ::std::result::Result::Err(error) => {
+                            reader.recover(reader.attribute_error(&attr.name, value, error))?;
+                            continue;
+                          }
+                        };
+                        #check
                         #label.as_mut().unwrap().push(value);
                       }
                     }
@@ -338,7 +391,7 @@ pub fn parse(
             let visit_string = || {
                 Some(quote! {
                   for attr in attributes {
-                    if attr.name.local_name == #label_name {
+                    if attr.name.local_name == #label_name && attr.name.namespace.as_deref() == #namespace {
                       #label = Some(attr.value.to_owned());
                     }
                   }
@@ -350,6 +403,7 @@ pub fn parse(
                     &action,
                     &Ident::new("visit_str", Span::call_site()),
                     &field.get_visitor_ident(Some(&struct_name)),
+                    &check_unknown,
                 )
             };
 
@@ -358,6 +412,7 @@ pub fn parse(
                     &action,
                     &simple_type.get_simple_type_visitor(),
                     &visitor_label,
+                    &TokenStream::new(),
                 )
             };
 
@@ -367,6 +422,7 @@ pub fn parse(
                     Field::FieldStruct { struct_name } => visit_option_vec(
                         &Ident::new("visit_str", field.get_span()),
                         &field.get_visitor_ident(Some(struct_name)),
+                        &check_unknown,
                     ),
                     Field::FieldOption { .. } | Field::FieldVec { .. } => {
                         unimplemented!("Not supported")
@@ -374,6 +430,7 @@ pub fn parse(
                     simple_type => visit_option_vec(
                         &simple_type.get_simple_type_visitor(),
                         &field.get_visitor_ident(None),
+                        &TokenStream::new(),
                     ),
                 },
                 Field::FieldStruct { struct_name } => visit_struct(struct_name, action),
@@ -390,6 +447,7 @@ pub fn parse(
                         &quote! { .push(value) },
                         &Ident::new("visit_str", field.get_span()),
                         &field.get_visitor_ident(Some(struct_name)),
+                        &check_unknown,
                     ),
                     Field::FieldOption { .. } | Field::FieldVec { .. } => {
                         unimplemented!("Not supported")
@@ -398,6 +456,7 @@ pub fn parse(
                         &quote! { .push(value) },
                         &simple_type.get_simple_type_visitor(),
                         &visitor_label,
+                        &TokenStream::new(),
                     ),
                 },
                 Field::FieldStruct { struct_name } => {
@@ -446,6 +505,10 @@ pub fn parse(
             let label = &field.label();
             let value_label = field.get_value_label();
 
+            if field.is_extras() {
+                return quote! { #label: #value_label, };
+            }
+
             match field.get_type() {
                 Field::FieldOption { .. } | Field::FieldVec { .. } => {
                     quote! { #label: #value_label, }
@@ -463,19 +526,51 @@ pub fn parse(
                             name
                         );
 
-                        quote! { #label: #value_label.ok_or_else(|| #error.to_string())?, }
+                        let attribute = if field.is_attribute() {
+                            let attribute = field.renamed_label(root_attributes);
+                            quote!(::std::option::Option::Some(#attribute))
+                        } else {
+                            quote!(::std::option::Option::None)
+                        };
+
+                        quote! {
+                          #label: match #value_label {
+                            ::std::option::Option::Some(value) => value,
+                            ::std::option::Option::None => {
+                              reader.recover(reader.missing_field(#error, #attribute))?;
+                              ::std::default::Default::default()
+                            }
+                          },
+                        }
                     }
                 }
             }
         })
         .collect();
 
+    let (init_extras, load_extras, read_extras) = data_struct
+        .fields
+        .iter()
+        .map(|field| XmlField::new(field.clone()))
+        .find(|field| field.is_extras())
+        .map_or((None, None, None), |field| {
+            build_code_for_extras(&field, data_struct, root_attributes)
+        });
+
     let (init_unused, write_unused, visit_unused) = if call_flatten_visitors.is_empty() {
         (None, None, None)
     } else {
         build_code_for_unused_xml_events(&call_flatten_visitors)
     };
 
+    let read_unknown_element = read_extras.unwrap_or_else(|| {
+        quote! {
+          reader.skip_element(|event| {
+            #write_unused
+          })?;
+        }
+    });
+
     let flatten = root_attributes.flatten;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -484,7 +579,7 @@ pub fn parse(
         #[allow(unused_variables)]
         fn deserialize<R: ::std::io::Read>(
           reader: &mut crate::xml::de::Deserializer<R>,
-        ) -> ::std::result::Result<Self, ::std::string::String> {
+        ) -> ::std::result::Result<Self, crate::error::DeserializeError> {
           let (named_element, struct_namespace) =
             if let crate::xml::__xml::reader::XmlEvent::StartElement { name, .. } = reader.peek()?.to_owned() {
               (name.local_name.to_owned(), name.namespace.clone())
@@ -502,6 +597,7 @@ pub fn parse(
           #variables
           #field_visitors
           #init_unused
+          #init_extras
 
           let mut depth = 0;
 
@@ -528,15 +624,14 @@ pub fn parse(
                       #write_unused
 
                       if depth > 0 { // Don't skip root element
-                        reader.skip_element(|event| {
-                          #write_unused
-                        })?;
+                        #read_unknown_element
                       }
                     }
                   }
                 }
                 if depth == 0 { // Look for attributes only at element start
                   #attributes_loading
+                  #load_extras
                 }
                 depth += 1;
               }
@@ -560,7 +655,10 @@ pub fn parse(
                 #write_unused
               }
               event => {
-                return ::std::result::Result::Err(::std::format!("unknown event {:?}", event));
+                return ::std::result::Result::Err(reader.error(
+                  crate::error::ErrorKind::Syntax,
+                  ::std::format!("unknown event {:?}", event),
+                ));
               }
             }
           }
@@ -595,17 +693,21 @@ fn build_call_visitor(
 
     Some(quote! {
       (#namespace, #label_name) => {
-        let visitor = #visitor_label{};
+        let visitor = #visitor_label::new(reader.options());
 
         #namespaces_matching
 
         let result = reader.read_inner_value::<#field_type, _>(|reader| {
           if let ::std::result::Result::Ok(crate::xml::__xml::reader::XmlEvent::Characters(s)) = reader.peek() {
-            let val = visitor.#visitor(&s);
+            let val = visitor.#visitor(&s)
+              .map_err(|error| reader.error(crate::error::ErrorKind::InvalidValue, error));
             let _event = reader.next_event()?;
             val
           } else {
-            ::std::result::Result::Err(::std::format!("unable to parse content for {}", #label_name))
+            ::std::result::Result::Err(reader.error(
+              crate::error::ErrorKind::InvalidValue,
+              ::std::format!("unable to parse content for {}", #label_name),
+            ))
           }
         });
 
@@ -616,6 +718,51 @@ fn build_call_visitor(
     })
 }
 
+fn build_code_for_extras(
+    field: &XmlField,
+    data_struct: &DataStruct,
+    root_attributes: &XmlAttribute,
+) -> (
+    Option<TokenStream>,
+    Option<TokenStream>,
+    Option<TokenStream>,
+) {
+    let value_label = field.get_value_label();
+    let struct_name = match field.get_type() {
+        Field::FieldStruct { struct_name } => struct_name,
+        field_type => unimplemented!(r#""extras" is not implemented for {:?}"#, field_type),
+    };
+
+    let known_attributes: Vec<TokenStream> = data_struct
+        .fields
+        .iter()
+        .map(|field| XmlField::new(field.clone()))
+        .filter(|field| field.is_attribute())
+        .map(|field| {
+            let label_name = field.renamed_label_without_namespace();
+            let namespace = field.attribute_namespace(root_attributes);
+            quote!((#namespace, #label_name))
+        })
+        .collect();
+
+    (
+        Some(quote! {
+          let mut #value_label = <#struct_name as ::std::default::Default>::default();
+        }),
+        Some(quote! {
+          for attr in attributes {
+            let known: &[(::std::option::Option<&str>, &str)] = &[#(#known_attributes),*];
+            if !known.contains(&(attr.name.namespace.as_deref(), attr.name.local_name.as_str())) {
+              #value_label.push_attribute(reader, attr)?;
+            }
+          }
+        }),
+        Some(quote! {
+          #value_label.read_element(reader, &event)?;
+        }),
+    )
+}
+
 fn build_code_for_unused_xml_events(
     call_flatten_visitors: &TokenStream,
 ) -> (
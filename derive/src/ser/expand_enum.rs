@@ -126,6 +126,15 @@ fn inner_enum_inspector(
       let label = &variant.ident;
       let label_name = build_label_name(label, &variant_attrs, &root_attributes.default_namespace);
 
+      if variant_attrs.other {
+        return quote! {
+          #name::#label(value) => {
+            let data_event = crate::xml::__xml::writer::XmlEvent::characters(value);
+            writer.write(data_event).map_err(|e| e.to_string())?;
+          }
+        };
+      }
+
       match variant.fields {
         Fields::Unit => {
           if let Some(_tag) = &root_attributes.tag {
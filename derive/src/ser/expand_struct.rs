@@ -17,11 +17,15 @@ pub fn serialize(
         .fields
         .iter()
         .map(|field| XmlField::new(field.clone()))
-        .filter(|field| field.is_attribute() || field.is_flatten())
+        .filter(|field| field.is_attribute() || field.is_flatten() || field.is_extras())
         .map(|field| {
             let label = field.label();
 
-            if field.is_attribute() {
+            if field.is_extras() {
+                quote!(
+                  self.#label.write_attributes(&mut child_attributes, &mut child_attributes_namespace);
+                )
+            } else if field.is_attribute() {
                 let label_name = field.renamed_label(root_attributes);
 
                 match field.get_type() {
@@ -184,11 +188,26 @@ pub fn serialize(
         })
         .collect();
 
+    // Unknown children are handed to the writer before the known ones, so that it
+    // can put each of them back at its original position among them.
+    let insert_extras: TokenStream = data_struct
+        .fields
+        .iter()
+        .map(|field| XmlField::new(field.clone()))
+        .filter(|field| field.is_extras())
+        .map(|field| {
+            let label = field.label();
+            quote!(
+              self.#label.write_elements(writer)?;
+            )
+        })
+        .collect();
+
     let struct_inspector: TokenStream = data_struct
     .fields
     .iter()
     .map(|field| XmlField::new(field.clone()))
-    .filter(|field| !field.is_attribute())
+    .filter(|field| !field.is_attribute() && !field.is_extras())
     .filter_map(|field| {
       let label = field.label();
       if field.is_text_content() {
@@ -422,7 +441,7 @@ pub fn serialize(
         root,
         root_attributes,
         append_attributes,
-        struct_inspector,
+        quote!(#insert_extras #struct_inspector),
         generics,
     )
 }
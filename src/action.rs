@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Adds an action to an intent filter.
@@ -43,4 +44,8 @@ pub struct Action {
     /// [`Intent`]: https://developer.android.com/reference/android/content/Intent
     #[xml(attribute = true, prefix = "android")]
     pub name: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
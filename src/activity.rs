@@ -1,9 +1,11 @@
+use super::extras::Extras;
 use crate::VarOrBool;
 
 use super::attribute_list::{AttributeList, VerticalBar};
 use super::intent_filter::IntentFilter;
 use super::layout::Layout;
 use super::meta_data::MetaData;
+use super::property::Property;
 use super::resources::{
     DrawableResource, MipmapOrDrawableResource, Resource, StringResourceOrString, StyleResource,
 };
@@ -20,26 +22,33 @@ use serde::{Deserialize, Serialize};
 /// ```xml
 /// <activity android:allowEmbedded=["true" | "false"]
 ///           android:allowTaskReparenting=["true" | "false"]
+///           android:allowUntrustedActivityEmbedding=["true" | "false"]
 ///           android:alwaysRetainTaskState=["true" | "false"]
+///           android:attributionTags="string"
 ///           android:autoRemoveFromRecents=["true" | "false"]
 ///           android:banner="drawable resource"
+///           android:canDisplayOnRemoteDevices=["true" | "false"]
 ///           android:clearTaskOnLaunch=["true" | "false"]
 ///           android:colorMode=["hdr" | "wideColorGamut"]
 ///           android:configChanges=["mcc", "mnc", "locale",
 ///                                  "touchscreen", "keyboard", "keyboardHidden",
 ///                                  "navigation", "screenLayout", "fontScale",
 ///                                  "uiMode", "orientation", "density",
-///                                  "screenSize", "smallestScreenSize"]
+///                                  "screenSize", "smallestScreenSize",
+///                                  "layoutDirection", "colorMode",
+///                                  "fontWeightAdjustment", "grammaticalGender"]
 ///           android:directBootAware=["true" | "false"]
 ///           android:documentLaunchMode=["intoExisting" | "always" |
 ///                                       "none" | "never"]
 ///           android:enabled=["true" | "false"]
+///           android:enableOnBackInvokedCallback=["true" | "false"]
 ///           android:excludeFromRecents=["true" | "false"]
 ///           android:exported=["true" | "false"]
 ///           android:finishOnTaskLaunch=["true" | "false"]
 ///           android:hardwareAccelerated=["true" | "false"]
 ///           android:icon="drawable resource"
 ///           android:immersive=["true" | "false"]
+///           android:knownActivityEmbeddingCerts="string array resource | string"
 ///           android:label="string resource"
 ///           android:launchMode=["standard" | "singleTop"|
 ///                               "singleTask" | "singleInstance"]
@@ -47,6 +56,7 @@ use serde::{Deserialize, Serialize};
 ///                                 "if_whitelisted" | "always"]
 ///           android:maxRecents="integer"
 ///           android:maxAspectRatio="float"
+///           android:minAspectRatio="float"
 ///           android:multiprocess=["true" | "false"]
 ///           android:name="string"
 ///           android:noHistory=["true" | "false"]
@@ -54,9 +64,14 @@ use serde::{Deserialize, Serialize};
 ///           android:persistableMode=["persistRootOnly" |
 ///                                    "persistAcrossReboots" | "persistNever"]
 ///           android:permission="string"
+///           android:preferMinimalPostProcessing=["true" | "false"]
 ///           android:process="string"
+///           android:recreateOnConfigChanges=["mcc", "mnc"]
 ///           android:relinquishTaskIdentity=["true" | "false"]
+///           android:requiredDisplayCategory="string"
 ///           android:resizeableActivity=["true" | "false"]
+///           android:rotationAnimation=["rotate" | "crossfade" | "jumpcut" |
+///                                      "seamless"]
 ///           android:screenOrientation=["unspecified" | "behind" |
 ///                                      "landscape" | "portrait" |
 ///                                      "reverseLandscape" | "reversePortrait" |
@@ -65,10 +80,12 @@ use serde::{Deserialize, Serialize};
 ///                                      "sensor" | "fullSensor"|"nosensor" |
 ///                                      "user" | "fullUser" | "locked"]
 ///           android:showForAllUsers=["true" | "false"]
+///           android:showWhenLocked=["true" | "false"]
 ///           android:stateNotNeeded=["true" | "false"]
 ///           android:supportsPictureInPicture=["true" | "false"]
 ///           android:taskAffinity="string"
 ///           android:theme="resource or theme"
+///           android:turnScreenOn=["true" | "false"]
 ///           android:uiOptions=["none" | "splitActionBarWhenNarrow"]
 ///           android:windowSoftInputMode=["stateUnspecified",
 ///                                        "stateUnchanged", "stateHidden",
@@ -92,6 +109,7 @@ use serde::{Deserialize, Serialize};
 /// ## Can contain
 /// * [`<intent-filter>`]
 /// * [`<meta-data>`]
+/// * [`<property>`]
 /// * [`<layout>`]
 ///
 /// ## Introduced in
@@ -102,6 +120,7 @@ use serde::{Deserialize, Serialize};
 /// [`<application>`]: crate::Application
 /// [`<intent-filter>`]: crate::IntentFilter
 /// [`<meta-data>`]: crate::MetaData
+/// [`<property>`]: crate::Property
 /// [`<layout>`]: crate::Layout
 /// [`noHistory`]: crate::Activity#structfield.no_history
 /// [`windowSoftInputMode`]: crate::Activity#structfield.window_soft_input_mode
@@ -151,6 +170,21 @@ pub struct Activity {
     /// [`launchMode`]: crate::Activity#structfield.launch_mode
     #[xml(attribute = true, prefix = "android", rename = "allowTaskReparenting")]
     pub allow_task_reparenting: Option<VarOrBool>,
+    /// Whether the activity can be embedded in a task of another app that is not
+    /// signed with one of the certificates in [`knownActivityEmbeddingCerts`], for
+    /// example in a split-screen layout of an untrusted host.
+    ///
+    /// The default value is "`false`".
+    ///
+    /// This attribute was added in API level 33.
+    ///
+    /// [`knownActivityEmbeddingCerts`]: crate::Activity#structfield.known_activity_embedding_certs
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "allowUntrustedActivityEmbedding"
+    )]
+    pub allow_untrusted_activity_embedding: Option<VarOrBool>,
     /// Whether or not the state of the task that the activity is in will always be
     /// maintained by the system — "`true`" if it will be, and "`false`" if the system
     /// is allowed to reset the task to its initial state in certain situations. The
@@ -168,6 +202,22 @@ pub struct Activity {
     /// multiple open tabs) that users would not like to lose.
     #[xml(attribute = true, prefix = "android", rename = "alwaysRetainTaskState")]
     pub always_retain_task_state: Option<VarOrBool>,
+    /// The [`attribution tags`] that the activity uses when it accesses private data,
+    /// separated by `|`. The tags must be declared with [`<attribution>`] elements.
+    ///
+    /// This attribute was added in API level 31.
+    ///
+    /// [`attribution tags`]: https://developer.android.com/guide/topics/data/audit-access#attribution-tags
+    /// [`<attribution>`]: crate::Attribution
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "attributionTags",
+        skip_serializing_if = "check_attribution_tags",
+        default = "default_attribution_tags"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub attribution_tags: AttributeList<VerticalBar, String>,
     /// Whether or not tasks launched by activities with this attribute remains in the
     /// [`overview screen`] until the last activity in the task is completed. If true, the
     /// task is automatically removed from the `overview screen.` This overrides the
@@ -200,6 +250,18 @@ pub struct Activity {
     /// [`Provide a home screen banner`]: https://developer.android.com/training/tv/start/start#banner
     #[xml(attribute = true, prefix = "android")]
     pub banner: Option<Resource<DrawableResource>>,
+    /// Whether the activity can be launched on a remote device, for example one that
+    /// the app is streamed to.
+    ///
+    /// The default value is "`true`".
+    ///
+    /// This attribute was added in API level 33.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "canDisplayOnRemoteDevices"
+    )]
+    pub can_display_on_remote_devices: Option<VarOrBool>,
     /// Whether or not all activities will be removed from the task, except for the root
     /// activity, whenever it is re-launched from the home screen — "`true`" if the
     /// task is always stripped down to its root activity, and "`false`" if not. The
@@ -308,6 +370,19 @@ pub struct Activity {
     /// [`enabled`]: crate::Application#structfield.enabled
     #[xml(attribute = true, prefix = "android")]
     pub enabled: Option<VarOrBool>,
+    /// Whether the activity opts in to the predictive back gesture. Overrides the
+    /// [`enableOnBackInvokedCallback`] value of the [`<application>`] element.
+    ///
+    /// This attribute was added in API level 33.
+    ///
+    /// [`enableOnBackInvokedCallback`]: crate::Application#structfield.enable_on_back_invoked_callback
+    /// [`<application>`]: crate::Application
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "enableOnBackInvokedCallback"
+    )]
+    pub enable_on_back_invoked_callback: Option<VarOrBool>,
     /// Whether or not the task initiated by this activity should be excluded
     /// from the list of recently used applications, the [`overview screen`].
     /// That is, when this activity is the root activity of a new task, this
@@ -407,6 +482,17 @@ pub struct Activity {
     /// [`setImmersive()`]: https://developer.android.com/reference/android/app/Activity#setImmersive(boolean)
     #[xml(attribute = true, prefix = "android")]
     pub immersive: Option<VarOrBool>,
+    /// The SHA-256 digests of the signing certificates of the apps that are allowed to
+    /// embed the activity, either as a reference to a string array resource or as a
+    /// single digest.
+    ///
+    /// This attribute was added in API level 33.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "knownActivityEmbeddingCerts"
+    )]
+    pub known_activity_embedding_certs: Option<String>,
     /// A user-readable label for the activity. The label is displayed on-screen when the
     /// activity must be represented to the user. It's often displayed along with the
     /// activity icon. If this attribute is not set, the label set for the application
@@ -484,6 +570,18 @@ pub struct Activity {
     /// [`resizeableActivity`]: crate::Activity#structfield.resizeable_activity
     #[xml(attribute = true, prefix = "android", rename = "maxAspectRatio")]
     pub max_aspect_ratio: Option<f32>,
+    /// The minimum aspect ratio the activity supports, expressed like
+    /// [`maxAspectRatio`]. If the app runs on a device with a narrower aspect ratio, the
+    /// system automatically letterboxes it.
+    ///
+    /// This attribute is ignored if the activity has [`resizeableActivity`] set to true.
+    ///
+    /// This attribute was added in API level 29.
+    ///
+    /// [`maxAspectRatio`]: crate::Activity#structfield.max_aspect_ratio
+    /// [`resizeableActivity`]: crate::Activity#structfield.resizeable_activity
+    #[xml(attribute = true, prefix = "android", rename = "minAspectRatio")]
+    pub min_aspect_ratio: Option<f32>,
     /// Whether an instance of the activity can be launched into the process of the
     /// component that started it — "`true`" if it can be, and "`false`" if not.
     ///
@@ -602,6 +700,19 @@ pub struct Activity {
     /// [`Security and Permissions`]: https://developer.android.com/training/articles/security-tips
     #[xml(attribute = true, prefix = "android")]
     pub permission: Option<String>,
+    /// Whether the activity prefers the display to turn off post-processing, such as
+    /// the low latency mode of a TV. Usually used by games and other latency-sensitive
+    /// content.
+    ///
+    /// The default value is "`false`".
+    ///
+    /// This attribute was added in API level 30.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "preferMinimalPostProcessing"
+    )]
+    pub prefer_minimal_post_processing: Option<VarOrBool>,
     /// The name of the process in which the activity should run. Normally, all components
     /// of an application run in a default process name created for the application
     /// and you do not need to use this attribute. But if necessary, you can override
@@ -622,6 +733,21 @@ pub struct Activity {
     /// [`process`]: crate::Application#structfield.process
     #[xml(attribute = true, prefix = "android")]
     pub process: Option<String>,
+    /// Configuration changes that always recreate the activity, even if they are
+    /// listed in [`configChanges`].
+    ///
+    /// This attribute was added in API level 26.
+    ///
+    /// [`configChanges`]: crate::Activity#structfield.config_changes
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "recreateOnConfigChanges",
+        skip_serializing_if = "check_recreate_on_config_changes",
+        default = "default_recreate_on_config_changes"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub recreate_on_config_changes: AttributeList<VerticalBar, RecreateOnConfigChanges>,
     /// Whether or not the activity relinquishes its task identifiers to an activity above
     /// it in the task stack. A task whose root activity has this attribute set to
     /// "`true`" replaces the base Intent with that of the next activity in the task.
@@ -644,6 +770,16 @@ pub struct Activity {
         rename = "relinquishTaskIdentity"
     )]
     pub relinquish_task_identity: Option<VarOrBool>,
+    /// The category of display the activity requires, such as `"automotive"`. The
+    /// activity can only be launched on displays that declare the same category.
+    ///
+    /// This attribute was added in API level 34.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "requiredDisplayCategory"
+    )]
+    pub required_display_category: Option<String>,
     /// Specifies whether the app supports [`multi-window display`]. You can set
     /// this attribute in either the `<activity>` or [`<application>`] element.
     ///
@@ -668,6 +804,12 @@ pub struct Activity {
     /// [`<application>`]: crate::Application
     #[xml(attribute = true, prefix = "android", rename = "resizeableActivity")]
     pub resizeable_activity: Option<VarOrBool>,
+    /// The animation that is used when the screen rotates while the activity is in the
+    /// foreground.
+    ///
+    /// This attribute was added in API level 26.
+    #[xml(attribute = true, prefix = "android", rename = "rotationAnimation")]
+    pub rotation_animation: Option<RotationAnimation>,
     /// The orientation of the activity's display on the device. The system ignores this
     /// attribute if the activity is running in [`multi-window mode`].
     ///
@@ -703,6 +845,14 @@ pub struct Activity {
     /// This attribute was added in API level 23.
     #[xml(attribute = true, prefix = "android", rename = "showForAllUsers")]
     pub show_for_all_users: Option<VarOrBool>,
+    /// Whether the activity is shown on top of the lock screen when the device is
+    /// locked.
+    ///
+    /// The default value is "`false`".
+    ///
+    /// This attribute was added in API level 27.
+    #[xml(attribute = true, prefix = "android", rename = "showWhenLocked")]
+    pub show_when_locked: Option<VarOrBool>,
     /// Whether or not the activity can be killed and successfully restarted without
     /// having saved its state — "`true`" if it can be restarted without reference to
     /// its previous state, and "`false`" if its previous state is required. The
@@ -782,6 +932,13 @@ pub struct Activity {
     /// [`Styles and Themes`]: https://developer.android.com/guide/topics/ui/look-and-feel/themes
     #[xml(attribute = true, prefix = "android")]
     pub theme: Option<Resource<StyleResource>>,
+    /// Whether the screen is turned on when the activity is resumed.
+    ///
+    /// The default value is "`false`".
+    ///
+    /// This attribute was added in API level 27.
+    #[xml(attribute = true, prefix = "android", rename = "turnScreenOn")]
+    pub turn_screen_on: Option<VarOrBool>,
     /// Extra options for an activity's UI.
     ///
     /// For more information about the app bar, see the [`Adding the App Bar`] training
@@ -834,6 +991,9 @@ pub struct Activity {
     #[xml(rename = "meta-data")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub meta_data: Vec<MetaData>,
+    /// List of `<property>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property: Vec<Property>,
     /// Specifies which attributes from lower priority manifest files should be replaced
     /// by attributes from this manifest. This is a comma-separated list of attribute
     /// names.
@@ -890,17 +1050,40 @@ pub struct Activity {
     /// Reference: [Merge manifest files - tools:strict](https://developer.android.com/studio/build/manage-manifests#attribute_markers)
     #[xml(attribute = true, prefix = "tools")]
     pub strict: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 fn default_config_changes() -> AttributeList<VerticalBar, ConfigChanges> {
     AttributeList::default()
 }
 
+fn default_attribution_tags() -> AttributeList<VerticalBar, String> {
+    AttributeList::default()
+}
+
+fn default_recreate_on_config_changes() -> AttributeList<VerticalBar, RecreateOnConfigChanges> {
+    AttributeList::default()
+}
+
 fn default_window_soft_input_mode() -> AttributeList<VerticalBar, WindowSoftInputMode> {
     AttributeList::default()
 }
 
 impl Activity {
+    fn check_attribution_tags(&self, value: &AttributeList<VerticalBar, String>) -> bool {
+        value.is_empty()
+    }
+
+    fn check_recreate_on_config_changes(
+        &self,
+        value: &AttributeList<VerticalBar, RecreateOnConfigChanges>,
+    ) -> bool {
+        value.is_empty()
+    }
+
     fn check_config_changes(&self, value: &AttributeList<VerticalBar, ConfigChanges>) -> bool {
         value.is_empty()
     }
@@ -930,13 +1113,23 @@ pub enum ColorMode {
     /// space wider than sRGB.
     #[xml(rename = "wideColorGamut")]
     WideColorGamut,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(ColorMode);
+
 /// Lists configuration changes that the `activity` will handle itself.
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub enum ConfigChanges {
+    /// The color mode has changed — for example, the display switched between a wide
+    /// color gamut or HDR and a standard one. Added in API level 26.
+    #[xml(rename = "colorMode")]
+    ColorMode,
     /// The display density has changed — the user might have specified a different
     /// display scale, or a different display might now be active. Added in API level
     /// 24.
@@ -947,6 +1140,14 @@ pub enum ConfigChanges {
     /// size.
     #[xml(rename = "fontScale")]
     FontScale,
+    /// The font weight adjustment has changed — the user has selected bold text in
+    /// the accessibility settings. Added in API level 31.
+    #[xml(rename = "fontWeightAdjustment")]
+    FontWeightAdjustment,
+    /// The grammatical gender the user wants to be addressed with has changed. Added in
+    /// API level 34.
+    #[xml(rename = "grammaticalGender")]
+    GrammaticalGender,
     /// The keyboard type has changed — for example, the user has plugged in an external
     /// keyboard.
     #[xml(rename = "keyboard")]
@@ -1011,8 +1212,14 @@ pub enum ConfigChanges {
     /// [`UiModeManager`]: https://developer.android.com/reference/android/app/UiModeManager
     #[xml(rename = "uiMode")]
     UiMode,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(ConfigChanges);
+
 /// Four values which produce the following effects when the user opens a document with
 /// the application
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
@@ -1057,8 +1264,14 @@ pub enum DocumentLaunchMode {
     /// [`FLAG_ACTIVITY_MULTIPLE_TASK`]: https://developer.android.com/reference/android/content/Intent#FLAG_ACTIVITY_MULTIPLE_TASK
     #[xml(rename = "never")]
     Never,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(DocumentLaunchMode);
+
 /// An instruction on how the activity should be launched.
 ///
 /// As shown in the enum variant description, the modes fall into two main groups, with
@@ -1175,8 +1388,14 @@ pub enum LaunchMode {
     /// Multiple Instances?: No
     #[xml(rename = "singleInstance")]
     SingleInstance,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(LaunchMode);
+
 /// This value indicates how tasks rooted at this activity will behave in lockTask mode.
 /// The value can be any one of the following [`R.attr.lockTaskMode`] string values:
 ///
@@ -1223,8 +1442,14 @@ pub enum LockTaskMode {
     /// [`finish()`]: https://developer.android.com/reference/android/app/Activity#finish()
     #[xml(rename = "always")]
     Always,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(LockTaskMode);
+
 /// Defines how an instance of an activity is preserved within a containing task
 /// across device restarts.
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
@@ -1280,8 +1505,57 @@ pub enum PersistableMode {
     /// set on your app's root activity.
     #[xml(rename = "persistNever")]
     PersistNever,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(PersistableMode);
+
+/// Configuration changes that always recreate an activity.
+#[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum RecreateOnConfigChanges {
+    /// The IMSI mobile country code (MCC) has changed.
+    #[xml(rename = "mcc")]
+    Mcc,
+    /// The IMSI mobile network code (MNC) has changed.
+    #[xml(rename = "mnc")]
+    Mnc,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(RecreateOnConfigChanges);
+
+/// The animation used when the screen rotates while an activity is in the foreground.
+#[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum RotationAnimation {
+    /// `The default value`. The old content rotates out and the new content rotates in.
+    #[xml(rename = "rotate")]
+    Rotate,
+    /// The old content fades out while the new content fades in.
+    #[xml(rename = "crossfade")]
+    Crossfade,
+    /// The new content appears immediately, without an animation.
+    #[xml(rename = "jumpcut")]
+    Jumpcut,
+    /// Seamless rotation, if the activity supports it. Falls back to `"jumpcut"`
+    /// otherwise.
+    #[xml(rename = "seamless")]
+    Seamless,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(RotationAnimation);
+
 /// The orientation of the activity's display on the device.
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -1375,8 +1649,14 @@ pub enum ScreenOrientation {
     /// Added in API level 18.
     #[xml(rename = "locked")]
     Locked,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(ScreenOrientation);
+
 /// How the main window of the activity interacts with the window containing the on-screen
 /// soft keyboard.
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
@@ -1435,4 +1715,10 @@ pub enum WindowSoftInputMode {
     /// get at and interact with obscured parts of the window.
     #[xml(rename = "adjustPan")]
     AdjustPan,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(WindowSoftInputMode);
@@ -1,8 +1,10 @@
+use super::extras::Extras;
 use crate::VarOrBool;
 
 use super::intent_filter::IntentFilter;
 use super::meta_data::MetaData;
-use super::resources::{MipmapOrDrawableResource, Resource, StringResource};
+use super::property::Property;
+use super::resources::{DrawableResource, MipmapOrDrawableResource, Resource, StringResource};
 use serde::{Deserialize, Serialize};
 
 /// An alias for an activity, named by the `targetActivity` attribute.
@@ -25,13 +27,23 @@ use serde::{Deserialize, Serialize};
 ///
 /// ## XML Syntax
 /// ```xml
-/// <activity-alias android:enabled=["true" | "false"]
+/// <activity-alias android:banner="drawable resource"
+///                 android:description="string resource"
+///                 android:enabled=["true" | "false"]
 ///                 android:exported=["true" | "false"]
 ///                 android:icon="drawable resource"
 ///                 android:label="string resource"
 ///                 android:name="string"
+///                 android:parentActivityName="string"
 ///                 android:permission="string"
-///                 android:targetActivity="string" >
+///                 android:targetActivity="string"
+///                 tools:replace="string"
+///                 tools:remove="string"
+///                 tools:node=["merge" | "replace" | "removeAll" | "merge-only" | "strict"]
+///                 tools:ignore="string"
+///                 tools:targetApi="string"
+///                 tools:selector="string"
+///                 tools:strict="string" >
 ///     ...
 /// </activity-alias>
 /// ```
@@ -42,6 +54,7 @@ use serde::{Deserialize, Serialize};
 /// ## Can contain
 /// * [`<intent-filter>`]
 /// * [`<meta-data>`]
+/// * [`<property>`]
 ///
 /// ## Introduced in
 /// API Level 1
@@ -49,6 +62,7 @@ use serde::{Deserialize, Serialize};
 /// [`<application>`]: crate::Application
 /// [`<intent-filter>`]: crate::IntentFilter
 /// [`<meta-data>`]: crate::MetaData
+/// [`<property>`]: crate::Property
 /// [`<activity>`]: crate::Activity
 /// [`android.intent.action.MAIN`]: https://developer.android.com/reference/android/content/Intent#ACTION_MAIN
 /// [`android.intent.category.LAUNCHER`]: https://developer.android.com/reference/android/content/Intent#CATEGORY_LAUNCHER
@@ -56,6 +70,18 @@ use serde::{Deserialize, Serialize};
     Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
 )]
 pub struct ActivityAlias {
+    /// A banner for the target activity when presented to users through the alias on
+    /// Android TV. See the [`<activity>`] element's [`banner`] attribute for more
+    /// information.
+    ///
+    /// [`<activity>`]: crate::Activity
+    /// [`banner`]: crate::Activity#structfield.banner
+    #[xml(attribute = true, prefix = "android")]
+    pub banner: Option<Resource<DrawableResource>>,
+    /// User-readable text about the alias, longer and more descriptive than the label.
+    /// The value must be set as a reference to a string resource.
+    #[xml(attribute = true, prefix = "android")]
+    pub description: Option<Resource<StringResource>>,
     /// Whether or not the target activity can be instantiated by the system through this
     /// alias — "`true`" if it can be, and "`false`" if not. The default value is
     /// "`true`".
@@ -104,6 +130,14 @@ pub struct ActivityAlias {
     /// is arbitrary; it does not refer to an actual class.
     #[xml(attribute = true, prefix = "android")]
     pub name: Option<String>,
+    /// The class name of the logical parent of the target activity when it is reached
+    /// through the alias. See the [`<activity>`] element's [`parentActivityName`]
+    /// attribute for more information.
+    ///
+    /// [`<activity>`]: crate::Activity
+    /// [`parentActivityName`]: crate::Activity#structfield.parent_activity_name
+    #[xml(attribute = true, prefix = "android", rename = "parentActivityName")]
+    pub parent_activity_name: Option<String>,
     /// The name of a permission that clients must have to launch the target activity or
     /// get it to do something via the alias. If a caller of [`startActivity()`] or
     /// [`startActivityForResult()`] has not been granted the specified permission,
@@ -136,4 +170,49 @@ pub struct ActivityAlias {
     #[xml(rename = "meta-data")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub meta_data: Vec<MetaData>,
+    /// List of `<property>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property: Vec<Property>,
+    /// Specifies which attributes from lower priority manifest files should be replaced
+    /// by attributes from this manifest. This is a comma-separated list of attribute
+    /// names.
+    ///
+    /// Reference: [Merge manifest files - tools:replace](https://developer.android.com/studio/build/manage-manifests#merge-manifests)
+    #[xml(attribute = true, prefix = "tools")]
+    pub replace: Option<String>,
+    /// Specifies which attributes or child elements from lower priority manifest files
+    /// should be removed entirely.
+    ///
+    /// Reference: [Merge manifest files - tools:remove](https://developer.android.com/studio/build/manage-manifests#merge-manifests)
+    #[xml(attribute = true, prefix = "tools")]
+    pub remove: Option<String>,
+    /// Specifies the merge strategy for this element.
+    ///
+    /// Reference: [Merge manifest files - tools:node](https://developer.android.com/studio/build/manage-manifests#merge-manifests)
+    #[xml(attribute = true, prefix = "tools")]
+    pub node: Option<String>,
+    /// Lint issue IDs to ignore on this element.
+    ///
+    /// Reference: [Tools Attributes - tools:ignore](https://developer.android.com/studio/write/tool-attributes#tools-ignore)
+    #[xml(attribute = true, prefix = "tools")]
+    pub ignore: Option<String>,
+    /// Target API level for this element.
+    ///
+    /// Reference: [Tools Attributes - tools:targetApi](https://developer.android.com/studio/write/tool-attributes#toolstargetapi)
+    #[xml(attribute = true, prefix = "tools", rename = "targetApi")]
+    pub target_api: Option<String>,
+    /// Specifies library package names to apply the merge rule to.
+    ///
+    /// Reference: [Merge manifest files - tools:selector](https://developer.android.com/studio/build/manage-manifests#marker_selector)
+    #[xml(attribute = true, prefix = "tools")]
+    pub selector: Option<String>,
+    /// Generate a build failure if attributes don't exactly match.
+    ///
+    /// Reference: [Merge manifest files - tools:strict](https://developer.android.com/studio/build/manage-manifests#attribute_markers)
+    #[xml(attribute = true, prefix = "tools")]
+    pub strict: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
@@ -0,0 +1,49 @@
+use super::extras::Extras;
+use serde::{Deserialize, Serialize};
+
+/// Declares a system service that the system server loads from an APEX module.
+///
+/// It is only honoured in the manifest of an APEX module's service package.
+///
+/// ## XML Syntax
+/// ```xml
+/// <apex-system-service android:name="string"
+///                      android:path="string"
+///                      android:initOrder="integer"
+///                      android:minSdkVersion="integer"
+///                      android:maxSdkVersion="integer" />
+/// ```
+///
+/// ## Contained in
+/// * [`<application>`]
+///
+/// ## Introduced in
+/// API Level 31
+///
+/// [`<application>`]: crate::Application
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct ApexSystemService {
+    /// The fully qualified class name of the service.
+    #[xml(attribute = true, prefix = "android")]
+    pub name: String,
+    /// The path of the jar that contains the service, if it isn't on the system
+    /// server classpath.
+    #[xml(attribute = true, prefix = "android")]
+    pub path: Option<String>,
+    /// The order in which services of the same module are started: higher values are
+    /// started first. The default is 0.
+    #[xml(attribute = true, prefix = "android", rename = "initOrder")]
+    pub init_order: Option<i32>,
+    /// The lowest API level that the service is loaded on.
+    #[xml(attribute = true, prefix = "android", rename = "minSdkVersion")]
+    pub min_sdk_version: Option<u32>,
+    /// The highest API level that the service is loaded on.
+    #[xml(attribute = true, prefix = "android", rename = "maxSdkVersion")]
+    pub max_sdk_version: Option<u32>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
@@ -1,17 +1,24 @@
 use super::activity::Activity;
 use super::activity_alias::ActivityAlias;
+use super::apex_system_service::ApexSystemService;
+use super::extras::Extras;
 use super::meta_data::MetaData;
 use super::profileable::Profileable;
+use super::property::Property;
 use super::provider::Provider;
 use super::receiver::Receiver;
 use super::resources::{
     DrawableResource, MipmapOrDrawableResource, Resource, StringResource, StringResourceOrString,
     StyleResource, XmlResource,
 };
+use super::sdk_library::SdkLibrary;
 use super::service::Service;
+use super::static_library::StaticLibrary;
 use super::ui_options::UiOptions;
 use super::uses_library::UsesLibrary;
 use super::uses_native_library::UsesNativeLibrary;
+use super::uses_sdk_library::UsesSdkLibrary;
+use super::uses_static_library::UsesStaticLibrary;
 use crate::VarOrBool;
 use serde::{Deserialize, Serialize};
 
@@ -28,16 +35,25 @@ use serde::{Deserialize, Serialize};
 /// ## XML Syntax
 /// ```xml
 /// <application android:allowTaskReparenting=["true" | "false"]
+///              android:allowAudioPlaybackCapture=["true" | "false"]
 ///              android:allowBackup=["true" | "false"]
 ///              android:allowClearUserData=["true" | "false"]
+///              android:allowCrossUidActivitySwitchFromBelow=["true" | "false"]
 ///              android:allowNativeHeapPointerTagging=["true" | "false"]
+///              android:appCategory=["accessibility" | "audio" | "game" |
+///                                   "image" | "maps" | "news" |
+///                                   "productivity" | "social" | "video"]
+///              android:autoRevokePermissions=["allowed" | "discouraged" | "disallowed"]
 ///              android:backupAgent="string"
 ///              android:backupInForeground=["true" | "false"]
 ///              android:banner="drawable resource"
+///              android:crossProfile=["true" | "false"]
+///              android:dataExtractionRules="xml resource"
 ///              android:debuggable=["true" | "false"]
 ///              android:description="string resource"
 ///              android:directBootAware=["true" | "false"]
 ///              android:enabled=["true" | "false"]
+///              android:enableOnBackInvokedCallback=["true" | "false"]
 ///              android:extractNativeLibs=["true" | "false"]
 ///              android:fullBackupContent="xml resource"
 ///              android:fullBackupOnly=["true" | "false"]
@@ -50,15 +66,20 @@ use serde::{Deserialize, Serialize};
 ///              android:killAfterRestore=["true" | "false"]
 ///              android:largeHeap=["true" | "false"]
 ///              android:label="string resource"
+///              android:localeConfig="xml resource"
 ///              android:logo="drawable resource"
 ///              android:manageSpaceActivity="string"
+///              android:memtagMode=["default" | "off" | "async" | "sync"]
 ///              android:name="string"
 ///              android:networkSecurityConfig="xml resource"
+///              android:pageSizeCompat=["enabled" | "disabled"]
 ///              android:permission="string"
 ///              android:persistent=["true" | "false"]
+///              android:preserveLegacyExternalStorage=["true" | "false"]
 ///              android:process="string"
 ///              android:restoreAnyVersion=["true" | "false"]
 ///              android:requestLegacyExternalStorage=["true" | "false"]
+///              android:requestRawExternalStorageAccess=["true" | "false"]
 ///              android:requiredAccountType="string"
 ///              android:resizeableActivity=["true" | "false"]
 ///              android:restrictedAccountType="string"
@@ -68,7 +89,9 @@ use serde::{Deserialize, Serialize};
 ///              android:theme="resource or theme"
 ///              android:uiOptions=["none" | "splitActionBarWhenNarrow"]
 ///              android:usesCleartextTraffic=["true" | "false"]
-///              android:vmSafeMode=["true" | "false"] >
+///              android:usesNonSdkApi=["true" | "false"]
+///              android:vmSafeMode=["true" | "false"]
+///              android:zygotePreloadName="string" >
 ///       ...
 /// </application>
 /// ```
@@ -80,10 +103,17 @@ use serde::{Deserialize, Serialize};
 /// * [`<activity>`]
 /// * [`<activity-alias>`]
 /// * [`<meta-data>`]
+/// * [`<property>`]
 /// * [`<service>`]
 /// * [`<receiver>`]
 /// * [`<provider>`]
 /// * [`<uses-library>`]
+/// * [`<uses-native-library>`]
+/// * [`<uses-sdk-library>`]
+/// * [`<uses-static-library>`]
+/// * [`<sdk-library>`]
+/// * [`<static-library>`]
+/// * [`<apex-system-service>`]
 ///
 /// ## Introduced in
 /// API Level 1
@@ -92,10 +122,17 @@ use serde::{Deserialize, Serialize};
 /// [`<activity>`]: crate::Activity
 /// [`<activity-alias>`]: crate::ActivityAlias
 /// [`<meta-data>`]: crate::MetaData
+/// [`<property>`]: crate::Property
 /// [`<service>`]: crate::Service
 /// [`<receiver>`]: crate::Receiver
 /// [`<provider>`]: crate::Provider
 /// [`<uses-library>`]: crate::UsesLibrary
+/// [`<uses-native-library>`]: crate::UsesNativeLibrary
+/// [`<uses-sdk-library>`]: crate::UsesSdkLibrary
+/// [`<uses-static-library>`]: crate::UsesStaticLibrary
+/// [`<sdk-library>`]: crate::SdkLibrary
+/// [`<static-library>`]: crate::StaticLibrary
+/// [`<apex-system-service>`]: crate::ApexSystemService
 #[derive(
     Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Default, Clone,
 )]
@@ -114,6 +151,20 @@ pub struct Application {
     /// [`allowTaskReparenting`]: crate::Activity#structfield.allow_task_reparenting
     #[xml(attribute = true, prefix = "android", rename = "allowTaskReparenting")]
     pub allow_task_reparenting: Option<VarOrBool>,
+    /// Whether other apps can capture the audio that this application plays, for
+    /// example with [`AudioPlaybackCaptureConfiguration`].
+    ///
+    /// The default value is "`true`" for apps that target API level 29 or higher.
+    ///
+    /// This attribute was added in API level 29.
+    ///
+    /// [`AudioPlaybackCaptureConfiguration`]: https://developer.android.com/reference/android/media/AudioPlaybackCaptureConfiguration
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "allowAudioPlaybackCapture"
+    )]
+    pub allow_audio_playback_capture: Option<VarOrBool>,
     /// Whether to allow the application to participate in the backup and restore
     /// infrastructure. If this attribute is set to false, no backup or restore of the
     /// application will ever be performed, even by a full-system backup that would
@@ -146,6 +197,19 @@ pub struct Application {
     /// [`Restoring User Data on New Devices`]: https://developer.android.com/guide/topics/data/backup
     #[xml(attribute = true, prefix = "android", rename = "allowClearUserData")]
     pub allow_clear_user_data: Option<VarOrBool>,
+    /// Whether activities of other apps that are below this application's activities in
+    /// the task are allowed to start activities on top of them.
+    ///
+    /// The default value is "`true`". Set it to "`false`" to protect the app's tasks
+    /// from being hijacked by apps with a different UID.
+    ///
+    /// This attribute was added in API level 35.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "allowCrossUidActivitySwitchFromBelow"
+    )]
+    pub allow_cross_uid_activity_switch_from_below: Option<VarOrBool>,
     /// Whether or not the app has the Heap pointer tagging feature enabled.
     ///
     /// The default value of this attribute is `true`.
@@ -163,6 +227,19 @@ pub struct Application {
         rename = "allowNativeHeapPointerTagging"
     )]
     pub allow_native_heap_pointer_tagging: Option<VarOrBool>,
+    /// Declares the category of this app. Categories are used to cluster multiple apps
+    /// together into meaningful groups, such as when summarizing battery, network, or
+    /// disk usage.
+    ///
+    /// This attribute was added in API level 26.
+    #[xml(attribute = true, prefix = "android", rename = "appCategory")]
+    pub app_category: Option<AppCategory>,
+    /// Whether the system may revoke the runtime permissions of this app when it hasn't
+    /// been used for a few months.
+    ///
+    /// This attribute was added in API level 30.
+    #[xml(attribute = true, prefix = "android", rename = "autoRevokePermissions")]
+    pub auto_revoke_permissions: Option<AutoRevokePermissions>,
     /// The name of the class that implements the application's backup agent, a subclass
     /// of [`BackupAgent`]. The attribute value should be a fully qualified class name
     /// (such as, `"com.example.project.MyBackupAgent"`). However, as a shorthand, if
@@ -210,6 +287,25 @@ pub struct Application {
     /// [`Provide a home screen banner`]: https://developer.android.com/training/tv/start/start#banner
     #[xml(attribute = true, prefix = "android")]
     pub banner: Option<Resource<DrawableResource>>,
+    /// Whether the app can communicate across the profiles of the same user, such as
+    /// between the personal and the work profile. The app also needs the
+    /// `INTERACT_ACROSS_PROFILES` permission and the user's consent.
+    ///
+    /// This attribute was added in API level 30.
+    #[xml(attribute = true, prefix = "android", rename = "crossProfile")]
+    pub cross_profile: Option<VarOrBool>,
+    /// Points to an XML file that contains the backup and restore rules of the app
+    /// for devices running Android 12 (API level 31) or higher. It replaces
+    /// [`fullBackupContent`] on those devices.
+    ///
+    /// For more information, see [`Back up user data with Auto Backup`].
+    ///
+    /// This attribute was added in API level 31.
+    ///
+    /// [`fullBackupContent`]: crate::Application#structfield.full_backup_content
+    /// [`Back up user data with Auto Backup`]: https://developer.android.com/guide/topics/data/autobackup#include-exclude-android-12
+    #[xml(attribute = true, prefix = "android", rename = "dataExtractionRules")]
+    pub data_extraction_rules: Option<Resource<XmlResource>>,
     /// Whether or not the application can be debugged, even when running on a device in
     /// user mode — "`true`" if it can be, and "`false`" if not. The default value is
     /// "`false`".
@@ -247,6 +343,23 @@ pub struct Application {
     /// The default value is "`true`".
     #[xml(attribute = true, prefix = "android")]
     pub enabled: Option<VarOrBool>,
+    /// Whether the app opts in to the predictive back gesture and receives back
+    /// events through [`OnBackInvokedCallback`] instead of `onBackPressed()`.
+    ///
+    /// The default value is "`false`". The [`<activity>`] element has its own
+    /// [`enableOnBackInvokedCallback`] attribute that overrides the value set here.
+    ///
+    /// This attribute was added in API level 33.
+    ///
+    /// [`OnBackInvokedCallback`]: https://developer.android.com/reference/android/window/OnBackInvokedCallback
+    /// [`<activity>`]: crate::Activity
+    /// [`enableOnBackInvokedCallback`]: crate::Activity#structfield.enable_on_back_invoked_callback
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "enableOnBackInvokedCallback"
+    )]
+    pub enable_on_back_invoked_callback: Option<VarOrBool>,
     /// Whether or not the package installer extracts native libraries from the APK to the
     /// filesystem. If set to "`false`", then your native libraries must be page aligned
     /// and stored uncompressed in the APK. Although your APK might be larger, your
@@ -412,6 +525,16 @@ pub struct Application {
     /// [`<provider>`]: crate::Provider
     #[xml(attribute = true, prefix = "android")]
     pub label: Option<StringResourceOrString>,
+    /// Points to an XML file that lists the locales the app supports, which the system
+    /// offers in the per-app language settings.
+    ///
+    /// For more information, see [`Per-app language preferences`].
+    ///
+    /// This attribute was added in API level 33.
+    ///
+    /// [`Per-app language preferences`]: https://developer.android.com/guide/topics/resources/app-languages
+    #[xml(attribute = true, prefix = "android", rename = "localeConfig")]
+    pub locale_config: Option<Resource<XmlResource>>,
     /// A logo for the application as whole, and the default logo for activities. This
     /// attribute must be set as a reference to a drawable resource containing the
     /// image (for example `"@drawable/logo"`).
@@ -426,6 +549,14 @@ pub struct Application {
     /// [`<activity>`]: crate::Activity
     #[xml(attribute = true, prefix = "android", rename = "manageSpaceActivity")]
     pub manage_space_activity: Option<String>,
+    /// The mode of the [`Memory Tagging Extension`] (MTE) for the native code of the
+    /// app, on hardware that supports it.
+    ///
+    /// This attribute was added in API level 33.
+    ///
+    /// [`Memory Tagging Extension`]: https://developer.android.com/ndk/guides/arm-mte
+    #[xml(attribute = true, prefix = "android", rename = "memtagMode")]
+    pub memtag_mode: Option<MemtagMode>,
     /// The fully qualified name of an [`Application`] subclass implemented for the
     /// application. When the application process is started, this class is instantiated
     /// before any of the application's components.
@@ -445,6 +576,12 @@ pub struct Application {
     /// [`Network Security Configuration`]: https://developer.android.com/training/articles/security-config
     #[xml(attribute = true, prefix = "android", rename = "networkSecurityConfig")]
     pub network_security_config: Option<Resource<XmlResource>>,
+    /// Whether the app runs in the page size compatibility mode on devices with
+    /// 16 KB memory pages, when its native libraries are not 16 KB aligned.
+    ///
+    /// This attribute was added in API level 35.
+    #[xml(attribute = true, prefix = "android", rename = "pageSizeCompat")]
+    pub page_size_compat: Option<PageSizeCompat>,
     /// The name of a permission that clients must have in order to interact with the
     /// application. This attribute is a convenient way to set a permission that applies
     /// to all of the application's components. It can be overwritten by setting the
@@ -463,6 +600,16 @@ pub struct Application {
     /// certain system applications.
     #[xml(attribute = true, prefix = "android")]
     pub persistent: Option<VarOrBool>,
+    /// Whether the app keeps the legacy storage model after it is updated to target
+    /// API level 30 or higher, as long as it is not uninstalled.
+    ///
+    /// This attribute was added in API level 30.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "preserveLegacyExternalStorage"
+    )]
+    pub preserve_legacy_external_storage: Option<VarOrBool>,
     /// The name of a process where all components of the application should run. Each
     /// component can override this default by setting its own `process` attribute.
     ///
@@ -505,6 +652,16 @@ pub struct Application {
         rename = "requestLegacyExternalStorage"
     )]
     pub request_legacy_external_storage: Option<VarOrBool>,
+    /// Whether the app wants raw file path access to external storage, bypassing the
+    /// performance overhead of the FUSE file system.
+    ///
+    /// This attribute was added in API level 31.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "requestRawExternalStorageAccess"
+    )]
+    pub request_raw_external_storage_access: Option<VarOrBool>,
     /// Specifies the account type required by the application in order to function. If
     /// your app requires an [`Account`], the value for this attribute must correspond to
     /// the account authenticator type used by your app (as defined by
@@ -679,6 +836,12 @@ pub struct Application {
     /// [`StrictMode.VmPolicy.Builder.detectCleartextNetwork()`]: https://developer.android.com/reference/android/os/StrictMode.VmPolicy.Builder#detectCleartextNetwork()
     #[xml(attribute = true, prefix = "android", rename = "usesCleartextTraffic")]
     pub uses_cleartext_traffic: Option<VarOrBool>,
+    /// Whether the app may use non-SDK interfaces. Only honoured for apps that are part
+    /// of the platform.
+    ///
+    /// This attribute was added in API level 28.
+    #[xml(attribute = true, prefix = "android", rename = "usesNonSdkApi")]
+    pub uses_non_sdk_api: Option<VarOrBool>,
     /// Indicates whether the app would like the virtual machine (VM) to operate in safe
     /// mode. The default value is "`false`".
     ///
@@ -689,6 +852,14 @@ pub struct Application {
     /// ART ahead-of-time (AOT) compiler.
     #[xml(attribute = true, prefix = "android", rename = "vmSafeMode")]
     pub vm_safe_mode: Option<VarOrBool>,
+    /// The name of the class that implements the app's [`ZygotePreload`], which loads
+    /// code into the app zygote shared by the isolated services of the app.
+    ///
+    /// This attribute was added in API level 29.
+    ///
+    /// [`ZygotePreload`]: https://developer.android.com/reference/android/app/ZygotePreload
+    #[xml(attribute = true, prefix = "android", rename = "zygotePreloadName")]
+    pub zygote_preload_name: Option<String>,
     /// Optional `<profileable>` tag.
     pub profileable: Option<Profileable>,
     /// List of `<activity>` tags.
@@ -711,6 +882,9 @@ pub struct Application {
     #[xml(rename = "meta-data")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub meta_data: Vec<MetaData>,
+    /// List of `<property>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property: Vec<Property>,
     /// List of `<uses-library>` tags.
     #[xml(rename = "uses-library")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -719,6 +893,24 @@ pub struct Application {
     #[xml(rename = "uses-native-library")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub uses_native_library: Vec<UsesNativeLibrary>,
+    /// List of `<uses-sdk-library>` tags.
+    #[xml(rename = "uses-sdk-library")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uses_sdk_library: Vec<UsesSdkLibrary>,
+    /// List of `<uses-static-library>` tags.
+    #[xml(rename = "uses-static-library")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uses_static_library: Vec<UsesStaticLibrary>,
+    /// Optional `<sdk-library>` tag.
+    #[xml(rename = "sdk-library")]
+    pub sdk_library: Option<SdkLibrary>,
+    /// Optional `<static-library>` tag.
+    #[xml(rename = "static-library")]
+    pub static_library: Option<StaticLibrary>,
+    /// List of `<apex-system-service>` tags.
+    #[xml(rename = "apex-system-service")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub apex_system_service: Vec<ApexSystemService>,
     /// Specifies which attributes from lower priority manifest files should be replaced
     /// by attributes from this manifest. This is a comma-separated list of attribute
     /// names.
@@ -757,6 +949,10 @@ pub struct Application {
     /// Reference: [Merge manifest files - tools:strict](https://developer.android.com/studio/build/manage-manifests#attribute_markers)
     #[xml(attribute = true, prefix = "tools")]
     pub strict: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 impl Application {
@@ -791,4 +987,124 @@ pub enum GwpAsanMode {
     /// 4. GWP-ASan provides additional information about the fault in the crash report.
     #[xml(rename = "always")]
     Always,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(GwpAsanMode);
+
+/// The category of an app, used to group apps together, for example when summarizing
+/// battery, network or disk usage.
+#[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum AppCategory {
+    /// Apps which are primarily accessibility apps, such as screen-readers.
+    #[xml(rename = "accessibility")]
+    Accessibility,
+    /// Apps which primarily work with audio or music, such as music players.
+    #[xml(rename = "audio")]
+    Audio,
+    /// Apps which are primarily games.
+    #[xml(rename = "game")]
+    Game,
+    /// Apps which primarily work with images or photos, such as camera or gallery
+    /// apps.
+    #[xml(rename = "image")]
+    Image,
+    /// Apps which are primarily maps apps, such as navigation apps.
+    #[xml(rename = "maps")]
+    Maps,
+    /// Apps which are primarily news apps, such as newspapers, magazines or sports apps.
+    #[xml(rename = "news")]
+    News,
+    /// Apps which are primarily productivity apps, such as cloud storage or workplace
+    /// apps.
+    #[xml(rename = "productivity")]
+    Productivity,
+    /// Apps which are primarily social apps, such as messaging, communication, email,
+    /// or social network apps.
+    #[xml(rename = "social")]
+    Social,
+    /// Apps which primarily work with video or movies, such as streaming video apps.
+    #[xml(rename = "video")]
+    Video,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(AppCategory);
+
+/// Whether the system may revoke the runtime permissions of an app that hasn't been
+/// used for a few months.
+#[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum AutoRevokePermissions {
+    /// The permissions of the app may be revoked.
+    #[xml(rename = "allowed")]
+    Allowed,
+    /// The app would prefer that its permissions are not revoked, but the user can
+    /// still decide otherwise.
+    #[xml(rename = "discouraged")]
+    Discouraged,
+    /// The permissions of the app are never revoked. Only honoured for apps that are
+    /// part of the platform.
+    #[xml(rename = "disallowed")]
+    Disallowed,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(AutoRevokePermissions);
+
+/// The mode of the Arm [`Memory Tagging Extension`] for the native heap of an app.
+///
+/// [`Memory Tagging Extension`]: https://developer.android.com/ndk/guides/arm-mte
+#[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum MemtagMode {
+    /// Uses the default mode of the device, which is usually `"off"`.
+    #[xml(rename = "default")]
+    Default,
+    /// Disables memory tagging.
+    #[xml(rename = "off")]
+    Off,
+    /// Reports tag mismatches asynchronously. Faster, but the report doesn't point at
+    /// the faulting instruction.
+    #[xml(rename = "async")]
+    Async,
+    /// Reports tag mismatches synchronously, with a precise stack trace.
+    #[xml(rename = "sync")]
+    Sync,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(MemtagMode);
+
+/// Whether an app runs in the page size compatibility mode on devices with 16 KB memory
+/// pages.
+#[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum PageSizeCompat {
+    /// Always runs the app in the compatibility mode.
+    #[xml(rename = "enabled")]
+    Enabled,
+    /// Never runs the app in the compatibility mode, even if its native libraries are
+    /// not aligned to 16 KB.
+    #[xml(rename = "disabled")]
+    Disabled,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(PageSizeCompat);
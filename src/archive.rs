@@ -0,0 +1,87 @@
+use crate::error::{Error, Result};
+use crate::{AndroidManifest, from_binary, from_proto, from_str};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::ZipArchive;
+use zip::result::ZipError;
+
+/// Location of the manifest in APK and AAR archives.
+const MANIFEST_ENTRY: &str = "AndroidManifest.xml";
+/// Location of the manifest of the base module in Android App Bundles.
+const BUNDLE_MANIFEST_ENTRY: &str = "base/manifest/AndroidManifest.xml";
+/// Largest manifest read from an archive. Real manifests are far smaller, and the
+/// size recorded in the archive can't be trusted.
+const MAX_MANIFEST_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Encoding of a manifest stored in an archive.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum ManifestFormat {
+    /// Plain XML text, as found in AAR libraries.
+    Text,
+    /// Binary XML (AXML), as found in APKs.
+    Binary,
+    /// `aapt2` protobuf `XmlNode`, as found in App Bundles.
+    Proto,
+}
+
+impl ManifestFormat {
+    fn detect(bytes: &[u8]) -> ManifestFormat {
+        if crate::compiled::axml::is_axml(bytes) {
+            return ManifestFormat::Binary;
+        }
+        let text = bytes.strip_prefix(b"\xef\xbb\xbf").unwrap_or(bytes);
+        match text.iter().find(|byte| !byte.is_ascii_whitespace()) {
+            Some(b'<') => ManifestFormat::Text,
+            _ => ManifestFormat::Proto,
+        }
+    }
+}
+
+impl AndroidManifest {
+    /// Reads the manifest out of a local `.apk`, `.aab` or `.aar` archive.
+    ///
+    /// The manifest is looked up at `AndroidManifest.xml` for APKs and AARs and
+    /// at `base/manifest/AndroidManifest.xml` for App Bundles. Text, binary XML
+    /// and `aapt2` protobuf manifests are detected automatically.
+    pub fn from_archive<P: AsRef<Path>>(path: P) -> Result<AndroidManifest> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .map_err(|error| Error::FailedToReadArchive(format!("{}: {error}", path.display())))?;
+        let mut archive = ZipArchive::new(file)
+            .map_err(|error| Error::FailedToReadArchive(format!("{}: {error}", path.display())))?;
+        let bytes = read_manifest_entry(&mut archive)
+            .map_err(|error| Error::FailedToReadArchive(format!("{}: {error}", path.display())))?;
+        match ManifestFormat::detect(&bytes) {
+            ManifestFormat::Binary => from_binary(&bytes),
+            ManifestFormat::Text => {
+                let text = String::from_utf8(bytes)
+                    .map_err(|error| Error::deserialize(error.to_string()))?;
+                from_str(&text)
+            }
+            ManifestFormat::Proto => from_proto(&bytes),
+        }
+    }
+}
+
+fn read_manifest_entry(archive: &mut ZipArchive<File>) -> std::result::Result<Vec<u8>, String> {
+    for name in [MANIFEST_ENTRY, BUNDLE_MANIFEST_ENTRY] {
+        let entry = match archive.by_name(name) {
+            Ok(entry) => entry,
+            Err(ZipError::FileNotFound) => continue,
+            Err(error) => return Err(error.to_string()),
+        };
+        let mut bytes = Vec::new();
+        entry
+            .take(MAX_MANIFEST_SIZE + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|error| format!("{name}: {error}"))?;
+        if bytes.len() as u64 > MAX_MANIFEST_SIZE {
+            return Err(format!("{name} is larger than {MAX_MANIFEST_SIZE} bytes"));
+        }
+        return Ok(bytes);
+    }
+    Err(format!(
+        "neither {MANIFEST_ENTRY} nor {BUNDLE_MANIFEST_ENTRY} found"
+    ))
+}
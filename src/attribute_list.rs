@@ -1,4 +1,4 @@
-use crate::xml::{XmlDeserialize, XmlSerialize};
+use crate::xml::{KnownValue, XmlDeserialize, XmlSerialize};
 use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
     de::{self, DeserializeOwned, Visitor},
@@ -31,8 +31,26 @@ impl Delimiter for VerticalBar {
 }
 
 #[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct Comma;
+
+impl Delimiter for Comma {
+    fn delimiter_symbol() -> &'static str {
+        ","
+    }
+}
+
+/// A list of values separated by a [`Delimiter`], such as
+/// `android:configChanges="orientation|screenSize"`.
+///
+/// Parsing fails on an empty segment or one that is not a valid `T`, unless
+/// [`ParseOptions::lenient_lists`](crate::ParseOptions::lenient_lists) is set.
+/// Then invalid segments are kept as raw strings and written back after the
+/// known values. Segments that an attribute enum doesn't recognise are kept in
+/// its `Unknown` variant either way.
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct AttributeList<D: Delimiter, T: Serialize + DeserializeOwned> {
     vec: Vec<T>,
+    unrecognized: Vec<String>,
     phantom: PhantomData<D>,
 }
 
@@ -40,6 +58,7 @@ impl<D: Delimiter, T: Serialize + DeserializeOwned> AttributeList<D, T> {
     pub fn new() -> Self {
         AttributeList {
             vec: Vec::new(),
+            unrecognized: Vec::new(),
             phantom: PhantomData,
         }
     }
@@ -47,6 +66,7 @@ impl<D: Delimiter, T: Serialize + DeserializeOwned> AttributeList<D, T> {
     pub fn from_vec(vec: Vec<T>) -> Self {
         AttributeList {
             vec,
+            unrecognized: Vec::new(),
             phantom: PhantomData,
         }
     }
@@ -55,17 +75,36 @@ impl<D: Delimiter, T: Serialize + DeserializeOwned> AttributeList<D, T> {
         &self.vec
     }
 
+    /// Segments that are not a valid `T`, kept when parsing with
+    /// [`ParseOptions::lenient_lists`](crate::ParseOptions::lenient_lists).
+    pub fn unrecognized(&self) -> &[String] {
+        &self.unrecognized
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.vec.is_empty()
+        self.vec.is_empty() && self.unrecognized.is_empty()
+    }
+
+    fn segments(&self) -> Result<Vec<String>, serde_plain::Error> {
+        let mut segments = self
+            .vec
+            .iter()
+            .map(serde_plain::to_string)
+            .collect::<Result<Vec<_>, _>>()?;
+        segments.extend(self.unrecognized.iter().cloned());
+        Ok(segments)
+    }
+}
+
+impl<D: Delimiter, T: Serialize + DeserializeOwned> Default for AttributeList<D, T> {
+    fn default() -> Self {
+        AttributeList::new()
     }
 }
 
 impl<D: Delimiter, T: Serialize + DeserializeOwned> From<Vec<T>> for AttributeList<D, T> {
     fn from(vec: Vec<T>) -> Self {
-        AttributeList {
-            vec,
-            phantom: PhantomData,
-        }
+        AttributeList::from_vec(vec)
     }
 }
 
@@ -77,14 +116,8 @@ impl<D: Delimiter, T: Serialize + DeserializeOwned> Serialize for AttributeList<
         if self.is_empty() {
             return Err(S::Error::custom("a value list can't be empty"));
         };
-        serializer.serialize_str(
-            &self
-                .vec()
-                .iter()
-                .map(|v| serde_plain::to_string(v).unwrap())
-                .collect::<Vec<String>>()
-                .join(D::delimiter_symbol()),
-        )
+        let segments = self.segments().map_err(S::Error::custom)?;
+        serializer.serialize_str(&segments.join(D::delimiter_symbol()))
     }
 }
 
@@ -93,15 +126,10 @@ impl<D: Delimiter, T: Serialize + DeserializeOwned> XmlSerialize for AttributeLi
         &self,
         writer: &mut crate::xml::ser::Serializer<W>,
     ) -> Result<(), String> {
-        let values = self
-            .vec()
-            .iter()
-            .map(serde_plain::to_string)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|error| error.to_string())?;
+        let segments = self.segments().map_err(|error| error.to_string())?;
         writer
             .write(xml::writer::XmlEvent::characters(
-                &values.join(D::delimiter_symbol()),
+                &segments.join(D::delimiter_symbol()),
             ))
             .map_err(|error| error.to_string())
     }
@@ -109,18 +137,35 @@ impl<D: Delimiter, T: Serialize + DeserializeOwned> XmlSerialize for AttributeLi
 
 fn parse_list_with_delimiter<D: Delimiter, T: Serialize + DeserializeOwned>(
     v: &str,
+    lenient: bool,
 ) -> Result<AttributeList<D, T>, String> {
     if v.is_empty() {
         return Err(
             "there is no default value list. at least one value must be specified".to_string(),
         );
     };
-    let values = v
-        .replace(' ', "")
-        .split(D::delimiter_symbol())
-        .map(|s| serde_plain::from_str(s).unwrap())
-        .collect();
-    Ok(AttributeList::from_vec(values))
+    let mut list = AttributeList::new();
+    for segment in v.replace(' ', "").split(D::delimiter_symbol()) {
+        if segment.is_empty() {
+            return Err(format!(
+                "empty segment in {:?}-separated list",
+                D::delimiter_symbol()
+            ));
+        }
+        match serde_plain::from_str(segment) {
+            Ok(value) => list.vec.push(value),
+            Err(_) if lenient => list.unrecognized.push(segment.to_string()),
+            Err(error) => {
+                return Err(format!(
+                    "invalid segment {:?} of {:?}-separated list: {}",
+                    segment,
+                    D::delimiter_symbol(),
+                    error
+                ));
+            }
+        }
+    }
+    Ok(list)
 }
 
 struct ListVisitor<D: Delimiter, T: Serialize + DeserializeOwned> {
@@ -152,7 +197,7 @@ impl<'de, D: Delimiter, T: Serialize + DeserializeOwned> Visitor<'de> for ListVi
     where
         E: de::Error,
     {
-        parse_list_with_delimiter(v).map_err(|e| E::custom(e))
+        parse_list_with_delimiter(v, false).map_err(|e| E::custom(e))
     }
 }
 
@@ -165,19 +210,67 @@ impl<'de, D: Delimiter, T: Serialize + DeserializeOwned> Deserialize<'de> for At
     }
 }
 
-impl<D: Delimiter, T: Serialize + DeserializeOwned> XmlDeserialize for AttributeList<D, T> {
-    fn deserialize<R: Read>(reader: &mut crate::xml::de::Deserializer<R>) -> Result<Self, String> {
+impl<D: Delimiter, T: Serialize + DeserializeOwned + KnownValue> XmlDeserialize
+    for AttributeList<D, T>
+{
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
         loop {
             match reader.next_event()? {
                 xml::reader::XmlEvent::StartElement { .. } => {}
                 xml::reader::XmlEvent::Characters(ref v) => {
-                    return parse_list_with_delimiter(v);
+                    return parse_list_with_delimiter(v, reader.options().lenient_lists)
+                        .map_err(Into::into);
                 }
                 _ => {
                     break;
                 }
             }
         }
-        Err("Unable to parse attribute".to_string())
+        Err("Unable to parse attribute".to_string().into())
+    }
+
+    fn unknown_values(&self) -> Vec<&str> {
+        self.vec
+            .iter()
+            .filter_map(KnownValue::unknown_value)
+            .chain(self.unrecognized.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_segment_is_an_error() {
+        let error = parse_list_with_delimiter::<VerticalBar, u32>("1|two|3", false).unwrap_err();
+
+        assert!(
+            error.starts_with(r#"invalid segment "two" of "|"-separated list"#),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_lenient_list_keeps_invalid_segments() {
+        let list = parse_list_with_delimiter::<VerticalBar, u32>("1|two|3", true).unwrap();
+
+        assert_eq!(list.vec(), &[1, 3]);
+        assert_eq!(list.unrecognized(), ["two"]);
+
+        let written = serde_plain::to_string(&list).unwrap();
+        assert_eq!(written, "1|3|two");
+        assert_eq!(parse_list_with_delimiter(&written, true), Ok(list));
+    }
+
+    #[test]
+    fn test_list_of_only_invalid_segments_is_not_empty() {
+        let list = parse_list_with_delimiter::<Semicolon, u32>("one;two", true).unwrap();
+
+        assert!(!list.is_empty());
+        assert_eq!(serde_plain::to_string(&list).unwrap(), "one;two");
     }
 }
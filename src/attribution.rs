@@ -0,0 +1,84 @@
+use super::extras::Extras;
+use super::resources::{Resource, StringResource};
+use serde::{Deserialize, Serialize};
+
+/// Declares an attribution tag, a logical part of the app that accesses private data.
+///
+/// Data accesses are [`audited`] per tag, so that the user and the app can tell which
+/// part of the app, such as a feature or a library, accessed the data. Components refer
+/// to their tags with the `android:attributionTags` attribute, and code passes them
+/// with [`Context.createAttributionContext()`].
+///
+/// ## XML Syntax
+/// ```xml
+/// <attribution android:tag="string"
+///              android:label="string resource" >
+///     <inherit-from android:tag="string" />
+///     ...
+/// </attribution>
+/// ```
+///
+/// ## Contained in
+/// * [`<manifest>`]
+///
+/// ## Can contain
+/// * [`<inherit-from>`]
+///
+/// ## Introduced in
+/// API Level 30
+///
+/// [`audited`]: https://developer.android.com/guide/topics/data/audit-access
+/// [`Context.createAttributionContext()`]: https://developer.android.com/reference/android/content/Context#createAttributionContext(java.lang.String)
+/// [`<manifest>`]: crate::AndroidManifest
+/// [`<inherit-from>`]: crate::InheritFrom
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct Attribution {
+    /// `Required`. The attribution tag, at most 50 characters long. Each tag must be
+    /// declared only once.
+    #[xml(attribute = true, prefix = "android")]
+    pub tag: String,
+    /// `Required`. A user-readable description of the part of the app the tag stands
+    /// for. It must be a reference to a string resource.
+    #[xml(attribute = true, prefix = "android")]
+    pub label: Option<Resource<StringResource>>,
+    /// List of `<inherit-from>` tags.
+    #[xml(rename = "inherit-from")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inherit_from: Vec<InheritFrom>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Names an attribution tag that was used by a previous version of the app and is now
+/// merged into the parent [`<attribution>`].
+///
+/// Data accesses that are attributed to the old tag are counted for the parent tag.
+///
+/// ## XML Syntax
+/// ```xml
+/// <inherit-from android:tag="string" />
+/// ```
+///
+/// ## Contained in
+/// * [`<attribution>`]
+///
+/// ## Introduced in
+/// API Level 30
+///
+/// [`<attribution>`]: crate::Attribution
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct InheritFrom {
+    /// `Required`. The attribution tag that is inherited from.
+    #[xml(attribute = true, prefix = "android")]
+    pub tag: String,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Adds a category name to an intent filter.
@@ -45,4 +46,8 @@ pub struct Category {
     /// [`startActivityForResult()`]: https://developer.android.com/reference/android/app/Activity#startActivityForResult(android.content.Intent,%20int)
     #[xml(attribute = true, prefix = "android")]
     pub name: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
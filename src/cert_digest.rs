@@ -0,0 +1,197 @@
+use crate::xml::{XmlDeserialize, XmlSerialize};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Visitor},
+};
+use std::fmt;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+/// The length of a SHA-256 digest in bytes.
+const DIGEST_LENGTH: usize = 32;
+
+/// The SHA-256 digest of a signing certificate, used by `android:certDigest`.
+///
+/// The digest can be written as plain hex, as printed by `apksigner`, or as
+/// colon-separated byte pairs, as printed by `keytool`. Both forms are accepted in
+/// either case and the original text is written back unchanged. Two digests are
+/// equal if their bytes are.
+///
+/// ## Example
+/// ```rust
+/// use android_manifest::CertDigest;
+///
+/// let plain: CertDigest = "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9"
+///     .parse()
+///     .unwrap();
+/// let colons: CertDigest = "0A:1B:2C:3D:4E:5F:60:71:82:93:A4:B5:C6:D7:E8:F9:\
+///                           0A:1B:2C:3D:4E:5F:60:71:82:93:A4:B5:C6:D7:E8:F9"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(plain, colons);
+/// assert_eq!(colons.to_hex(), plain.as_str());
+///
+/// assert!("0a:1b".parse::<CertDigest>().is_err());
+/// ```
+#[derive(Debug, Eq, Clone)]
+pub struct CertDigest {
+    text: String,
+    bytes: Vec<u8>,
+}
+
+impl CertDigest {
+    /// Creates a digest from its bytes, written as lowercase hex without separators.
+    ///
+    /// Returns an error if `bytes` is not 32 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() != DIGEST_LENGTH {
+            return Err(format!(
+                "certificate digest has {} bytes instead of {DIGEST_LENGTH}",
+                bytes.len()
+            ));
+        }
+        let text = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+        Ok(Self {
+            text,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// The digest as it was written in the manifest.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The digest as lowercase hex without separators, the form the platform compares.
+    pub fn to_hex(&self) -> String {
+        self.bytes
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    fn parse(v: &str) -> Result<Self, String> {
+        if v.is_empty() {
+            return Err("value of attribute is empty".to_string());
+        };
+        let malformed =
+            || format!("certificate digest {v:?} is not hex or colon-separated hex byte pairs");
+        if !v.chars().all(|c| c.is_ascii_hexdigit() || c == ':') {
+            return Err(malformed());
+        }
+        let pairs: Vec<&str> = if v.contains(':') {
+            v.split(':').collect()
+        } else {
+            (0..v.len())
+                .step_by(2)
+                .map(|i| &v[i..(i + 2).min(v.len())])
+                .collect()
+        };
+        let mut bytes = Vec::with_capacity(pairs.len());
+        for pair in pairs {
+            if pair.len() != 2 {
+                return Err(malformed());
+            }
+            bytes.push(u8::from_str_radix(pair, 16).map_err(|error| error.to_string())?);
+        }
+        if bytes.len() != DIGEST_LENGTH {
+            return Err(format!(
+                "certificate digest {v:?} has {} bytes instead of {DIGEST_LENGTH}",
+                bytes.len()
+            ));
+        }
+        Ok(Self {
+            text: v.to_string(),
+            bytes,
+        })
+    }
+}
+
+impl PartialEq for CertDigest {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl FromStr for CertDigest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for CertDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl Serialize for CertDigest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.text)
+    }
+}
+
+impl XmlSerialize for CertDigest {
+    fn serialize<W: Write>(
+        &self,
+        writer: &mut crate::xml::ser::Serializer<W>,
+    ) -> Result<(), String> {
+        writer
+            .write(xml::writer::XmlEvent::characters(&self.text))
+            .map_err(|error| error.to_string())
+    }
+}
+
+struct CertDigestVisitor;
+
+impl<'de> Visitor<'de> for CertDigestVisitor {
+    type Value = CertDigest;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a SHA-256 digest as hex, optionally with colons between bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        CertDigest::parse(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for CertDigest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_string(CertDigestVisitor)
+    }
+}
+
+impl XmlDeserialize for CertDigest {
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
+        loop {
+            match reader.next_event()? {
+                xml::reader::XmlEvent::StartElement { .. } => {}
+                xml::reader::XmlEvent::Characters(ref v) => {
+                    return CertDigest::parse(v).map_err(Into::into);
+                }
+                _ => {
+                    break;
+                }
+            }
+        }
+        Err("Unable to parse attribute".to_string().into())
+    }
+}
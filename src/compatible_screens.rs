@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Specifies each screen configuration with which the application is
@@ -62,6 +63,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
 pub struct CompatibleScreens {
     pub screen: Vec<Screen>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 /// Specifies a single screen configuration with which the application is
@@ -138,6 +143,10 @@ pub struct Screen {
     /// [`Supporting Multiple Screens`]: https://developer.android.com/guide/practices/screens_support#range
     #[xml(attribute = true, prefix = "android", rename = "screenDensity")]
     pub screen_density: String,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 /// Android runs on a variety of devices that have different screen sizes and pixel
@@ -169,4 +178,10 @@ pub enum ScreenSize {
     /// in a pocket and would most likely be tablet-style devices. Added in API level 9.
     #[xml(rename = "xlarge")]
     Xlarge,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(ScreenSize);
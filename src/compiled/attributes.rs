@@ -0,0 +1,1417 @@
+//! Resource IDs, value formats and symbol tables of the framework `android:`
+//! attributes known to the manifest model.
+//!
+//! IDs and symbols are taken from the platform `public.xml` and `attrs_manifest.xml`
+//! and must stay sorted by attribute name.
+
+/// The attribute accepts a reference to another resource.
+pub(crate) const FORMAT_REFERENCE: u32 = 1 << 0;
+/// The attribute accepts a string value.
+pub(crate) const FORMAT_STRING: u32 = 1 << 1;
+/// The attribute accepts an integer value.
+pub(crate) const FORMAT_INTEGER: u32 = 1 << 2;
+/// The attribute accepts a boolean value.
+pub(crate) const FORMAT_BOOLEAN: u32 = 1 << 3;
+/// The attribute accepts a color value.
+pub(crate) const FORMAT_COLOR: u32 = 1 << 4;
+/// The attribute accepts a floating point value.
+pub(crate) const FORMAT_FLOAT: u32 = 1 << 5;
+/// The attribute accepts a dimension value.
+pub(crate) const FORMAT_DIMENSION: u32 = 1 << 6;
+/// The attribute accepts a fraction value.
+pub(crate) const FORMAT_FRACTION: u32 = 1 << 7;
+/// The attribute accepts exactly one of its symbols.
+pub(crate) const FORMAT_ENUM: u32 = 1 << 16;
+/// The attribute accepts a `|` separated combination of its symbols.
+pub(crate) const FORMAT_FLAGS: u32 = 1 << 17;
+
+/// Definition of a framework attribute.
+#[derive(Debug)]
+pub(crate) struct AttributeDefinition {
+    /// Attribute name without the `android:` prefix.
+    pub name: &'static str,
+    /// Resource ID of the attribute in `android.R.attr`.
+    pub id: u32,
+    /// Combination of the `FORMAT_*` bits accepted by the attribute.
+    pub format: u32,
+    /// Enum or flag symbols and their values, ordered by value.
+    pub symbols: &'static [(&'static str, u32)],
+}
+
+impl AttributeDefinition {
+    /// Returns `true` if the attribute accepts any of the given format bits.
+    pub fn accepts(&self, format: u32) -> bool {
+        self.format & format != 0
+    }
+
+    /// Renders an integer value through the enum or flag symbols of the
+    /// attribute. Returns `None` if the value has no symbolic form.
+    pub fn symbolic(&self, value: u32) -> Option<String> {
+        if self.accepts(FORMAT_ENUM) {
+            if let Some((name, _)) = self.symbols.iter().find(|(_, v)| *v == value) {
+                return Some(name.to_string());
+            }
+        }
+        if !self.accepts(FORMAT_FLAGS) {
+            return None;
+        }
+        if value == 0 {
+            return self
+                .symbols
+                .iter()
+                .find(|(_, v)| *v == 0)
+                .map(|(name, _)| name.to_string());
+        }
+        // Consume the widest masks first, so that composite flags such as
+        // `adjustNothing` win over their individual bits.
+        let mut remaining = value;
+        let mut matched = Vec::new();
+        for (index, (_, flag)) in self.symbols.iter().enumerate().rev() {
+            if *flag != 0 && remaining & flag == *flag {
+                remaining &= !flag;
+                matched.push(index);
+            }
+        }
+        if remaining != 0 {
+            return None;
+        }
+        matched.sort_unstable();
+        let names: Vec<_> = matched.iter().map(|i| self.symbols[*i].0).collect();
+        Some(names.join("|"))
+    }
+}
+
+/// Finds the definition of the `android:` attribute with the given name.
+pub(crate) fn by_name(name: &str) -> Option<&'static AttributeDefinition> {
+    ANDROID_ATTRIBUTES
+        .binary_search_by(|def| def.name.cmp(name))
+        .ok()
+        .map(|index| &ANDROID_ATTRIBUTES[index])
+}
+
+/// Finds the definition of the `android:` attribute with the given resource ID.
+pub(crate) fn by_id(id: u32) -> Option<&'static AttributeDefinition> {
+    ANDROID_ATTRIBUTES.iter().find(|def| def.id == id)
+}
+
+pub(crate) static ANDROID_ATTRIBUTES: &[AttributeDefinition] = &[
+    AttributeDefinition {
+        name: "allowAudioPlaybackCapture",
+        id: 0x01010601,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "allowBackup",
+        id: 0x01010280,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "allowClearUserData",
+        id: 0x01010005,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "allowCrossUidActivitySwitchFromBelow",
+        id: 0x010106ca,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "allowEmbedded",
+        id: 0x010103f5,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "allowNativeHeapPointerTagging",
+        id: 0x01010612,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "allowSharedIsolatedProcess",
+        id: 0x0101067d,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "allowTaskReparenting",
+        id: 0x01010204,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "allowUntrustedActivityEmbedding",
+        id: 0x01010669,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "alwaysRetainTaskState",
+        id: 0x01010203,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "anyDensity",
+        id: 0x0101026c,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "appCategory",
+        id: 0x01010545,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("game", 0x0),
+            ("audio", 0x1),
+            ("video", 0x2),
+            ("image", 0x3),
+            ("social", 0x4),
+            ("news", 0x5),
+            ("maps", 0x6),
+            ("productivity", 0x7),
+            ("accessibility", 0x8),
+        ],
+    },
+    AttributeDefinition {
+        name: "attributionTags",
+        id: 0x01010642,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "authorities",
+        id: 0x01010018,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "autoRemoveFromRecents",
+        id: 0x01010447,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "autoRevokePermissions",
+        id: 0x01010613,
+        format: FORMAT_ENUM,
+        symbols: &[("allowed", 0x0), ("discouraged", 0x1), ("disallowed", 0x2)],
+    },
+    AttributeDefinition {
+        name: "autoVerify",
+        id: 0x010104ee,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "backgroundPermission",
+        id: 0x0101059e,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "backupAgent",
+        id: 0x0101027f,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "backupInForeground",
+        id: 0x0101051a,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "banner",
+        id: 0x010103f2,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "canDisplayOnRemoteDevices",
+        id: 0x01010650,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "certDigest",
+        id: 0x01010548,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "clearTaskOnLaunch",
+        id: 0x01010015,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "colorMode",
+        id: 0x0101054a,
+        format: FORMAT_ENUM,
+        symbols: &[("default", 0x0), ("wideColorGamut", 0x1), ("hdr", 0x2)],
+    },
+    AttributeDefinition {
+        name: "compatibleWidthLimitDp",
+        id: 0x01010365,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "configChanges",
+        id: 0x0101001f,
+        format: FORMAT_FLAGS,
+        symbols: &[
+            ("mcc", 0x1),
+            ("mnc", 0x2),
+            ("locale", 0x4),
+            ("touchscreen", 0x8),
+            ("keyboard", 0x10),
+            ("keyboardHidden", 0x20),
+            ("navigation", 0x40),
+            ("orientation", 0x80),
+            ("screenLayout", 0x100),
+            ("uiMode", 0x200),
+            ("screenSize", 0x400),
+            ("smallestScreenSize", 0x800),
+            ("density", 0x1000),
+            ("layoutDirection", 0x2000),
+            ("colorMode", 0x4000),
+            ("grammaticalGender", 0x8000),
+            ("resourcesUnused", 0x8000000),
+            ("fontWeightAdjustment", 0x10000000),
+            ("fontScale", 0x40000000),
+            ("assetsPaths", 0x80000000),
+        ],
+    },
+    AttributeDefinition {
+        name: "crossProfile",
+        id: 0x0101060f,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "dataExtractionRules",
+        id: 0x0101063e,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "debuggable",
+        id: 0x0101000f,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "defaultHeight",
+        id: 0x010104f5,
+        format: FORMAT_DIMENSION | FORMAT_FRACTION,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "defaultWidth",
+        id: 0x010104f4,
+        format: FORMAT_DIMENSION | FORMAT_FRACTION,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "description",
+        id: 0x01010020,
+        format: FORMAT_REFERENCE | FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "directBootAware",
+        id: 0x01010505,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "documentLaunchMode",
+        id: 0x01010445,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("none", 0x0),
+            ("intoExisting", 0x1),
+            ("always", 0x2),
+            ("never", 0x3),
+        ],
+    },
+    AttributeDefinition {
+        name: "enableOnBackInvokedCallback",
+        id: 0x0101066c,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "enabled",
+        id: 0x0101000e,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "excludeFromRecents",
+        id: 0x01010017,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "exported",
+        id: 0x01010010,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "externalService",
+        id: 0x0101050e,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "extractNativeLibs",
+        id: 0x010104ea,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "finishOnTaskLaunch",
+        id: 0x01010014,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "forceUriPermissions",
+        id: 0x0101059f,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "foregroundServiceType",
+        id: 0x01010599,
+        format: FORMAT_FLAGS,
+        symbols: &[
+            ("dataSync", 0x1),
+            ("mediaPlayback", 0x2),
+            ("phoneCall", 0x4),
+            ("location", 0x8),
+            ("connectedDevice", 0x10),
+            ("mediaProjection", 0x20),
+            ("camera", 0x40),
+            ("microphone", 0x80),
+            ("health", 0x100),
+            ("remoteMessaging", 0x200),
+            ("systemExempted", 0x400),
+            ("shortService", 0x800),
+            ("mediaProcessing", 0x2000),
+            ("specialUse", 0x40000000),
+        ],
+    },
+    AttributeDefinition {
+        name: "fragment",
+        id: 0x010106b5,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "fragmentAdvancedPattern",
+        id: 0x010106b9,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "fragmentPattern",
+        id: 0x010106b7,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "fragmentPrefix",
+        id: 0x010106b6,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "fragmentSuffix",
+        id: 0x010106b8,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "fullBackupContent",
+        id: 0x010104eb,
+        format: FORMAT_REFERENCE | FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "fullBackupOnly",
+        id: 0x01010473,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "functionalTest",
+        id: 0x01010023,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "glEsVersion",
+        id: 0x01010281,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "grantUriPermissions",
+        id: 0x0101001b,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "gravity",
+        id: 0x010100af,
+        format: FORMAT_FLAGS,
+        symbols: &[
+            ("center_horizontal", 0x1),
+            ("left", 0x3),
+            ("right", 0x5),
+            ("fill_horizontal", 0x7),
+            ("clip_horizontal", 0x8),
+            ("center_vertical", 0x10),
+            ("center", 0x11),
+            ("top", 0x30),
+            ("bottom", 0x50),
+            ("fill_vertical", 0x70),
+            ("fill", 0x77),
+            ("clip_vertical", 0x80),
+            ("start", 0x800003),
+            ("end", 0x800005),
+        ],
+    },
+    AttributeDefinition {
+        name: "gwpAsanMode",
+        id: 0x01010616,
+        format: FORMAT_ENUM,
+        symbols: &[("never", 0x0), ("always", 0x1), ("default", 0xffffffff)],
+    },
+    AttributeDefinition {
+        name: "handleProfiling",
+        id: 0x01010022,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "hardwareAccelerated",
+        id: 0x010102d3,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "hasCode",
+        id: 0x0101000c,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "hasFragileUserData",
+        id: 0x0101059a,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "host",
+        id: 0x01010028,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "icon",
+        id: 0x01010002,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "immersive",
+        id: 0x010102c0,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "initOrder",
+        id: 0x0101001a,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "installLocation",
+        id: 0x010102b7,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("auto", 0x0),
+            ("internalOnly", 0x1),
+            ("preferExternal", 0x2),
+        ],
+    },
+    AttributeDefinition {
+        name: "isFeatureSplit",
+        id: 0x0101055b,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "isGame",
+        id: 0x010103f4,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "isolatedProcess",
+        id: 0x010103a9,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "isolatedSplits",
+        id: 0x0101054b,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "killAfterRestore",
+        id: 0x0101029c,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "knownActivityEmbeddingCerts",
+        id: 0x0101066a,
+        format: FORMAT_REFERENCE | FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "knownCerts",
+        id: 0x0101062a,
+        format: FORMAT_REFERENCE | FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "label",
+        id: 0x01010001,
+        format: FORMAT_REFERENCE | FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "largeHeap",
+        id: 0x0101035a,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "largeScreens",
+        id: 0x01010286,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "largestWidthLimitDp",
+        id: 0x01010366,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "launchMode",
+        id: 0x0101001d,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("standard", 0x0),
+            ("singleTop", 0x1),
+            ("singleTask", 0x2),
+            ("singleInstance", 0x3),
+            ("singleInstancePerTask", 0x4),
+        ],
+    },
+    AttributeDefinition {
+        name: "localeConfig",
+        id: 0x0101065b,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "lockTaskMode",
+        id: 0x010104ed,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("normal", 0x0),
+            ("never", 0x1),
+            ("always", 0x2),
+            ("if_whitelisted", 0x3),
+        ],
+    },
+    AttributeDefinition {
+        name: "logo",
+        id: 0x010102be,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "manageSpaceActivity",
+        id: 0x01010004,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "maxAspectRatio",
+        id: 0x01010560,
+        format: FORMAT_FLOAT,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "maxRecents",
+        id: 0x01010446,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "maxSdkVersion",
+        id: 0x01010271,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "memtagMode",
+        id: 0x01010624,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("off", 0x0),
+            ("async", 0x1),
+            ("sync", 0x2),
+            ("default", 0xffffffff),
+        ],
+    },
+    AttributeDefinition {
+        name: "mimeType",
+        id: 0x01010026,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "minAspectRatio",
+        id: 0x0101059b,
+        format: FORMAT_FLOAT,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "minHeight",
+        id: 0x01010140,
+        format: FORMAT_DIMENSION | FORMAT_FRACTION,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "minSdkVersion",
+        id: 0x0101020c,
+        format: FORMAT_INTEGER | FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "minWidth",
+        id: 0x0101013f,
+        format: FORMAT_DIMENSION | FORMAT_FRACTION,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "multiprocess",
+        id: 0x01010013,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "name",
+        id: 0x01010003,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "networkSecurityConfig",
+        id: 0x01010527,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "noHistory",
+        id: 0x0101022d,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "normalScreens",
+        id: 0x01010285,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "order",
+        id: 0x010101ea,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "packageType",
+        id: 0x01010587,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "pageSizeCompat",
+        id: 0x010106f5,
+        format: FORMAT_ENUM,
+        symbols: &[("enabled", 0x20), ("disabled", 0x40)],
+    },
+    AttributeDefinition {
+        name: "parentActivityName",
+        id: 0x010103a7,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "path",
+        id: 0x0101002a,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "pathAdvancedPattern",
+        id: 0x01010620,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "pathPattern",
+        id: 0x0101002c,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "pathPrefix",
+        id: 0x0101002b,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "pathSuffix",
+        id: 0x0101061e,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "permission",
+        id: 0x01010006,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "permissionFlags",
+        id: 0x010103c7,
+        format: FORMAT_FLAGS,
+        symbols: &[
+            ("costsMoney", 0x1),
+            ("removed", 0x2),
+            ("hardRestricted", 0x4),
+            ("softRestricted", 0x8),
+            ("immutablyRestricted", 0x10),
+            ("installerExemptIgnored", 0x20),
+        ],
+    },
+    AttributeDefinition {
+        name: "permissionGroup",
+        id: 0x0101000a,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "persistableMode",
+        id: 0x0101042d,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("persistRootOnly", 0x0),
+            ("persistNever", 0x1),
+            ("persistAcrossReboots", 0x2),
+        ],
+    },
+    AttributeDefinition {
+        name: "persistent",
+        id: 0x0101000d,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "port",
+        id: 0x01010029,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "preferMinimalPostProcessing",
+        id: 0x0101060c,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "preserveLegacyExternalStorage",
+        id: 0x01010614,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "priority",
+        id: 0x0101001c,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "process",
+        id: 0x01010011,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "protectionLevel",
+        id: 0x01010009,
+        format: FORMAT_FLAGS,
+        symbols: &[
+            ("normal", 0x0),
+            ("dangerous", 0x1),
+            ("signature", 0x2),
+            ("signatureOrSystem", 0x3),
+            ("internal", 0x4),
+            ("privileged", 0x10),
+            ("development", 0x20),
+            ("appop", 0x40),
+            ("pre23", 0x80),
+            ("installer", 0x100),
+            ("verifier", 0x200),
+            ("preinstalled", 0x400),
+            ("setup", 0x800),
+            ("instant", 0x1000),
+            ("runtime", 0x2000),
+            ("oem", 0x4000),
+            ("vendorPrivileged", 0x8000),
+            ("textClassifier", 0x10000),
+            ("configurator", 0x80000),
+            ("incidentReportApprover", 0x100000),
+            ("appPredictor", 0x200000),
+            ("module", 0x400000),
+            ("companion", 0x800000),
+            ("retailDemo", 0x1000000),
+            ("recents", 0x2000000),
+            ("role", 0x4000000),
+            ("knownSigner", 0x8000000),
+        ],
+    },
+    AttributeDefinition {
+        name: "query",
+        id: 0x010106b0,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "queryAdvancedPattern",
+        id: 0x010106b4,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "queryPattern",
+        id: 0x010106b2,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "queryPrefix",
+        id: 0x010106b1,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "querySuffix",
+        id: 0x010106b3,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "readPermission",
+        id: 0x01010007,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "recreateOnConfigChanges",
+        id: 0x01010682,
+        format: FORMAT_FLAGS,
+        symbols: &[("mcc", 0x1), ("mnc", 0x2)],
+    },
+    AttributeDefinition {
+        name: "relinquishTaskIdentity",
+        id: 0x01010476,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "reqFiveWayNav",
+        id: 0x01010232,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "reqHardKeyboard",
+        id: 0x01010229,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "reqKeyboardType",
+        id: 0x01010228,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("undefined", 0x0),
+            ("nokeys", 0x1),
+            ("qwerty", 0x2),
+            ("twelvekey", 0x3),
+        ],
+    },
+    AttributeDefinition {
+        name: "reqNavigation",
+        id: 0x0101022a,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("undefined", 0x0),
+            ("nonav", 0x1),
+            ("dpad", 0x2),
+            ("trackball", 0x3),
+            ("wheel", 0x4),
+        ],
+    },
+    AttributeDefinition {
+        name: "reqTouchScreen",
+        id: 0x01010227,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("undefined", 0x0),
+            ("notouch", 0x1),
+            ("stylus", 0x2),
+            ("finger", 0x3),
+        ],
+    },
+    AttributeDefinition {
+        name: "requestLegacyExternalStorage",
+        id: 0x01010603,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "requestRawExternalStorageAccess",
+        id: 0x01010645,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "required",
+        id: 0x0101028e,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "requiredAccountType",
+        id: 0x010103d6,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "requiredDisplayCategory",
+        id: 0x0101067a,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "requiredFeature",
+        id: 0x01010554,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "requiredNotFeature",
+        id: 0x01010555,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "requiredSplitTypes",
+        id: 0x0101067f,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "requiresSmallestWidthDp",
+        id: 0x01010364,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "resizeable",
+        id: 0x0101028d,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "resizeableActivity",
+        id: 0x010104f6,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "resource",
+        id: 0x01010025,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "restoreAnyVersion",
+        id: 0x010102ba,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "restrictedAccountType",
+        id: 0x010103d5,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "rotationAnimation",
+        id: 0x0101053a,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("rotate", 0x0),
+            ("crossfade", 0x1),
+            ("jumpcut", 0x2),
+            ("seamless", 0x3),
+        ],
+    },
+    AttributeDefinition {
+        name: "scheme",
+        id: 0x01010027,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "screenDensity",
+        id: 0x010102cb,
+        format: FORMAT_INTEGER | FORMAT_ENUM,
+        symbols: &[
+            ("ldpi", 0x78),
+            ("mdpi", 0xa0),
+            ("hdpi", 0xf0),
+            ("xhdpi", 0x140),
+            ("xxhdpi", 0x1e0),
+            ("xxxhdpi", 0x280),
+        ],
+    },
+    AttributeDefinition {
+        name: "screenOrientation",
+        id: 0x0101001e,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("landscape", 0x0),
+            ("portrait", 0x1),
+            ("user", 0x2),
+            ("behind", 0x3),
+            ("sensor", 0x4),
+            ("nosensor", 0x5),
+            ("sensorLandscape", 0x6),
+            ("sensorPortrait", 0x7),
+            ("reverseLandscape", 0x8),
+            ("reversePortrait", 0x9),
+            ("fullSensor", 0xa),
+            ("userLandscape", 0xb),
+            ("userPortrait", 0xc),
+            ("fullUser", 0xd),
+            ("locked", 0xe),
+            ("unspecified", 0xffffffff),
+        ],
+    },
+    AttributeDefinition {
+        name: "screenSize",
+        id: 0x010102ca,
+        format: FORMAT_ENUM,
+        symbols: &[
+            ("small", 0xc8),
+            ("normal", 0x12c),
+            ("large", 0x190),
+            ("xlarge", 0x1f4),
+        ],
+    },
+    AttributeDefinition {
+        name: "sharedUserId",
+        id: 0x0101000b,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "sharedUserLabel",
+        id: 0x01010261,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "shell",
+        id: 0x01010594,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "showForAllUsers",
+        id: 0x010104ef,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "showWhenLocked",
+        id: 0x01010569,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "smallScreens",
+        id: 0x01010284,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "splitTypes",
+        id: 0x0101067e,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "ssp",
+        id: 0x010103e3,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "sspAdvancedPattern",
+        id: 0x01010621,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "sspPattern",
+        id: 0x010103e5,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "sspPrefix",
+        id: 0x010103e4,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "sspSuffix",
+        id: 0x0101061f,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "stateNotNeeded",
+        id: 0x01010016,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "stopWithTask",
+        id: 0x0101036f,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "supportsPictureInPicture",
+        id: 0x010104f7,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "supportsRtl",
+        id: 0x010103af,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "syncable",
+        id: 0x01010019,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "tag",
+        id: 0x010100d1,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "targetActivity",
+        id: 0x01010202,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "targetPackage",
+        id: 0x01010021,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "targetProcesses",
+        id: 0x01010541,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "targetSandboxVersion",
+        id: 0x0101054c,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "targetSdkVersion",
+        id: 0x01010270,
+        format: FORMAT_INTEGER | FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "taskAffinity",
+        id: 0x01010012,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "testOnly",
+        id: 0x01010272,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "theme",
+        id: 0x01010000,
+        format: FORMAT_REFERENCE,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "turnScreenOn",
+        id: 0x0101056a,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "uiOptions",
+        id: 0x01010398,
+        format: FORMAT_FLAGS,
+        symbols: &[("none", 0x0), ("splitActionBarWhenNarrow", 0x1)],
+    },
+    AttributeDefinition {
+        name: "useAppZygote",
+        id: 0x01010597,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "usesCleartextTraffic",
+        id: 0x010104ec,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "usesNonSdkApi",
+        id: 0x010105b7,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "usesPermissionFlags",
+        id: 0x01010644,
+        format: FORMAT_FLAGS,
+        symbols: &[("neverForLocation", 0x10000)],
+    },
+    AttributeDefinition {
+        name: "value",
+        id: 0x01010024,
+        format: FORMAT_STRING | FORMAT_INTEGER | FORMAT_COLOR | FORMAT_FLOAT | FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "version",
+        id: 0x01010519,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "versionCode",
+        id: 0x0101021b,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "versionMajor",
+        id: 0x01010576,
+        format: FORMAT_INTEGER,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "versionName",
+        id: 0x0101021c,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "vmSafeMode",
+        id: 0x010102b8,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "windowSoftInputMode",
+        id: 0x0101022b,
+        format: FORMAT_FLAGS,
+        symbols: &[
+            ("stateUnspecified", 0x0),
+            ("stateUnchanged", 0x1),
+            ("stateHidden", 0x2),
+            ("stateAlwaysHidden", 0x3),
+            ("stateVisible", 0x4),
+            ("stateAlwaysVisible", 0x5),
+            ("adjustResize", 0x10),
+            ("adjustPan", 0x20),
+            ("adjustNothing", 0x30),
+        ],
+    },
+    AttributeDefinition {
+        name: "writePermission",
+        id: 0x01010008,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "xlargeScreens",
+        id: 0x010102bf,
+        format: FORMAT_BOOLEAN,
+        symbols: &[],
+    },
+    AttributeDefinition {
+        name: "zygotePreloadName",
+        id: 0x0101059d,
+        format: FORMAT_STRING,
+        symbols: &[],
+    },
+];
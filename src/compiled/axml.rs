@@ -0,0 +1,552 @@
+//! Android binary XML (AXML), the chunked format `aapt`/`aapt2` store
+//! `AndroidManifest.xml` as inside an APK.
+
+use super::{
+    ANDROID_NAMESPACE, CompiledValue, Node, ResourceIdMap, attributes, data_type, read_nodes,
+    render_value,
+};
+use std::collections::HashMap;
+use xml::name::Name;
+use xml::writer::XmlEvent;
+use xml::{EmitterConfig, EventWriter};
+
+pub(crate) const RES_STRING_POOL_TYPE: u16 = 0x0001;
+pub(crate) const RES_XML_TYPE: u16 = 0x0003;
+pub(crate) const RES_XML_START_NAMESPACE_TYPE: u16 = 0x0100;
+pub(crate) const RES_XML_END_NAMESPACE_TYPE: u16 = 0x0101;
+pub(crate) const RES_XML_START_ELEMENT_TYPE: u16 = 0x0102;
+pub(crate) const RES_XML_END_ELEMENT_TYPE: u16 = 0x0103;
+pub(crate) const RES_XML_CDATA_TYPE: u16 = 0x0104;
+pub(crate) const RES_XML_RESOURCE_MAP_TYPE: u16 = 0x0180;
+
+pub(crate) const UTF8_FLAG: u32 = 1 << 8;
+pub(crate) const NO_INDEX: u32 = 0xffff_ffff;
+
+const CHUNK_HEADER_SIZE: u16 = 8;
+const STRING_POOL_HEADER_SIZE: u16 = 28;
+const NODE_HEADER_SIZE: u16 = 16;
+const ATTRIBUTE_EXT_SIZE: u16 = 20;
+const ATTRIBUTE_SIZE: u16 = 20;
+const RES_VALUE_SIZE: u16 = 8;
+
+/// Returns `true` if `bytes` start with an AXML document header.
+pub(crate) fn is_axml(bytes: &[u8]) -> bool {
+    bytes.len() >= 8
+        && u16::from_le_bytes([bytes[0], bytes[1]]) == RES_XML_TYPE
+        && u16::from_le_bytes([bytes[2], bytes[3]]) == CHUNK_HEADER_SIZE
+}
+
+/// Decodes an AXML document into the equivalent XML text.
+pub(crate) fn decode(bytes: &[u8]) -> Result<String, String> {
+    let document = Chunk::read(bytes, 0)?;
+    if document.kind != RES_XML_TYPE {
+        return Err(format!(
+            "not a binary XML document, found chunk type 0x{:04x}",
+            document.kind
+        ));
+    }
+    let mut decoder = Decoder {
+        strings: Vec::new(),
+        resource_ids: Vec::new(),
+        namespaces: Vec::new(),
+        pending_namespaces: Vec::new(),
+        writer: EventWriter::new_with_config(
+            Vec::new(),
+            EmitterConfig::new().write_document_declaration(true),
+        ),
+    };
+    let mut offset = document.header_size;
+    while offset < document.size {
+        let chunk = Chunk::read(bytes, offset)?;
+        decoder.chunk(bytes, &chunk)?;
+        offset += chunk.size;
+    }
+    String::from_utf8(decoder.writer.into_inner()).map_err(|error| error.to_string())
+}
+
+/// Encodes XML text into an AXML document, compiling the values of framework
+/// `android:` attributes into typed values and resolving resource references
+/// through `resources`.
+pub(crate) fn encode(xml: &str, resources: &ResourceIdMap) -> Result<Vec<u8>, String> {
+    let nodes = read_nodes(xml, resources)?;
+
+    // Names of attributes with a resource ID lead the string pool, in the order
+    // of the resource map that follows it.
+    let mut resource_ids: Vec<(u32, &str)> = nodes
+        .iter()
+        .flat_map(|node| match node {
+            Node::StartElement { attributes, .. } => attributes.as_slice(),
+            _ => &[],
+        })
+        .filter_map(|attribute| Some((attribute.resource_id?, attribute.name.as_str())))
+        .collect();
+    resource_ids.sort_unstable();
+    resource_ids.dedup();
+    let mut pool = StringPool {
+        strings: resource_ids
+            .iter()
+            .map(|(_, name)| name.to_string())
+            .collect(),
+        indices: HashMap::new(),
+    };
+
+    let mut body = Vec::new();
+    for node in &nodes {
+        match node {
+            Node::StartNamespace { prefix, uri, line }
+            | Node::EndNamespace { prefix, uri, line } => {
+                let kind = match node {
+                    Node::StartNamespace { .. } => RES_XML_START_NAMESPACE_TYPE,
+                    _ => RES_XML_END_NAMESPACE_TYPE,
+                };
+                write_node_header(&mut body, kind, 8, *line);
+                write_u32(&mut body, pool.intern(prefix));
+                write_u32(&mut body, pool.intern(uri));
+            }
+            Node::StartElement {
+                namespace,
+                name,
+                attributes,
+                line,
+            } => {
+                let size = ATTRIBUTE_EXT_SIZE as usize + attributes.len() * ATTRIBUTE_SIZE as usize;
+                write_node_header(&mut body, RES_XML_START_ELEMENT_TYPE, size, *line);
+                write_u32(&mut body, pool.intern_optional(namespace.as_deref()));
+                write_u32(&mut body, pool.intern(name));
+                write_u16(&mut body, ATTRIBUTE_EXT_SIZE);
+                write_u16(&mut body, ATTRIBUTE_SIZE);
+                write_u16(&mut body, attributes.len() as u16);
+                // Indices of the `id`, `class` and `style` attributes, unused in manifests.
+                write_u16(&mut body, 0);
+                write_u16(&mut body, 0);
+                write_u16(&mut body, 0);
+                for attribute in attributes {
+                    let name = match attribute.resource_id {
+                        Some(id) => resource_ids
+                            .binary_search(&(id, attribute.name.as_str()))
+                            .map_err(|_| format!("missing resource ID for {}", attribute.name))?
+                            as u32,
+                        None => pool.intern(&attribute.name),
+                    };
+                    let (raw_value, data_type, data) = match attribute.value {
+                        CompiledValue::String => {
+                            let index = pool.intern(&attribute.raw_value);
+                            (index, data_type::STRING, index)
+                        }
+                        CompiledValue::Typed { data_type, data } => (NO_INDEX, data_type, data),
+                    };
+                    write_u32(
+                        &mut body,
+                        pool.intern_optional(attribute.namespace.as_deref()),
+                    );
+                    write_u32(&mut body, name);
+                    write_u32(&mut body, raw_value);
+                    write_res_value(&mut body, data_type, data);
+                }
+            }
+            Node::EndElement {
+                namespace,
+                name,
+                line,
+            } => {
+                write_node_header(&mut body, RES_XML_END_ELEMENT_TYPE, 8, *line);
+                write_u32(&mut body, pool.intern_optional(namespace.as_deref()));
+                write_u32(&mut body, pool.intern(name));
+            }
+            Node::Text { text, line } => {
+                write_node_header(&mut body, RES_XML_CDATA_TYPE, 12, *line);
+                let index = pool.intern(text);
+                write_u32(&mut body, index);
+                write_res_value(&mut body, data_type::STRING, index);
+            }
+        }
+    }
+
+    let string_pool = pool.encode()?;
+    let resource_map_size = CHUNK_HEADER_SIZE as usize + resource_ids.len() * 4;
+    let mut document = Vec::new();
+    write_chunk_header(
+        &mut document,
+        RES_XML_TYPE,
+        CHUNK_HEADER_SIZE,
+        CHUNK_HEADER_SIZE as usize + string_pool.len() + resource_map_size + body.len(),
+    );
+    document.extend_from_slice(&string_pool);
+    write_chunk_header(
+        &mut document,
+        RES_XML_RESOURCE_MAP_TYPE,
+        CHUNK_HEADER_SIZE,
+        resource_map_size,
+    );
+    for (id, _) in &resource_ids {
+        write_u32(&mut document, *id);
+    }
+    document.extend_from_slice(&body);
+    Ok(document)
+}
+
+/// UTF-16 string pool under construction.
+struct StringPool {
+    strings: Vec<String>,
+    indices: HashMap<String, u32>,
+}
+
+impl StringPool {
+    fn intern(&mut self, string: &str) -> u32 {
+        if let Some(index) = self.indices.get(string) {
+            return *index;
+        }
+        let index = self.strings.len() as u32;
+        self.strings.push(string.to_string());
+        self.indices.insert(string.to_string(), index);
+        index
+    }
+
+    fn intern_optional(&mut self, string: Option<&str>) -> u32 {
+        string.map_or(NO_INDEX, |string| self.intern(string))
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, String> {
+        let mut offsets = Vec::with_capacity(self.strings.len());
+        let mut data = Vec::new();
+        for string in &self.strings {
+            offsets.push(data.len() as u32);
+            let units: Vec<u16> = string.encode_utf16().collect();
+            if units.len() > 0x7fff_ffff {
+                return Err(format!("string of {} characters is too long", units.len()));
+            }
+            if units.len() > 0x7fff {
+                write_u16(&mut data, 0x8000 | (units.len() >> 16) as u16);
+            }
+            write_u16(&mut data, units.len() as u16);
+            for unit in units {
+                write_u16(&mut data, unit);
+            }
+            write_u16(&mut data, 0);
+        }
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+        let strings_start = STRING_POOL_HEADER_SIZE as usize + offsets.len() * 4;
+        let mut pool = Vec::with_capacity(strings_start + data.len());
+        write_chunk_header(
+            &mut pool,
+            RES_STRING_POOL_TYPE,
+            STRING_POOL_HEADER_SIZE,
+            strings_start + data.len(),
+        );
+        write_u32(&mut pool, offsets.len() as u32);
+        // Style count, flags (UTF-16) and styles start.
+        write_u32(&mut pool, 0);
+        write_u32(&mut pool, 0);
+        write_u32(&mut pool, strings_start as u32);
+        write_u32(&mut pool, 0);
+        for offset in offsets {
+            write_u32(&mut pool, offset);
+        }
+        pool.extend_from_slice(&data);
+        Ok(pool)
+    }
+}
+
+fn write_chunk_header(buffer: &mut Vec<u8>, kind: u16, header_size: u16, size: usize) {
+    write_u16(buffer, kind);
+    write_u16(buffer, header_size);
+    write_u32(buffer, size as u32);
+}
+
+fn write_node_header(buffer: &mut Vec<u8>, kind: u16, body_size: usize, line: u32) {
+    write_chunk_header(
+        buffer,
+        kind,
+        NODE_HEADER_SIZE,
+        NODE_HEADER_SIZE as usize + body_size,
+    );
+    write_u32(buffer, line);
+    // Comment index.
+    write_u32(buffer, NO_INDEX);
+}
+
+fn write_res_value(buffer: &mut Vec<u8>, data_type: u8, data: u32) {
+    write_u16(buffer, RES_VALUE_SIZE);
+    buffer.push(0);
+    buffer.push(data_type);
+    write_u32(buffer, data);
+}
+
+fn write_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_u32(buffer: &mut Vec<u8>, value: u32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Header shared by every chunk.
+struct Chunk {
+    kind: u16,
+    start: usize,
+    header_size: usize,
+    size: usize,
+}
+
+impl Chunk {
+    fn read(bytes: &[u8], start: usize) -> Result<Chunk, String> {
+        let chunk = Chunk {
+            kind: read_u16(bytes, start)?,
+            start,
+            header_size: read_u16(bytes, start + 2)? as usize,
+            size: read_u32(bytes, start + 4)? as usize,
+        };
+        if chunk.header_size < 8 || chunk.size < chunk.header_size {
+            return Err(format!("malformed chunk header at offset {start}"));
+        }
+        if start + chunk.size > bytes.len() {
+            return Err(format!(
+                "chunk at offset {start} is truncated: {} of {} bytes available",
+                bytes.len() - start,
+                chunk.size
+            ));
+        }
+        Ok(chunk)
+    }
+
+    fn body(&self) -> usize {
+        self.start + self.header_size
+    }
+}
+
+struct Decoder {
+    strings: Vec<String>,
+    resource_ids: Vec<u32>,
+    /// Namespaces currently in scope as `(prefix, uri)`, innermost last.
+    namespaces: Vec<(String, String)>,
+    /// Namespaces opened since the last start element.
+    pending_namespaces: Vec<(String, String)>,
+    writer: EventWriter<Vec<u8>>,
+}
+
+impl Decoder {
+    fn chunk(&mut self, bytes: &[u8], chunk: &Chunk) -> Result<(), String> {
+        match chunk.kind {
+            RES_STRING_POOL_TYPE => self.strings = read_string_pool(bytes, chunk)?,
+            RES_XML_RESOURCE_MAP_TYPE => {
+                self.resource_ids = (chunk.body()..chunk.start + chunk.size)
+                    .step_by(4)
+                    .map(|offset| read_u32(bytes, offset))
+                    .collect::<Result<_, _>>()?;
+            }
+            RES_XML_START_NAMESPACE_TYPE => {
+                let prefix = self.string(read_u32(bytes, chunk.body())?)?;
+                let uri = self.string(read_u32(bytes, chunk.body() + 4)?)?;
+                self.namespaces.push((prefix.clone(), uri.clone()));
+                self.pending_namespaces.push((prefix, uri));
+            }
+            RES_XML_END_NAMESPACE_TYPE => {
+                self.namespaces.pop();
+            }
+            RES_XML_START_ELEMENT_TYPE => self.start_element(bytes, chunk)?,
+            RES_XML_END_ELEMENT_TYPE => {
+                self.writer
+                    .write(XmlEvent::end_element())
+                    .map_err(|error| error.to_string())?;
+            }
+            RES_XML_CDATA_TYPE => {
+                let text = self.string(read_u32(bytes, chunk.body())?)?;
+                self.writer
+                    .write(XmlEvent::characters(&text))
+                    .map_err(|error| error.to_string())?;
+            }
+            _ => log::debug!("Skipping AXML chunk of type 0x{:04x}", chunk.kind),
+        }
+        Ok(())
+    }
+
+    fn start_element(&mut self, bytes: &[u8], chunk: &Chunk) -> Result<(), String> {
+        let body = chunk.body();
+        let namespace = self.optional_string(read_u32(bytes, body)?)?;
+        let name = self.string(read_u32(bytes, body + 4)?)?;
+        let attribute_start = read_u16(bytes, body + 8)? as usize;
+        let attribute_size = read_u16(bytes, body + 10)? as usize;
+        let attribute_count = read_u16(bytes, body + 12)? as usize;
+
+        let mut declared = std::mem::take(&mut self.pending_namespaces);
+        let mut attrs = Vec::with_capacity(attribute_count);
+        for index in 0..attribute_count {
+            let offset = body + attribute_start + index * attribute_size;
+            let namespace = self.optional_string(read_u32(bytes, offset)?)?;
+            let name_index = read_u32(bytes, offset + 4)?;
+            let raw_value = read_u32(bytes, offset + 8)?;
+            let data_type = read_u8(bytes, offset + 15)?;
+            let data = read_u32(bytes, offset + 16)?;
+
+            let resource_id = self.resource_ids.get(name_index as usize).copied();
+            let definition = resource_id.and_then(attributes::by_id);
+            let mut name = self.string(name_index)?;
+            if name.is_empty() {
+                // Obfuscated manifests strip the names of framework attributes and
+                // only keep their resource IDs.
+                match definition {
+                    Some(definition) => name = definition.name.to_string(),
+                    None => {
+                        log::debug!("Skipping AXML attribute without a name: {resource_id:?}");
+                        continue;
+                    }
+                }
+            }
+            let definition = definition.or_else(|| match namespace.as_deref() {
+                Some(ANDROID_NAMESPACE) => attributes::by_name(&name),
+                _ => None,
+            });
+
+            let value = if raw_value != NO_INDEX && data_type == data_type::STRING {
+                Some(self.string(raw_value)?)
+            } else {
+                render_value(data_type, data, definition, |index| self.string(index))?
+            };
+            let Some(value) = value else {
+                continue;
+            };
+            let prefix = namespace
+                .as_ref()
+                .map(|uri| self.prefix_for(uri, &mut declared));
+            attrs.push((prefix, name, value));
+        }
+
+        let element_prefix = namespace
+            .as_ref()
+            .map(|uri| self.prefix_for(uri, &mut declared));
+        let element_name = match &element_prefix {
+            Some(prefix) => Name::prefixed(&name, prefix),
+            None => Name::local(&name),
+        };
+        let mut event = XmlEvent::start_element(element_name);
+        for (prefix, uri) in &declared {
+            event = event.ns(prefix.as_str(), uri.as_str());
+        }
+        for (prefix, name, value) in &attrs {
+            let attr_name = match prefix {
+                Some(prefix) => Name::prefixed(name, prefix),
+                None => Name::local(name),
+            };
+            event = event.attr(attr_name, value);
+        }
+        self.writer.write(event).map_err(|error| error.to_string())
+    }
+
+    /// Finds the prefix bound to `uri`, declaring a new one on the current
+    /// element if the document never declared the namespace.
+    fn prefix_for(&self, uri: &str, declared: &mut Vec<(String, String)>) -> String {
+        if let Some((prefix, _)) = self
+            .namespaces
+            .iter()
+            .chain(declared.iter())
+            .rev()
+            .find(|(_, known)| known == uri)
+        {
+            return prefix.clone();
+        }
+        let prefix = if uri == ANDROID_NAMESPACE {
+            "android".to_string()
+        } else {
+            format!("ns{}", declared.len())
+        };
+        declared.push((prefix.clone(), uri.to_string()));
+        prefix
+    }
+
+    fn string(&self, index: u32) -> Result<String, String> {
+        self.strings
+            .get(index as usize)
+            .cloned()
+            .ok_or_else(|| format!("string pool index {index} is out of bounds"))
+    }
+
+    fn optional_string(&self, index: u32) -> Result<Option<String>, String> {
+        if index == NO_INDEX {
+            Ok(None)
+        } else {
+            self.string(index).map(Some)
+        }
+    }
+}
+
+fn read_string_pool(bytes: &[u8], chunk: &Chunk) -> Result<Vec<String>, String> {
+    let count = read_u32(bytes, chunk.start + 8)? as usize;
+    // Every string has a 4-byte offset in the chunk body, so a count that doesn't
+    // fit is malformed and must not be trusted for allocation.
+    if count > (chunk.size - chunk.header_size) / 4 {
+        return Err(format!(
+            "string pool at offset {} declares {count} strings but has room for fewer",
+            chunk.start
+        ));
+    }
+    let flags = read_u32(bytes, chunk.start + 16)?;
+    let strings_start = chunk.start + read_u32(bytes, chunk.start + 20)? as usize;
+    let mut strings = Vec::with_capacity(count);
+    for index in 0..count {
+        let offset = strings_start + read_u32(bytes, chunk.body() + index * 4)? as usize;
+        let string = if flags & UTF8_FLAG != 0 {
+            read_utf8_string(bytes, offset)?
+        } else {
+            read_utf16_string(bytes, offset)?
+        };
+        strings.push(string);
+    }
+    Ok(strings)
+}
+
+fn read_utf8_string(bytes: &[u8], offset: usize) -> Result<String, String> {
+    // The UTF-16 length comes first and is only useful for preallocation.
+    let (_, offset) = read_utf8_length(bytes, offset)?;
+    let (length, offset) = read_utf8_length(bytes, offset)?;
+    let data = bytes
+        .get(offset..offset + length)
+        .ok_or_else(|| format!("string at offset {offset} is truncated"))?;
+    String::from_utf8(data.to_vec()).map_err(|error| error.to_string())
+}
+
+fn read_utf8_length(bytes: &[u8], offset: usize) -> Result<(usize, usize), String> {
+    let first = read_u8(bytes, offset)? as usize;
+    if first & 0x80 != 0 {
+        let second = read_u8(bytes, offset + 1)? as usize;
+        Ok((((first & 0x7f) << 8) | second, offset + 2))
+    } else {
+        Ok((first, offset + 1))
+    }
+}
+
+fn read_utf16_string(bytes: &[u8], offset: usize) -> Result<String, String> {
+    let first = read_u16(bytes, offset)? as usize;
+    let (length, offset) = if first & 0x8000 != 0 {
+        let second = read_u16(bytes, offset + 2)? as usize;
+        (((first & 0x7fff) << 16) | second, offset + 4)
+    } else {
+        (first, offset + 2)
+    };
+    let units = (0..length)
+        .map(|index| read_u16(bytes, offset + index * 2))
+        .collect::<Result<Vec<_>, _>>()?;
+    String::from_utf16(&units).map_err(|error| error.to_string())
+}
+
+fn read_u8(bytes: &[u8], offset: usize) -> Result<u8, String> {
+    bytes
+        .get(offset)
+        .copied()
+        .ok_or_else(|| format!("unexpected end of data at offset {offset}"))
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Result<u16, String> {
+    match bytes.get(offset..offset + 2) {
+        Some(data) => Ok(u16::from_le_bytes([data[0], data[1]])),
+        None => Err(format!("unexpected end of data at offset {offset}")),
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, String> {
+    match bytes.get(offset..offset + 4) {
+        Some(data) => Ok(u32::from_le_bytes([data[0], data[1], data[2], data[3]])),
+        None => Err(format!("unexpected end of data at offset {offset}")),
+    }
+}
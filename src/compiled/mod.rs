@@ -0,0 +1,516 @@
+//! Compiled (binary) manifest formats produced by the Android build tools.
+
+pub(crate) mod attributes;
+pub(crate) mod axml;
+pub(crate) mod proto;
+mod resource_id_map;
+
+pub use resource_id_map::*;
+
+use std::collections::BTreeMap;
+use xml::common::Position;
+use xml::reader::{EventReader, ParserConfig, XmlEvent as ReaderEvent};
+
+use attributes::{
+    AttributeDefinition, FORMAT_BOOLEAN, FORMAT_COLOR, FORMAT_DIMENSION, FORMAT_ENUM, FORMAT_FLAGS,
+    FORMAT_FLOAT, FORMAT_FRACTION, FORMAT_INTEGER, FORMAT_STRING,
+};
+
+/// `android` namespace URI.
+pub(crate) const ANDROID_NAMESPACE: &str = "http://schemas.android.com/apk/res/android";
+
+/// Data types of a typed `Res_value`.
+pub(crate) mod data_type {
+    pub const NULL: u8 = 0x00;
+    pub const REFERENCE: u8 = 0x01;
+    pub const ATTRIBUTE: u8 = 0x02;
+    pub const STRING: u8 = 0x03;
+    pub const FLOAT: u8 = 0x04;
+    pub const DIMENSION: u8 = 0x05;
+    pub const FRACTION: u8 = 0x06;
+    pub const DYNAMIC_REFERENCE: u8 = 0x07;
+    pub const DYNAMIC_ATTRIBUTE: u8 = 0x08;
+    pub const INT_DEC: u8 = 0x10;
+    pub const INT_HEX: u8 = 0x11;
+    pub const INT_BOOLEAN: u8 = 0x12;
+    pub const INT_COLOR_ARGB8: u8 = 0x1c;
+    pub const INT_COLOR_RGB8: u8 = 0x1d;
+    pub const INT_COLOR_ARGB4: u8 = 0x1e;
+    pub const INT_COLOR_RGB4: u8 = 0x1f;
+}
+
+const COMPLEX_UNIT_MASK: u32 = 0xf;
+const COMPLEX_RADIX_SHIFT: u32 = 4;
+const COMPLEX_RADIX_MASK: u32 = 0x3;
+const COMPLEX_MANTISSA_SHIFT: u32 = 8;
+const RADIX_MULTIPLIERS: [f32; 4] = [
+    1.0,
+    1.0 / (1 << 7) as f32,
+    1.0 / (1 << 15) as f32,
+    1.0 / (1 << 23) as f32,
+];
+const DIMENSION_UNITS: [&str; 6] = ["px", "dip", "sp", "pt", "in", "mm"];
+const FRACTION_UNITS: [&str; 2] = ["%", "%p"];
+
+/// A compiled attribute value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum CompiledValue {
+    /// The value is kept as a string.
+    String,
+    /// The value is stored as a typed `Res_value`.
+    Typed { data_type: u8, data: u32 },
+}
+
+/// Compiles the textual value of a framework attribute into a typed value the
+/// way `aapt2` does, trying the formats accepted by the attribute in turn.
+pub(crate) fn compile_value(
+    value: &str,
+    attribute: &AttributeDefinition,
+    resources: &ResourceIdMap,
+) -> Result<CompiledValue, String> {
+    let typed = |data_type, data| Ok(CompiledValue::Typed { data_type, data });
+    if value == "@null" {
+        return typed(data_type::REFERENCE, 0);
+    }
+    if let Some(reference) = value.strip_prefix('@') {
+        if let Some(id) = parse_id(reference) {
+            return typed(data_type::REFERENCE, id);
+        }
+        return match resources.get(reference) {
+            Some(id) => typed(data_type::REFERENCE, id),
+            None => Err(format!(
+                "unresolved resource reference {value} in android:{}",
+                attribute.name
+            )),
+        };
+    }
+    if let Some(id) = value.strip_prefix('?').and_then(parse_id) {
+        return typed(data_type::ATTRIBUTE, id);
+    }
+    if attribute.accepts(FORMAT_ENUM) {
+        if let Some((_, data)) = attribute.symbols.iter().find(|(name, _)| *name == value) {
+            return typed(data_type::INT_DEC, *data);
+        }
+    }
+    if attribute.accepts(FORMAT_FLAGS) {
+        let flags = value.split('|').try_fold(0, |data, flag| {
+            attribute
+                .symbols
+                .iter()
+                .find(|(name, _)| *name == flag.trim())
+                .map(|(_, flag)| data | flag)
+        });
+        if let Some(data) = flags {
+            return typed(data_type::INT_HEX, data);
+        }
+    }
+    if attribute.accepts(FORMAT_BOOLEAN) {
+        match value {
+            "true" => return typed(data_type::INT_BOOLEAN, 0xffff_ffff),
+            "false" => return typed(data_type::INT_BOOLEAN, 0),
+            _ => {}
+        }
+    }
+    if attribute.accepts(FORMAT_COLOR) {
+        if let Some((data_type, data)) = parse_color(value) {
+            return typed(data_type, data);
+        }
+    }
+    if attribute.accepts(FORMAT_INTEGER) {
+        if let Some(data) = parse_id(value) {
+            return typed(data_type::INT_HEX, data);
+        }
+        if let Ok(data) = value.parse::<i32>() {
+            return typed(data_type::INT_DEC, data as u32);
+        }
+    }
+    if attribute.accepts(FORMAT_FLOAT) {
+        if let Ok(data) = value.parse::<f32>() {
+            return typed(data_type::FLOAT, data.to_bits());
+        }
+    }
+    if attribute.accepts(FORMAT_DIMENSION) {
+        if let Some(data) = parse_complex(value, &DIMENSION_UNITS, 1.0) {
+            return typed(data_type::DIMENSION, data);
+        }
+    }
+    if attribute.accepts(FORMAT_FRACTION) {
+        if let Some(data) = parse_complex(value, &FRACTION_UNITS, 100.0) {
+            return typed(data_type::FRACTION, data);
+        }
+    }
+    if attribute.accepts(FORMAT_STRING) {
+        return Ok(CompiledValue::String);
+    }
+    Err(format!(
+        "invalid value {value:?} for attribute android:{}",
+        attribute.name
+    ))
+}
+
+/// XML event with the values of framework attributes compiled.
+pub(crate) enum Node {
+    StartNamespace {
+        prefix: String,
+        uri: String,
+        line: u32,
+    },
+    EndNamespace {
+        prefix: String,
+        uri: String,
+        line: u32,
+    },
+    StartElement {
+        namespace: Option<String>,
+        name: String,
+        attributes: Vec<CompiledAttribute>,
+        line: u32,
+    },
+    EndElement {
+        namespace: Option<String>,
+        name: String,
+        line: u32,
+    },
+    Text {
+        text: String,
+        line: u32,
+    },
+}
+
+pub(crate) struct CompiledAttribute {
+    pub namespace: Option<String>,
+    pub name: String,
+    pub resource_id: Option<u32>,
+    pub raw_value: String,
+    pub value: CompiledValue,
+}
+
+/// Reads XML text into a flat list of events, compiling the values of
+/// framework `android:` attributes.
+pub(crate) fn read_nodes(xml: &str, resources: &ResourceIdMap) -> Result<Vec<Node>, String> {
+    let config = ParserConfig::new()
+        .trim_whitespace(true)
+        .cdata_to_characters(true)
+        .ignore_comments(true)
+        .coalesce_characters(true);
+    let mut reader = EventReader::new_with_config(xml.as_bytes(), config);
+    let mut nodes = Vec::new();
+    // Namespace declarations introduced by each open element.
+    let mut scopes: Vec<Vec<(String, String)>> = Vec::new();
+    let mut in_scope: Vec<BTreeMap<String, String>> = vec![BTreeMap::new()];
+    loop {
+        let event = reader.next().map_err(|error| error.to_string())?;
+        let line = reader.position().row as u32 + 1;
+        match event {
+            ReaderEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } => {
+                let parent = in_scope.last().cloned().unwrap_or_default();
+                let mut declared = Vec::new();
+                let mut current = BTreeMap::new();
+                for (prefix, uri) in &namespace {
+                    if matches!(prefix, "xml" | "xmlns") || uri.is_empty() {
+                        continue;
+                    }
+                    if parent.get(prefix).map(String::as_str) != Some(uri) {
+                        declared.push((prefix.to_string(), uri.to_string()));
+                    }
+                    current.insert(prefix.to_string(), uri.to_string());
+                }
+                for (prefix, uri) in &declared {
+                    nodes.push(Node::StartNamespace {
+                        prefix: prefix.clone(),
+                        uri: uri.clone(),
+                        line,
+                    });
+                }
+                scopes.push(declared);
+                in_scope.push(current);
+
+                let mut compiled = Vec::with_capacity(attributes.len());
+                for attribute in attributes {
+                    let definition = match attribute.name.namespace.as_deref() {
+                        Some(ANDROID_NAMESPACE) => attributes::by_name(&attribute.name.local_name),
+                        _ => None,
+                    };
+                    let value = match definition {
+                        Some(definition) => compile_value(&attribute.value, definition, resources)?,
+                        None => CompiledValue::String,
+                    };
+                    compiled.push(CompiledAttribute {
+                        namespace: attribute.name.namespace,
+                        name: attribute.name.local_name,
+                        resource_id: definition.map(|definition| definition.id),
+                        raw_value: attribute.value,
+                        value,
+                    });
+                }
+                // Attributes with resource IDs come first, ordered by ID, as the
+                // framework looks them up with a binary search.
+                compiled.sort_by_key(|attribute| {
+                    (attribute.resource_id.is_none(), attribute.resource_id)
+                });
+                nodes.push(Node::StartElement {
+                    namespace: name.namespace,
+                    name: name.local_name,
+                    attributes: compiled,
+                    line,
+                });
+            }
+            ReaderEvent::EndElement { name } => {
+                nodes.push(Node::EndElement {
+                    namespace: name.namespace,
+                    name: name.local_name,
+                    line,
+                });
+                in_scope.pop();
+                for (prefix, uri) in scopes.pop().unwrap_or_default().into_iter().rev() {
+                    nodes.push(Node::EndNamespace { prefix, uri, line });
+                }
+            }
+            ReaderEvent::Characters(text) => nodes.push(Node::Text { text, line }),
+            ReaderEvent::EndDocument => break,
+            _ => {}
+        }
+    }
+    Ok(nodes)
+}
+
+fn parse_id(value: &str) -> Option<u32> {
+    let hex = value.strip_prefix("0x")?;
+    u32::from_str_radix(hex, 16).ok()
+}
+
+pub(crate) fn parse_color(value: &str) -> Option<(u8, u32)> {
+    let hex = value.strip_prefix('#')?;
+    let data = u32::from_str_radix(hex, 16).ok()?;
+    let expand = |data: u32| {
+        (0..4).fold(0, |argb, nibble| {
+            let channel = (data >> (nibble * 4)) & 0xf;
+            argb | ((channel << 4 | channel) << (nibble * 8))
+        })
+    };
+    match hex.len() {
+        3 => Some((data_type::INT_COLOR_RGB4, 0xff00_0000 | expand(data))),
+        4 => Some((data_type::INT_COLOR_ARGB4, expand(data))),
+        6 => Some((data_type::INT_COLOR_RGB8, 0xff00_0000 | data)),
+        8 => Some((data_type::INT_COLOR_ARGB8, data)),
+        _ => None,
+    }
+}
+
+/// Encodes a number with a unit suffix into the complex `Res_value` format.
+fn parse_complex(value: &str, units: &[&str], scale: f32) -> Option<u32> {
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let unit = match unit {
+        "dp" => 1,
+        unit => units.iter().position(|known| *known == unit)?,
+    } as u32;
+    let number = number.parse::<f32>().ok()? / scale;
+
+    let negative = number < 0.0;
+    let bits = (number.abs() as f64 * (1 << 23) as f64 + 0.5) as u64;
+    let (radix, shift) = if bits & 0x7f_ffff == 0 {
+        (0, 23)
+    } else if bits & 0xffff_ffff_ff80_0000 == 0 {
+        (3, 0)
+    } else if bits & 0xffff_ffff_8000_0000 == 0 {
+        (2, 8)
+    } else if bits & 0xffff_ff80_0000_0000 == 0 {
+        (1, 16)
+    } else {
+        (0, 23)
+    };
+    let mut mantissa = ((bits >> shift) & 0xff_ffff) as u32;
+    if negative {
+        mantissa = mantissa.wrapping_neg() & 0xff_ffff;
+    }
+    Some((mantissa << COMPLEX_MANTISSA_SHIFT) | (radix << COMPLEX_RADIX_SHIFT) | unit)
+}
+
+/// Renders a typed `Res_value` in the textual form accepted by the XML
+/// deserializer. `string` resolves string pool indices and `attribute` is the
+/// framework definition of the attribute the value belongs to, if known.
+///
+/// Returns `Ok(None)` for `TYPE_NULL` values, which mean the attribute is unset.
+pub(crate) fn render_value(
+    data_type: u8,
+    data: u32,
+    attribute: Option<&attributes::AttributeDefinition>,
+    string: impl Fn(u32) -> Result<String, String>,
+) -> Result<Option<String>, String> {
+    let value = match data_type {
+        data_type::NULL => return Ok(None),
+        data_type::REFERENCE | data_type::DYNAMIC_REFERENCE => {
+            if data == 0 {
+                "@null".to_string()
+            } else {
+                format!("@0x{data:08x}")
+            }
+        }
+        data_type::ATTRIBUTE | data_type::DYNAMIC_ATTRIBUTE => format!("?0x{data:08x}"),
+        data_type::STRING => string(data)?,
+        data_type::FLOAT => f32::from_bits(data).to_string(),
+        data_type::DIMENSION => render_complex(data, &DIMENSION_UNITS, 1.0)?,
+        data_type::FRACTION => render_complex(data, &FRACTION_UNITS, 100.0)?,
+        data_type::INT_BOOLEAN => (data != 0).to_string(),
+        data_type::INT_DEC | data_type::INT_HEX => {
+            match attribute.and_then(|attribute| attribute.symbolic(data)) {
+                Some(symbolic) => symbolic,
+                None if data_type == data_type::INT_HEX => format!("0x{data:08x}"),
+                None => (data as i32).to_string(),
+            }
+        }
+        data_type::INT_COLOR_ARGB8
+        | data_type::INT_COLOR_RGB8
+        | data_type::INT_COLOR_ARGB4
+        | data_type::INT_COLOR_RGB4 => format!("#{data:08x}"),
+        other => return Err(format!("unsupported value type 0x{other:02x}")),
+    };
+    Ok(Some(value))
+}
+
+fn render_complex(data: u32, units: &[&str], scale: f32) -> Result<String, String> {
+    let unit = units
+        .get((data & COMPLEX_UNIT_MASK) as usize)
+        .ok_or_else(|| format!("unsupported complex unit in 0x{data:08x}"))?;
+    let mantissa = (data as i32) >> COMPLEX_MANTISSA_SHIFT;
+    let radix = (data >> COMPLEX_RADIX_SHIFT) & COMPLEX_RADIX_MASK;
+    let value = mantissa as f32 * RADIX_MULTIPLIERS[radix as usize] * scale;
+    Ok(format!("{value}{unit}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(name: &str, value: &str) -> CompiledValue {
+        let attribute = attributes::by_name(name).unwrap();
+        compile_value(value, attribute, &ResourceIdMap::new()).unwrap()
+    }
+
+    fn render(name: &str, value: CompiledValue) -> String {
+        let CompiledValue::Typed { data_type, data } = value else {
+            panic!("{value:?} is not a typed value");
+        };
+        let attribute = attributes::by_name(name);
+        render_value(data_type, data, attribute, |_| unreachable!())
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn compiles_values_like_aapt2() {
+        let typed = |data_type, data| CompiledValue::Typed { data_type, data };
+        assert_eq!(
+            compile("defaultWidth", "600dp"),
+            typed(data_type::DIMENSION, 0x0002_5801)
+        );
+        assert_eq!(
+            compile("windowSoftInputMode", "stateHidden|adjustResize"),
+            typed(data_type::INT_HEX, 0x12)
+        );
+        assert_eq!(
+            compile("launchMode", "singleTask"),
+            typed(data_type::INT_DEC, 2)
+        );
+        assert_eq!(
+            compile("value", "#f00"),
+            typed(data_type::INT_COLOR_RGB4, 0xffff_0000)
+        );
+        assert_eq!(compile("versionName", "1.0"), CompiledValue::String);
+    }
+
+    #[test]
+    fn renders_compiled_values() {
+        for (name, value) in [
+            ("defaultWidth", "600dip"),
+            ("minHeight", "-1.5mm"),
+            ("minWidth", "25%p"),
+            ("protectionLevel", "signature|privileged"),
+            ("windowSoftInputMode", "stateAlwaysHidden|adjustNothing"),
+            ("screenOrientation", "unspecified"),
+            ("maxAspectRatio", "2.5"),
+            ("versionCode", "-7"),
+            ("memtagMode", "default"),
+            ("permissionFlags", "costsMoney|hardRestricted"),
+            ("usesPermissionFlags", "neverForLocation"),
+        ] {
+            assert_eq!(render(name, compile(name, value)), value);
+        }
+    }
+
+    /// Names of the `android:` attributes declared by the models, taken from
+    /// their `#[xml(...)]` field attributes.
+    fn modelled_android_attributes() -> Vec<String> {
+        let mut names = Vec::new();
+        let mut dirs = vec![std::path::PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src"
+        ))];
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+                let source = std::fs::read_to_string(&path).unwrap();
+                for (start, _) in source.match_indices("#[xml(") {
+                    let rest = &source[start..];
+                    let arguments = &rest[..rest.find(")]").unwrap()];
+                    if !arguments.contains("attribute = true")
+                        || !arguments.contains("prefix = \"android\"")
+                    {
+                        continue;
+                    }
+                    let name = match arguments.split_once("rename = \"") {
+                        Some((_, rename)) => &rename[..rename.find('"').unwrap()],
+                        None => {
+                            let field = &rest[rest.find("pub ").unwrap() + 4..];
+                            &field[..field.find(':').unwrap()]
+                        }
+                    };
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names
+    }
+
+    #[test]
+    fn every_modelled_android_attribute_has_a_definition() {
+        // Written as the documentation spells it; the platform reads
+        // `android:enabled`, which is defined.
+        let undefined = ["enable"];
+        let missing: Vec<_> = modelled_android_attributes()
+            .into_iter()
+            .filter(|name| attributes::by_name(name).is_none())
+            .filter(|name| !undefined.contains(&name.as_str()))
+            .collect();
+        assert!(missing.is_empty(), "no definition for {missing:?}");
+    }
+
+    #[test]
+    fn attribute_definitions_are_sorted_and_unique() {
+        for pair in attributes::ANDROID_ATTRIBUTES.windows(2) {
+            assert!(
+                pair[0].name < pair[1].name,
+                "{} is out of order",
+                pair[1].name
+            );
+        }
+        let mut ids: Vec<_> = attributes::ANDROID_ATTRIBUTES
+            .iter()
+            .map(|definition| definition.id)
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), attributes::ANDROID_ATTRIBUTES.len());
+    }
+}
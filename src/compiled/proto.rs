@@ -0,0 +1,598 @@
+//! `aapt2` protobuf XML (`XmlNode` of `Resources.proto`), the format
+//! `AndroidManifest.xml` is stored in inside Android App Bundles.
+
+use super::{
+    ANDROID_NAMESPACE, CompiledValue, Node, ResourceIdMap, attributes, data_type, read_nodes,
+    render_value,
+};
+use xml::name::Name;
+use xml::writer::XmlEvent;
+use xml::{EmitterConfig, EventWriter};
+
+// Field numbers of the `Resources.proto` messages used by XML documents.
+const XML_NODE_ELEMENT: u32 = 1;
+const XML_NODE_TEXT: u32 = 2;
+const XML_NODE_SOURCE: u32 = 3;
+const XML_ELEMENT_NAMESPACE_DECLARATION: u32 = 1;
+const XML_ELEMENT_NAMESPACE_URI: u32 = 2;
+const XML_ELEMENT_NAME: u32 = 3;
+const XML_ELEMENT_ATTRIBUTE: u32 = 4;
+const XML_ELEMENT_CHILD: u32 = 5;
+const XML_NAMESPACE_PREFIX: u32 = 1;
+const XML_NAMESPACE_URI: u32 = 2;
+const XML_NAMESPACE_SOURCE: u32 = 3;
+const XML_ATTRIBUTE_NAMESPACE_URI: u32 = 1;
+const XML_ATTRIBUTE_NAME: u32 = 2;
+const XML_ATTRIBUTE_VALUE: u32 = 3;
+const XML_ATTRIBUTE_SOURCE: u32 = 4;
+const XML_ATTRIBUTE_RESOURCE_ID: u32 = 5;
+const XML_ATTRIBUTE_COMPILED_ITEM: u32 = 6;
+const SOURCE_POSITION_LINE_NUMBER: u32 = 1;
+const ITEM_REF: u32 = 1;
+const ITEM_STR: u32 = 2;
+const ITEM_RAW_STR: u32 = 3;
+const ITEM_PRIM: u32 = 7;
+const REFERENCE_TYPE: u32 = 1;
+const REFERENCE_ID: u32 = 2;
+const REFERENCE_NAME: u32 = 3;
+const REFERENCE_TYPE_ATTRIBUTE: u64 = 1;
+const STRING_VALUE: u32 = 1;
+const PRIMITIVE_NULL: u32 = 1;
+const PRIMITIVE_EMPTY: u32 = 2;
+const PRIMITIVE_FLOAT: u32 = 3;
+const PRIMITIVE_INT_DECIMAL: u32 = 6;
+const PRIMITIVE_INT_HEXADECIMAL: u32 = 7;
+const PRIMITIVE_BOOLEAN: u32 = 8;
+const PRIMITIVE_COLOR_ARGB8: u32 = 9;
+const PRIMITIVE_COLOR_RGB8: u32 = 10;
+const PRIMITIVE_COLOR_ARGB4: u32 = 11;
+const PRIMITIVE_COLOR_RGB4: u32 = 12;
+const PRIMITIVE_DIMENSION: u32 = 13;
+const PRIMITIVE_FRACTION: u32 = 14;
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_LENGTH_DELIMITED: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+/// How deeply elements may nest before a document is rejected, so that crafted
+/// input can't overflow the stack of the recursive decoder.
+const MAX_ELEMENT_DEPTH: usize = 256;
+
+/// Decodes a protobuf `XmlNode` document into the equivalent XML text.
+pub(crate) fn decode(bytes: &[u8]) -> Result<String, String> {
+    let mut element = None;
+    for field in Fields::new(bytes) {
+        if let (XML_NODE_ELEMENT, Value::Bytes(bytes)) = field? {
+            element = Some(bytes);
+        }
+    }
+    let element = element.ok_or_else(|| "XmlNode has no root element".to_string())?;
+    let mut decoder = Decoder {
+        namespaces: Vec::new(),
+        writer: EventWriter::new_with_config(
+            Vec::new(),
+            EmitterConfig::new().write_document_declaration(true),
+        ),
+    };
+    decoder.element(element, 0)?;
+    String::from_utf8(decoder.writer.into_inner()).map_err(|error| error.to_string())
+}
+
+struct Decoder {
+    /// Namespaces currently in scope as `(prefix, uri)`, innermost last.
+    namespaces: Vec<(String, String)>,
+    writer: EventWriter<Vec<u8>>,
+}
+
+impl Decoder {
+    fn element(&mut self, bytes: &[u8], depth: usize) -> Result<(), String> {
+        if depth >= MAX_ELEMENT_DEPTH {
+            return Err(format!(
+                "elements are nested more than {MAX_ELEMENT_DEPTH} levels deep"
+            ));
+        }
+        let mut declared = Vec::new();
+        let mut namespace = String::new();
+        let mut name = String::new();
+        let mut attrs = Vec::new();
+        let mut children = Vec::new();
+        for field in Fields::new(bytes) {
+            match field? {
+                (XML_ELEMENT_NAMESPACE_DECLARATION, Value::Bytes(bytes)) => {
+                    let mut prefix = String::new();
+                    let mut uri = String::new();
+                    for field in Fields::new(bytes) {
+                        match field? {
+                            (XML_NAMESPACE_PREFIX, value) => prefix = value.string()?,
+                            (XML_NAMESPACE_URI, value) => uri = value.string()?,
+                            _ => {}
+                        }
+                    }
+                    declared.push((prefix, uri));
+                }
+                (XML_ELEMENT_NAMESPACE_URI, value) => namespace = value.string()?,
+                (XML_ELEMENT_NAME, value) => name = value.string()?,
+                (XML_ELEMENT_ATTRIBUTE, Value::Bytes(bytes)) => {
+                    if let Some(attribute) = read_attribute(bytes)? {
+                        attrs.push(attribute);
+                    }
+                }
+                (XML_ELEMENT_CHILD, Value::Bytes(bytes)) => children.push(bytes),
+                _ => {}
+            }
+        }
+
+        let scope = self.namespaces.len();
+        self.namespaces.extend(declared.iter().cloned());
+        let mut undeclared = Vec::new();
+        let element_prefix = self.prefix_for(&namespace, &mut undeclared);
+        let attrs: Vec<_> = attrs
+            .into_iter()
+            .map(|(namespace, name, value)| {
+                (self.prefix_for(&namespace, &mut undeclared), name, value)
+            })
+            .collect();
+
+        let element_name = match &element_prefix {
+            Some(prefix) => Name::prefixed(&name, prefix),
+            None => Name::local(&name),
+        };
+        let mut event = XmlEvent::start_element(element_name);
+        for (prefix, uri) in declared.iter().chain(&undeclared) {
+            event = event.ns(prefix.as_str(), uri.as_str());
+        }
+        for (prefix, name, value) in &attrs {
+            let attr_name = match prefix {
+                Some(prefix) => Name::prefixed(name, prefix),
+                None => Name::local(name),
+            };
+            event = event.attr(attr_name, value);
+        }
+        self.writer
+            .write(event)
+            .map_err(|error| error.to_string())?;
+
+        for child in children {
+            for field in Fields::new(child) {
+                match field? {
+                    (XML_NODE_ELEMENT, Value::Bytes(bytes)) => self.element(bytes, depth + 1)?,
+                    (XML_NODE_TEXT, value) => {
+                        let text = value.string()?;
+                        self.writer
+                            .write(XmlEvent::characters(&text))
+                            .map_err(|error| error.to_string())?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.namespaces.truncate(scope);
+        self.writer
+            .write(XmlEvent::end_element())
+            .map_err(|error| error.to_string())
+    }
+
+    /// Finds the prefix bound to `uri`, declaring a new one on the current
+    /// element if the document never declared the namespace.
+    fn prefix_for(&self, uri: &str, undeclared: &mut Vec<(String, String)>) -> Option<String> {
+        if uri.is_empty() {
+            return None;
+        }
+        if let Some((prefix, _)) = self
+            .namespaces
+            .iter()
+            .chain(undeclared.iter())
+            .rev()
+            .find(|(_, known)| known == uri)
+        {
+            return Some(prefix.clone());
+        }
+        let prefix = if uri == ANDROID_NAMESPACE {
+            "android".to_string()
+        } else {
+            format!("ns{}", undeclared.len())
+        };
+        undeclared.push((prefix.clone(), uri.to_string()));
+        Some(prefix)
+    }
+}
+
+/// Reads an `XmlAttribute` as `(namespace_uri, name, value)`. Returns `None`
+/// for attributes compiled to a null value.
+fn read_attribute(bytes: &[u8]) -> Result<Option<(String, String, String)>, String> {
+    let mut namespace = String::new();
+    let mut name = String::new();
+    let mut value = String::new();
+    let mut resource_id = None;
+    let mut compiled_item = None;
+    for field in Fields::new(bytes) {
+        match field? {
+            (XML_ATTRIBUTE_NAMESPACE_URI, field) => namespace = field.string()?,
+            (XML_ATTRIBUTE_NAME, field) => name = field.string()?,
+            (XML_ATTRIBUTE_VALUE, field) => value = field.string()?,
+            (XML_ATTRIBUTE_RESOURCE_ID, Value::Varint(id)) => resource_id = Some(id as u32),
+            (XML_ATTRIBUTE_COMPILED_ITEM, Value::Bytes(bytes)) => compiled_item = Some(bytes),
+            _ => {}
+        }
+    }
+    let definition = resource_id.and_then(attributes::by_id);
+    if name.is_empty() {
+        match definition {
+            Some(definition) => name = definition.name.to_string(),
+            None => return Ok(None),
+        }
+    }
+    // The source text is kept next to the compiled item and carries resource
+    // names, so it is preferred whenever present.
+    if !value.is_empty() {
+        return Ok(Some((namespace, name, value)));
+    }
+    let Some(item) = compiled_item else {
+        return Ok(Some((namespace, name, value)));
+    };
+    let definition = definition.or_else(|| match namespace.as_str() {
+        ANDROID_NAMESPACE => attributes::by_name(&name),
+        _ => None,
+    });
+    Ok(render_item(item, definition)?.map(|value| (namespace, name, value)))
+}
+
+fn render_item(
+    bytes: &[u8],
+    definition: Option<&attributes::AttributeDefinition>,
+) -> Result<Option<String>, String> {
+    for field in Fields::new(bytes) {
+        match field? {
+            (ITEM_REF, Value::Bytes(bytes)) => {
+                let mut attribute = false;
+                let mut id = 0;
+                let mut name = String::new();
+                for field in Fields::new(bytes) {
+                    match field? {
+                        (REFERENCE_TYPE, Value::Varint(kind)) => {
+                            attribute = kind == REFERENCE_TYPE_ATTRIBUTE
+                        }
+                        (REFERENCE_ID, Value::Varint(value)) => id = value as u32,
+                        (REFERENCE_NAME, value) => name = value.string()?,
+                        _ => {}
+                    }
+                }
+                let sigil = if attribute { '?' } else { '@' };
+                if !name.is_empty() {
+                    return Ok(Some(format!("{sigil}{name}")));
+                }
+                let data_type = if attribute {
+                    data_type::ATTRIBUTE
+                } else {
+                    data_type::REFERENCE
+                };
+                return render_value(data_type, id, definition, |_| unreachable!());
+            }
+            (ITEM_STR | ITEM_RAW_STR, Value::Bytes(bytes)) => {
+                for field in Fields::new(bytes) {
+                    if let (STRING_VALUE, value) = field? {
+                        return Ok(Some(value.string()?));
+                    }
+                }
+                return Ok(Some(String::new()));
+            }
+            (ITEM_PRIM, Value::Bytes(bytes)) => {
+                for field in Fields::new(bytes) {
+                    let (data_type, data) = match field? {
+                        (PRIMITIVE_NULL, _) => return Ok(None),
+                        (PRIMITIVE_EMPTY, _) => return Ok(Some(String::new())),
+                        (PRIMITIVE_FLOAT, Value::Fixed32(data)) => (data_type::FLOAT, data),
+                        (PRIMITIVE_INT_DECIMAL, Value::Varint(data)) => {
+                            (data_type::INT_DEC, data as u32)
+                        }
+                        (PRIMITIVE_INT_HEXADECIMAL, Value::Varint(data)) => {
+                            (data_type::INT_HEX, data as u32)
+                        }
+                        (PRIMITIVE_BOOLEAN, Value::Varint(data)) => {
+                            (data_type::INT_BOOLEAN, data as u32)
+                        }
+                        (PRIMITIVE_COLOR_ARGB8, Value::Varint(data)) => {
+                            (data_type::INT_COLOR_ARGB8, data as u32)
+                        }
+                        (PRIMITIVE_COLOR_RGB8, Value::Varint(data)) => {
+                            (data_type::INT_COLOR_RGB8, data as u32)
+                        }
+                        (PRIMITIVE_COLOR_ARGB4, Value::Varint(data)) => {
+                            (data_type::INT_COLOR_ARGB4, data as u32)
+                        }
+                        (PRIMITIVE_COLOR_RGB4, Value::Varint(data)) => {
+                            (data_type::INT_COLOR_RGB4, data as u32)
+                        }
+                        (PRIMITIVE_DIMENSION, Value::Varint(data)) => {
+                            (data_type::DIMENSION, data as u32)
+                        }
+                        (PRIMITIVE_FRACTION, Value::Varint(data)) => {
+                            (data_type::FRACTION, data as u32)
+                        }
+                        _ => continue,
+                    };
+                    return render_value(data_type, data, definition, |_| unreachable!());
+                }
+                return Ok(None);
+            }
+            _ => {}
+        }
+    }
+    Ok(None)
+}
+
+/// Encodes XML text into a protobuf `XmlNode` document, compiling the values
+/// of framework `android:` attributes the way `aapt2` does for bundles.
+pub(crate) fn encode(xml: &str, resources: &ResourceIdMap) -> Result<Vec<u8>, String> {
+    // Encoded elements are built bottom-up, as every message is prefixed by its
+    // length.
+    let mut stack: Vec<(Vec<u8>, u32)> = Vec::new();
+    let mut namespaces = Vec::new();
+    let mut root = None;
+    for node in read_nodes(xml, resources)? {
+        match node {
+            Node::StartNamespace { prefix, uri, line } => {
+                let mut namespace = Vec::new();
+                write_string(&mut namespace, XML_NAMESPACE_PREFIX, &prefix);
+                write_string(&mut namespace, XML_NAMESPACE_URI, &uri);
+                write_message(&mut namespace, XML_NAMESPACE_SOURCE, &source_position(line));
+                namespaces.push(namespace);
+            }
+            Node::EndNamespace { .. } => {}
+            Node::StartElement {
+                namespace,
+                name,
+                attributes,
+                line,
+            } => {
+                let mut element = Vec::new();
+                for declaration in namespaces.drain(..) {
+                    write_message(
+                        &mut element,
+                        XML_ELEMENT_NAMESPACE_DECLARATION,
+                        &declaration,
+                    );
+                }
+                write_string(
+                    &mut element,
+                    XML_ELEMENT_NAMESPACE_URI,
+                    namespace.as_deref().unwrap_or_default(),
+                );
+                write_string(&mut element, XML_ELEMENT_NAME, &name);
+                for attribute in attributes {
+                    let mut encoded = Vec::new();
+                    write_string(
+                        &mut encoded,
+                        XML_ATTRIBUTE_NAMESPACE_URI,
+                        attribute.namespace.as_deref().unwrap_or_default(),
+                    );
+                    write_string(&mut encoded, XML_ATTRIBUTE_NAME, &attribute.name);
+                    write_string(&mut encoded, XML_ATTRIBUTE_VALUE, &attribute.raw_value);
+                    write_message(&mut encoded, XML_ATTRIBUTE_SOURCE, &source_position(line));
+                    if let Some(id) = attribute.resource_id {
+                        write_varint_field(&mut encoded, XML_ATTRIBUTE_RESOURCE_ID, id as u64);
+                        let item = compiled_item(&attribute.raw_value, attribute.value);
+                        write_message(&mut encoded, XML_ATTRIBUTE_COMPILED_ITEM, &item);
+                    }
+                    write_message(&mut element, XML_ELEMENT_ATTRIBUTE, &encoded);
+                }
+                stack.push((element, line));
+            }
+            Node::EndElement { .. } => {
+                let (element, line) = stack.pop().ok_or("unbalanced end element")?;
+                let mut node = Vec::new();
+                write_message(&mut node, XML_NODE_ELEMENT, &element);
+                write_message(&mut node, XML_NODE_SOURCE, &source_position(line));
+                match stack.last_mut() {
+                    Some((parent, _)) => write_message(parent, XML_ELEMENT_CHILD, &node),
+                    None => root = Some(node),
+                }
+            }
+            Node::Text { text, line } => {
+                if let Some((parent, _)) = stack.last_mut() {
+                    let mut node = Vec::new();
+                    write_string(&mut node, XML_NODE_TEXT, &text);
+                    write_message(&mut node, XML_NODE_SOURCE, &source_position(line));
+                    write_message(parent, XML_ELEMENT_CHILD, &node);
+                }
+            }
+        }
+    }
+    root.ok_or_else(|| "document has no root element".to_string())
+}
+
+fn compiled_item(raw_value: &str, value: CompiledValue) -> Vec<u8> {
+    let mut item = Vec::new();
+    let mut inner = Vec::new();
+    match value {
+        CompiledValue::String => {
+            write_string(&mut inner, STRING_VALUE, raw_value);
+            write_message(&mut item, ITEM_STR, &inner);
+        }
+        CompiledValue::Typed {
+            data_type: data_type @ (data_type::REFERENCE | data_type::ATTRIBUTE),
+            data,
+        } => {
+            if data_type == data_type::ATTRIBUTE {
+                write_varint_field(&mut inner, REFERENCE_TYPE, REFERENCE_TYPE_ATTRIBUTE);
+            }
+            write_varint_field(&mut inner, REFERENCE_ID, data as u64);
+            let name = &raw_value[1..];
+            if !name.starts_with("0x") && name != "null" {
+                write_string(&mut inner, REFERENCE_NAME, name);
+            }
+            write_message(&mut item, ITEM_REF, &inner);
+        }
+        CompiledValue::Typed { data_type, data } => {
+            let field = match data_type {
+                data_type::FLOAT => PRIMITIVE_FLOAT,
+                data_type::INT_DEC => PRIMITIVE_INT_DECIMAL,
+                data_type::INT_HEX => PRIMITIVE_INT_HEXADECIMAL,
+                data_type::INT_BOOLEAN => PRIMITIVE_BOOLEAN,
+                data_type::INT_COLOR_ARGB8 => PRIMITIVE_COLOR_ARGB8,
+                data_type::INT_COLOR_RGB8 => PRIMITIVE_COLOR_RGB8,
+                data_type::INT_COLOR_ARGB4 => PRIMITIVE_COLOR_ARGB4,
+                data_type::INT_COLOR_RGB4 => PRIMITIVE_COLOR_RGB4,
+                data_type::DIMENSION => PRIMITIVE_DIMENSION,
+                data_type::FRACTION => PRIMITIVE_FRACTION,
+                _ => PRIMITIVE_NULL,
+            };
+            // Members of a oneof are written even when set to their default value.
+            match field {
+                PRIMITIVE_FLOAT => {
+                    write_tag(&mut inner, field, WIRE_FIXED32);
+                    inner.extend_from_slice(&data.to_le_bytes());
+                }
+                PRIMITIVE_NULL => write_message(&mut inner, field, &[]),
+                _ => {
+                    let value = match field {
+                        // `int32` fields sign-extend negative values to 64 bits.
+                        PRIMITIVE_INT_DECIMAL => data as i32 as i64 as u64,
+                        PRIMITIVE_BOOLEAN => (data != 0) as u64,
+                        _ => data as u64,
+                    };
+                    write_tag(&mut inner, field, WIRE_VARINT);
+                    write_varint(&mut inner, value);
+                }
+            }
+            write_message(&mut item, ITEM_PRIM, &inner);
+        }
+    }
+    item
+}
+
+fn source_position(line: u32) -> Vec<u8> {
+    let mut position = Vec::new();
+    write_varint_field(&mut position, SOURCE_POSITION_LINE_NUMBER, line as u64);
+    position
+}
+
+fn write_tag(buffer: &mut Vec<u8>, field: u32, wire_type: u8) {
+    write_varint(buffer, ((field as u64) << 3) | wire_type as u64);
+}
+
+fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn write_varint_field(buffer: &mut Vec<u8>, field: u32, value: u64) {
+    // Proto3 omits scalar fields set to their default value.
+    if value != 0 {
+        write_tag(buffer, field, WIRE_VARINT);
+        write_varint(buffer, value);
+    }
+}
+
+fn write_message(buffer: &mut Vec<u8>, field: u32, message: &[u8]) {
+    write_tag(buffer, field, WIRE_LENGTH_DELIMITED);
+    write_varint(buffer, message.len() as u64);
+    buffer.extend_from_slice(message);
+}
+
+fn write_string(buffer: &mut Vec<u8>, field: u32, value: &str) {
+    if !value.is_empty() {
+        write_message(buffer, field, value.as_bytes());
+    }
+}
+
+/// Value of a protobuf field.
+enum Value<'a> {
+    Varint(u64),
+    Fixed32(u32),
+    Fixed64,
+    Bytes(&'a [u8]),
+}
+
+impl Value<'_> {
+    fn string(&self) -> Result<String, String> {
+        match self {
+            Value::Bytes(bytes) => String::from_utf8(bytes.to_vec()).map_err(|e| e.to_string()),
+            _ => Err("expected a string field".to_string()),
+        }
+    }
+}
+
+/// Iterator over the fields of an encoded protobuf message.
+struct Fields<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Fields<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Fields { bytes, offset: 0 }
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .bytes
+                .get(self.offset)
+                .ok_or("unexpected end of protobuf data")?;
+            self.offset += 1;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("protobuf varint is too long".to_string())
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .offset
+            .checked_add(length)
+            .and_then(|end| self.bytes.get(self.offset..end))
+            .ok_or("unexpected end of protobuf data")?;
+        self.offset += length;
+        Ok(bytes)
+    }
+
+    fn field(&mut self) -> Result<(u32, Value<'a>), String> {
+        let key = self.varint()?;
+        let field = (key >> 3) as u32;
+        let value = match (key & 0x7) as u8 {
+            WIRE_VARINT => Value::Varint(self.varint()?),
+            WIRE_FIXED64 => {
+                self.take(8)?;
+                Value::Fixed64
+            }
+            WIRE_LENGTH_DELIMITED => {
+                let length = self.varint()? as usize;
+                Value::Bytes(self.take(length)?)
+            }
+            WIRE_FIXED32 => {
+                let bytes = self.take(4)?;
+                Value::Fixed32(u32::from_le_bytes(bytes.try_into().unwrap()))
+            }
+            wire_type => {
+                return Err(format!(
+                    "unsupported protobuf wire type {wire_type} of field {field}"
+                ));
+            }
+        };
+        Ok((field, value))
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Result<(u32, Value<'a>), String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.bytes.len() {
+            return None;
+        }
+        let field = self.field();
+        if field.is_err() {
+            // Stop after the first error instead of reading garbage.
+            self.offset = self.bytes.len();
+        }
+        Some(field)
+    }
+}
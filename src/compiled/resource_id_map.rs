@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+/// Resource IDs used to resolve resource references when compiling a manifest
+/// into binary XML.
+///
+/// Compiled manifests store references such as `@string/app_name` as the
+/// numeric ID the resource was assigned in the application's resource table.
+/// Resource IDs of the framework `android:` attributes are built in and don't
+/// need to be added.
+///
+/// ## Example
+/// ```rust
+/// use android_manifest::ResourceIdMap;
+///
+/// let mut resources = ResourceIdMap::new();
+/// resources.insert("@string/app_name", 0x7f100000);
+/// resources.insert("@android:style/Theme.Material", 0x01030224);
+///
+/// assert_eq!(resources.get("@string/app_name"), Some(0x7f100000));
+/// ```
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct ResourceIdMap {
+    ids: HashMap<String, u32>,
+}
+
+impl ResourceIdMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns `id` to the resource `reference` in format
+    /// `@[package:]resource_type/resource_name`.
+    pub fn insert(&mut self, reference: &str, id: u32) -> Option<u32> {
+        self.ids.insert(normalize(reference).to_string(), id)
+    }
+
+    /// Returns the ID assigned to the resource `reference`.
+    pub fn get(&self, reference: &str) -> Option<u32> {
+        self.ids.get(normalize(reference)).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, u32)> for ResourceIdMap {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Self {
+        let mut map = ResourceIdMap::new();
+        for (reference, id) in iter {
+            map.insert(reference.as_ref(), id);
+        }
+        map
+    }
+}
+
+fn normalize(reference: &str) -> &str {
+    reference.strip_prefix('@').unwrap_or(reference)
+}
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Adds a data specification to an intent filter.
@@ -7,9 +8,12 @@ use serde::{Deserialize, Serialize};
 /// by separate attributes for each of its parts:
 ///
 /// ```xml
-/// <scheme>://<host>:<port>[<path>|<pathPrefix>|<pathPattern>]
+/// <scheme>://<host>:<port>[<path>|<pathPrefix>|<pathPattern>|<pathSuffix>|<pathAdvancedPattern>]
 /// ```
 ///
+/// For URIs without an authority, such as `tel:` or `mailto:`, the scheme-specific part
+/// can be matched instead with the `ssp` attributes.
+///
 /// ## XML Examples
 /// These attributes that specify the URL format are optional, but also mutually
 /// dependent:
@@ -54,6 +58,23 @@ use serde::{Deserialize, Serialize};
 ///     android:path="string"
 ///     android:pathPattern="string"
 ///     android:pathPrefix="string"
+///     android:pathSuffix="string"
+///     android:pathAdvancedPattern="string"
+///     android:ssp="string"
+///     android:sspPattern="string"
+///     android:sspPrefix="string"
+///     android:sspSuffix="string"
+///     android:sspAdvancedPattern="string"
+///     android:query="string"
+///     android:queryPattern="string"
+///     android:queryPrefix="string"
+///     android:querySuffix="string"
+///     android:queryAdvancedPattern="string"
+///     android:fragment="string"
+///     android:fragmentPattern="string"
+///     android:fragmentPrefix="string"
+///     android:fragmentSuffix="string"
+///     android:fragmentAdvancedPattern="string"
 ///     android:mimeType="string" />
 /// ```
 ///
@@ -151,6 +172,115 @@ pub struct Data {
     pub path_pattern: Option<String>,
     #[xml(attribute = true, prefix = "android", rename = "pathPrefix")]
     pub path_prefix: Option<String>,
+    /// The final part of the path, matched against the end of the path in the Intent
+    /// object.
+    ///
+    /// This attribute was introduced in API Level 31.
+    #[xml(attribute = true, prefix = "android", rename = "pathSuffix")]
+    pub path_suffix: Option<String>,
+    /// A complete path matched with a regular expression-like pattern, which supports
+    /// character classes such as `[a-z]`, `+` and `{min,max}` ranges. See
+    /// [`PATTERN_ADVANCED_GLOB`].
+    ///
+    /// This attribute was introduced in API Level 31.
+    ///
+    /// [`PATTERN_ADVANCED_GLOB`]: https://developer.android.com/reference/android/os/PatternMatcher#PATTERN_ADVANCED_GLOB
+    #[xml(attribute = true, prefix = "android", rename = "pathAdvancedPattern")]
+    pub path_advanced_pattern: Option<String>,
+    /// The complete scheme-specific part of a URI, which is everything between the
+    /// `scheme:` and the `#` fragment, for example `+1-555-0100` in
+    /// `tel:+1-555-0100`.
+    ///
+    /// The `ssp` attributes are meaningful only if the [`scheme`] attribute is also
+    /// specified for the filter, and are matched instead of the host and path
+    /// attributes.
+    ///
+    /// This attribute was introduced in API Level 19.
+    ///
+    /// [`scheme`]: crate::Data#structfield.scheme
+    #[xml(attribute = true, prefix = "android")]
+    pub ssp: Option<String>,
+    /// A scheme-specific part that can contain the same wildcards as
+    /// [`path`](crate::Data#structfield.path) patterns.
+    ///
+    /// This attribute was introduced in API Level 19.
+    #[xml(attribute = true, prefix = "android", rename = "sspPattern")]
+    pub ssp_pattern: Option<String>,
+    /// The initial part of the scheme-specific part.
+    ///
+    /// This attribute was introduced in API Level 19.
+    #[xml(attribute = true, prefix = "android", rename = "sspPrefix")]
+    pub ssp_prefix: Option<String>,
+    /// The final part of the scheme-specific part.
+    ///
+    /// This attribute was introduced in API Level 31.
+    #[xml(attribute = true, prefix = "android", rename = "sspSuffix")]
+    pub ssp_suffix: Option<String>,
+    /// A scheme-specific part matched with an advanced pattern, like
+    /// [`path_advanced_pattern`](crate::Data#structfield.path_advanced_pattern).
+    ///
+    /// This attribute was introduced in API Level 31.
+    #[xml(attribute = true, prefix = "android", rename = "sspAdvancedPattern")]
+    pub ssp_advanced_pattern: Option<String>,
+    /// The complete query of a URI, without the leading `?`.
+    ///
+    /// The `query` and `fragment` attributes are meaningful only if the [`scheme`]
+    /// attribute is also specified for the filter.
+    ///
+    /// This attribute was introduced in API Level 35.
+    ///
+    /// [`scheme`]: crate::Data#structfield.scheme
+    #[xml(attribute = true, prefix = "android")]
+    pub query: Option<String>,
+    /// A query that can contain the same wildcards as path patterns.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(attribute = true, prefix = "android", rename = "queryPattern")]
+    pub query_pattern: Option<String>,
+    /// The initial part of the query.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(attribute = true, prefix = "android", rename = "queryPrefix")]
+    pub query_prefix: Option<String>,
+    /// The final part of the query.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(attribute = true, prefix = "android", rename = "querySuffix")]
+    pub query_suffix: Option<String>,
+    /// A query matched with an advanced pattern.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(attribute = true, prefix = "android", rename = "queryAdvancedPattern")]
+    pub query_advanced_pattern: Option<String>,
+    /// The complete fragment of a URI, without the leading `#`.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(attribute = true, prefix = "android")]
+    pub fragment: Option<String>,
+    /// A fragment that can contain the same wildcards as path patterns.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(attribute = true, prefix = "android", rename = "fragmentPattern")]
+    pub fragment_pattern: Option<String>,
+    /// The initial part of the fragment.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(attribute = true, prefix = "android", rename = "fragmentPrefix")]
+    pub fragment_prefix: Option<String>,
+    /// The final part of the fragment.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(attribute = true, prefix = "android", rename = "fragmentSuffix")]
+    pub fragment_suffix: Option<String>,
+    /// A fragment matched with an advanced pattern.
+    ///
+    /// This attribute was introduced in API Level 35.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "fragmentAdvancedPattern"
+    )]
+    pub fragment_advanced_pattern: Option<String>,
     /// A MIME media type, such as `image/jpeg` or `audio/mpeg4-generic`. The
     /// subtype can be the asterisk wildcard (*) to indicate that any subtype
     /// matches.
@@ -164,4 +294,8 @@ pub struct Data {
     /// MIME types using lowercase letters.
     #[xml(attribute = true, prefix = "android", rename = "mimeType")]
     pub mime_type: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
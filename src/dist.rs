@@ -0,0 +1,406 @@
+//! Elements of the `dist` namespace, `http://schemas.android.com/apk/distribution`,
+//! that configure how Google Play delivers the modules of an app bundle.
+//!
+//! A feature module declares them in a [`<dist:module>`](Module) child of its
+//! [`<manifest>`](crate::AndroidManifest):
+//!
+//! ```xml
+//! <manifest xmlns:dist="http://schemas.android.com/apk/distribution" split="camera" ... >
+//!     <dist:module dist:instant="false" dist:title="@string/camera_title">
+//!         <dist:delivery>
+//!             <dist:install-time>
+//!                 <dist:conditions>
+//!                     <dist:device-feature dist:name="android.hardware.camera.ar" />
+//!                     <dist:min-sdk dist:value="24" />
+//!                 </dist:conditions>
+//!             </dist:install-time>
+//!             <dist:on-demand />
+//!         </dist:delivery>
+//!         <dist:fusing dist:include="true" />
+//!     </dist:module>
+//!     ...
+//! </manifest>
+//! ```
+//!
+//! The namespace is declared on the `<dist:module>` element when it is written.
+//!
+//! See [`Configure feature delivery`] for how Google Play uses these elements.
+//!
+//! [`Configure feature delivery`]: https://developer.android.com/guide/playcore/feature-delivery
+
+use super::extras::Extras;
+use super::resources::{Resource, StringResource};
+use crate::VarOrBool;
+use serde::{Deserialize, Serialize};
+
+/// Declares that the split is a module of an app bundle and how it is delivered.
+///
+/// ## XML Syntax
+/// ```xml
+/// <dist:module dist:instant=["true" | "false"]
+///              dist:title="string resource"
+///              dist:onDemand=["true" | "false"]
+///              dist:type="string" >
+///     ...
+/// </dist:module>
+/// ```
+///
+/// ## Contained in
+/// * [`<manifest>`]
+///
+/// ## Can contain
+/// * [`<dist:delivery>`]
+/// * [`<dist:fusing>`]
+///
+/// [`<manifest>`]: crate::AndroidManifest
+/// [`<dist:delivery>`]: Delivery
+/// [`<dist:fusing>`]: Fusing
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Default, Clone,
+)]
+#[xml(
+    rename = "module",
+    prefix = "dist",
+    namespaces = { "dist" = "http://schemas.android.com/apk/distribution" }
+)]
+pub struct Module {
+    /// Whether the module is available through Google Play Instant.
+    #[xml(attribute = true, prefix = "dist")]
+    pub instant: Option<VarOrBool>,
+    /// The user-facing name of the module, shown when it is downloaded. It must be a
+    /// reference to a string resource in the base module. Required for modules that
+    /// aren't always installed.
+    #[xml(attribute = true, prefix = "dist")]
+    pub title: Option<Resource<StringResource>>,
+    /// Whether the module is delivered on demand.
+    ///
+    /// ## Caution
+    /// Deprecated in favour of [`<dist:on-demand>`](OnDemand) in the
+    /// [`<dist:delivery>`](Delivery) element.
+    #[xml(attribute = true, prefix = "dist", rename = "onDemand")]
+    pub on_demand: Option<VarOrBool>,
+    /// The kind of module. Set to `"asset-pack"` for asset packs and unset for
+    /// feature modules.
+    #[xml(attribute = true, prefix = "dist", rename = "type")]
+    pub module_type: Option<String>,
+    /// Optional `<dist:delivery>` tag.
+    #[xml(prefix = "dist")]
+    pub delivery: Option<Delivery>,
+    /// Optional `<dist:fusing>` tag.
+    #[xml(prefix = "dist")]
+    pub fusing: Option<Fusing>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+impl Module {
+    /// Whether the module can be installed after the app, either because it is
+    /// delivered on demand or because its install-time delivery has conditions.
+    pub fn is_conditional_or_on_demand(&self) -> bool {
+        self.on_demand == Some(true.into())
+            || self.delivery.as_ref().is_some_and(|delivery| {
+                delivery.on_demand.is_some()
+                    || delivery
+                        .install_time
+                        .as_ref()
+                        .is_some_and(|install_time| install_time.conditions.is_some())
+            })
+    }
+}
+
+/// The ways a module is delivered. A module can combine install-time delivery with
+/// conditions and on-demand delivery.
+///
+/// ## XML Syntax
+/// ```xml
+/// <dist:delivery>
+///     <dist:install-time />
+///     <dist:on-demand />
+///     <dist:fast-follow />
+/// </dist:delivery>
+/// ```
+///
+/// ## Contained in
+/// * [`<dist:module>`](Module)
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Default, Clone,
+)]
+#[xml(namespaces = { "dist" = "http://schemas.android.com/apk/distribution" })]
+pub struct Delivery {
+    /// Optional `<dist:install-time>` tag.
+    #[xml(prefix = "dist", rename = "install-time")]
+    pub install_time: Option<InstallTime>,
+    /// Optional `<dist:on-demand>` tag.
+    #[xml(prefix = "dist", rename = "on-demand")]
+    pub on_demand: Option<OnDemand>,
+    /// Optional `<dist:fast-follow>` tag, for asset packs.
+    #[xml(prefix = "dist", rename = "fast-follow")]
+    pub fast_follow: Option<FastFollow>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Installs the module together with the app, on the devices that meet its
+/// conditions.
+///
+/// ## XML Syntax
+/// ```xml
+/// <dist:install-time>
+///     <dist:conditions>
+///         ...
+///     </dist:conditions>
+///     <dist:removable dist:value=["true" | "false"] />
+/// </dist:install-time>
+/// ```
+///
+/// ## Contained in
+/// * [`<dist:delivery>`](Delivery)
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Default, Clone,
+)]
+#[xml(namespaces = { "dist" = "http://schemas.android.com/apk/distribution" })]
+pub struct InstallTime {
+    /// Optional `<dist:conditions>` tag. Without it the module is installed on all
+    /// devices.
+    #[xml(prefix = "dist")]
+    pub conditions: Option<Conditions>,
+    /// Optional `<dist:removable>` tag.
+    #[xml(prefix = "dist")]
+    pub removable: Option<Removable>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Lets the app download the module after it is installed.
+///
+/// ## XML Syntax
+/// ```xml
+/// <dist:on-demand />
+/// ```
+///
+/// ## Contained in
+/// * [`<dist:delivery>`](Delivery)
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct OnDemand {
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Downloads an asset pack as soon as the app is installed.
+///
+/// ## XML Syntax
+/// ```xml
+/// <dist:fast-follow />
+/// ```
+///
+/// ## Contained in
+/// * [`<dist:delivery>`](Delivery)
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct FastFollow {
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Whether a module installed at install time can be uninstalled later.
+///
+/// ## XML Syntax
+/// ```xml
+/// <dist:removable dist:value=["true" | "false"] />
+/// ```
+///
+/// ## Contained in
+/// * [`<dist:install-time>`](InstallTime)
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct Removable {
+    /// The default is `"false"`.
+    #[xml(attribute = true, prefix = "dist")]
+    pub value: Option<VarOrBool>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// The conditions a device must meet to get the module at install time. A device
+/// must meet all of them.
+///
+/// ## XML Syntax
+/// ```xml
+/// <dist:conditions>
+///     <dist:device-feature dist:name="string" dist:version="integer" />
+///     <dist:min-sdk dist:value="integer" />
+///     <dist:max-sdk dist:value="integer" />
+///     <dist:user-countries dist:exclude=["true" | "false"]>
+///         <dist:country dist:code="string" />
+///     </dist:user-countries>
+///     <dist:device-groups>
+///         <dist:device-group dist:name="string" />
+///     </dist:device-groups>
+/// </dist:conditions>
+/// ```
+///
+/// ## Contained in
+/// * [`<dist:install-time>`](InstallTime)
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+#[xml(namespaces = { "dist" = "http://schemas.android.com/apk/distribution" })]
+pub struct Conditions {
+    /// List of `<dist:device-feature>` tags.
+    #[xml(prefix = "dist", rename = "device-feature")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub device_feature: Vec<DeviceFeature>,
+    /// Optional `<dist:min-sdk>` tag.
+    #[xml(prefix = "dist", rename = "min-sdk")]
+    pub min_sdk: Option<SdkCondition>,
+    /// Optional `<dist:max-sdk>` tag.
+    #[xml(prefix = "dist", rename = "max-sdk")]
+    pub max_sdk: Option<SdkCondition>,
+    /// Optional `<dist:user-countries>` tag.
+    #[xml(prefix = "dist", rename = "user-countries")]
+    pub user_countries: Option<UserCountries>,
+    /// Optional `<dist:device-groups>` tag.
+    #[xml(prefix = "dist", rename = "device-groups")]
+    pub device_groups: Option<DeviceGroups>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Requires a device to have a hardware or software feature, as named by
+/// [`<uses-feature>`](crate::UsesFeature).
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct DeviceFeature {
+    /// The name of the feature, such as `"android.hardware.camera.ar"`.
+    #[xml(attribute = true, prefix = "dist")]
+    pub name: String,
+    /// The minimum version of the feature, for features that have versions such as
+    /// `"android.hardware.vulkan.level"`.
+    #[xml(attribute = true, prefix = "dist")]
+    pub version: Option<u32>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// The API level bound of a `<dist:min-sdk>` or `<dist:max-sdk>` condition.
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct SdkCondition {
+    /// The API level, inclusive.
+    #[xml(attribute = true, prefix = "dist")]
+    pub value: u32,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Limits the module to users in, or with `dist:exclude="true"` outside of, a list of
+/// countries.
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+#[xml(namespaces = { "dist" = "http://schemas.android.com/apk/distribution" })]
+pub struct UserCountries {
+    /// Whether the countries are excluded rather than included. The default is
+    /// `"false"`.
+    #[xml(attribute = true, prefix = "dist")]
+    pub exclude: Option<VarOrBool>,
+    /// List of `<dist:country>` tags.
+    #[xml(prefix = "dist")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub country: Vec<Country>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// A country of a [`<dist:user-countries>`](UserCountries) condition.
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct Country {
+    /// The two-letter ISO 3166-1 code of the country, such as `"FR"`.
+    #[xml(attribute = true, prefix = "dist")]
+    pub code: String,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Limits the module to devices in one of the device groups defined in the Play
+/// Console.
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+#[xml(namespaces = { "dist" = "http://schemas.android.com/apk/distribution" })]
+pub struct DeviceGroups {
+    /// List of `<dist:device-group>` tags.
+    #[xml(prefix = "dist", rename = "device-group")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub device_group: Vec<DeviceGroup>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// A device group of a [`<dist:device-groups>`](DeviceGroups) condition.
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct DeviceGroup {
+    /// The name of the device group.
+    #[xml(attribute = true, prefix = "dist")]
+    pub name: String,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// Whether the module is included in the fused APKs that Google Play generates for
+/// devices that don't support split APKs.
+///
+/// ## XML Syntax
+/// ```xml
+/// <dist:fusing dist:include=["true" | "false"] />
+/// ```
+///
+/// ## Contained in
+/// * [`<dist:module>`](Module)
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct Fusing {
+    #[xml(attribute = true, prefix = "dist")]
+    pub include: Option<VarOrBool>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
@@ -0,0 +1,923 @@
+use crate::error::{DeserializeError, Error, ErrorKind, Result};
+use crate::extras::{ExtraAttribute, ExtraElement, ExtraNode};
+use crate::xml::de::Deserializer;
+use crate::{AndroidManifest, from_str};
+use std::collections::BTreeMap;
+use xml::reader::XmlEvent as ReaderEvent;
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Namespace prefixes in scope of an element, mapped to their URIs. The default
+/// namespace is stored under the empty prefix.
+type Scope = BTreeMap<String, String>;
+
+/// An editable `AndroidManifest.xml` text document.
+///
+/// The document exposes the typed [`AndroidManifest`] view of the text. Writing it
+/// back only rewrites the attributes and elements that were changed through that
+/// view: comments, whitespace, attribute order and quoting of everything else are
+/// kept byte for byte.
+///
+/// ## Example
+/// ```rust
+/// use android_manifest::ManifestDocument;
+///
+/// let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+/// <!-- Maintained by hand -->
+/// <manifest xmlns:android="http://schemas.android.com/apk/res/android"
+///     package="com.example"
+///     android:versionCode='7'>
+///     <application android:label="Example" />
+/// </manifest>"#;
+///
+/// let mut document = ManifestDocument::parse(xml).unwrap();
+/// document.manifest_mut().version_code = Some(8);
+///
+/// assert_eq!(
+///     document.to_string().unwrap(),
+///     xml.replace("android:versionCode='7'", "android:versionCode='8'")
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManifestDocument {
+    source: String,
+    original: AndroidManifest,
+    manifest: AndroidManifest,
+}
+
+impl ManifestDocument {
+    /// Parses a manifest document from XML text.
+    pub fn parse(source: &str) -> Result<ManifestDocument> {
+        let manifest = from_str(source)?;
+        Ok(ManifestDocument {
+            source: source.to_string(),
+            original: manifest.clone(),
+            manifest,
+        })
+    }
+
+    /// The text the document was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn manifest(&self) -> &AndroidManifest {
+        &self.manifest
+    }
+
+    pub fn manifest_mut(&mut self) -> &mut AndroidManifest {
+        &mut self.manifest
+    }
+
+    /// Writes the document back to XML text, applying the changes made to the
+    /// typed manifest to the original text.
+    ///
+    /// Changed attribute values are replaced in place, new attributes are appended
+    /// to the start tag and new elements are inserted next to their siblings with
+    /// the indentation of the surrounding text.
+    pub fn to_string(&self) -> Result<String> {
+        if self.manifest == self.original {
+            return Ok(self.source.clone());
+        }
+        let root = SourceScanner::new(&self.source)
+            .document()
+            .map_err(|message| {
+                Error::deserialize(DeserializeError::new(ErrorKind::Syntax, message))
+            })?;
+        let old = serialized_tree(&self.original)?;
+        let new = serialized_tree(&self.manifest)?;
+
+        let mut editor = Editor::new(&self.source, &root);
+        editor.edit_element(&root, &old, &new);
+        Ok(editor.finish())
+    }
+}
+
+/// Serializes the manifest and reads it back as a generic element tree.
+fn serialized_tree(manifest: &AndroidManifest) -> Result<ExtraElement> {
+    let xml = crate::xml::ser::to_string(manifest).map_err(Error::FailedToSerialize)?;
+    let mut reader = Deserializer::new_from_reader(xml.as_bytes());
+    loop {
+        match reader
+            .next_event()
+            .map_err(|error| Error::FailedToSerialize(error.to_string()))?
+        {
+            ReaderEvent::StartElement {
+                name, attributes, ..
+            } => {
+                return ExtraElement::read(&mut reader, &name, &attributes)
+                    .map_err(|error| Error::FailedToSerialize(error.to_string()));
+            }
+            ReaderEvent::EndDocument => {
+                return Err(Error::FailedToSerialize("empty document".to_string()));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// An element of the source text with the byte positions of its parts.
+#[derive(Debug)]
+struct SourceElement {
+    qualified_name: String,
+    namespace: Option<String>,
+    name: String,
+    /// Position of the opening `<`.
+    start: usize,
+    /// End of the element name in the start tag.
+    name_end: usize,
+    /// Position right after the start tag.
+    start_tag_end: usize,
+    self_closing: bool,
+    /// Position of the end tag, equal to `start_tag_end` for empty-element tags.
+    content_end: usize,
+    /// Position right after the element.
+    end: usize,
+    attributes: Vec<SourceAttribute>,
+    children: Vec<SourceElement>,
+    scope: Scope,
+}
+
+#[derive(Debug)]
+struct SourceAttribute {
+    namespace: Option<String>,
+    name: String,
+    /// Whether the attribute is an `xmlns` namespace declaration.
+    declaration: bool,
+    start: usize,
+    value_start: usize,
+    value_end: usize,
+    end: usize,
+    quote: char,
+}
+
+impl SourceElement {
+    /// End of the last attribute, where new attributes are appended.
+    fn attributes_end(&self) -> usize {
+        self.attributes
+            .last()
+            .map_or(self.name_end, |attribute| attribute.end)
+    }
+
+    /// End of whatever precedes the attribute at `index` in the start tag.
+    fn attribute_preceding_end(&self, index: usize) -> usize {
+        index
+            .checked_sub(1)
+            .map_or(self.name_end, |previous| self.attributes[previous].end)
+    }
+
+    fn attribute(&self, namespace: Option<&str>, name: &str) -> Option<usize> {
+        self.attributes.iter().position(|attribute| {
+            !attribute.declaration
+                && attribute.namespace.as_deref() == namespace
+                && attribute.name == name
+        })
+    }
+
+    fn children_named(&self, namespace: Option<&str>, name: &str) -> Vec<&SourceElement> {
+        self.children
+            .iter()
+            .filter(|child| child.namespace.as_deref() == namespace && child.name == name)
+            .collect()
+    }
+}
+
+/// Minimal XML scanner that records where elements and attributes are located in
+/// the source text.
+struct SourceScanner<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> SourceScanner<'a> {
+    fn new(text: &'a str) -> Self {
+        SourceScanner { text, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_past(&mut self, pattern: &str) -> std::result::Result<(), String> {
+        let index = self
+            .rest()
+            .find(pattern)
+            .ok_or_else(|| format!("unterminated markup, expected {pattern:?}"))?;
+        self.position += index + pattern.len();
+        Ok(())
+    }
+
+    /// Skips comments, processing instructions and other markup that doesn't
+    /// start an element. Returns `false` if none was found.
+    fn skip_markup(&mut self) -> std::result::Result<bool, String> {
+        let rest = self.rest();
+        if rest.starts_with("<!--") {
+            self.skip_past("-->")?;
+        } else if rest.starts_with("<![CDATA[") {
+            self.skip_past("]]>")?;
+        } else if rest.starts_with("<?") {
+            self.skip_past("?>")?;
+        } else if rest.starts_with("<!") {
+            self.skip_past(">")?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn name(&mut self) -> &'a str {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    fn document(&mut self) -> std::result::Result<SourceElement, String> {
+        let mut scope = Scope::new();
+        scope.insert("xml".to_string(), XML_NAMESPACE.to_string());
+        loop {
+            self.skip_whitespace();
+            if !self.skip_markup()? {
+                break;
+            }
+        }
+        if !self.rest().starts_with('<') {
+            return Err("expected the root element".to_string());
+        }
+        self.element(&scope)
+    }
+
+    fn element(&mut self, parent_scope: &Scope) -> std::result::Result<SourceElement, String> {
+        let start = self.position;
+        self.position += 1;
+        let qualified_name = self.name().to_string();
+        let name_end = self.position;
+
+        let mut attributes = Vec::new();
+        let self_closing = loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.position += 2;
+                break true;
+            }
+            if rest.starts_with('>') {
+                self.position += 1;
+                break false;
+            }
+            let attribute_start = self.position;
+            let attribute_name = self.name();
+            if attribute_name.is_empty() {
+                return Err(format!("malformed start tag <{qualified_name}>"));
+            }
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(format!("expected '=' after {attribute_name}"));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+            let quote = self
+                .rest()
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '"' | '\''))
+                .ok_or_else(|| format!("expected quoted value of {attribute_name}"))?;
+            let value_start = self.position + 1;
+            let value_end = self.text[value_start..]
+                .find(quote)
+                .map(|index| value_start + index)
+                .ok_or_else(|| format!("unterminated value of {attribute_name}"))?;
+            self.position = value_end + 1;
+            attributes.push((
+                attribute_name,
+                attribute_start,
+                value_start,
+                value_end,
+                quote,
+            ));
+        };
+        let start_tag_end = self.position;
+
+        let mut scope = parent_scope.clone();
+        for &(attribute_name, _, value_start, value_end, _) in &attributes {
+            let uri = self.text[value_start..value_end].to_string();
+            if attribute_name == "xmlns" {
+                scope.insert(String::new(), uri);
+            } else if let Some(prefix) = attribute_name.strip_prefix("xmlns:") {
+                scope.insert(prefix.to_string(), uri);
+            }
+        }
+        let resolve = |prefix: &str| -> std::result::Result<Option<String>, String> {
+            match scope.get(prefix) {
+                Some(uri) if uri.is_empty() => Ok(None),
+                Some(uri) => Ok(Some(uri.clone())),
+                None if prefix.is_empty() => Ok(None),
+                None => Err(format!("undeclared namespace prefix {prefix}")),
+            }
+        };
+
+        let (namespace, name) = match qualified_name.split_once(':') {
+            Some((prefix, name)) => (resolve(prefix)?, name.to_string()),
+            None => (resolve("")?, qualified_name.clone()),
+        };
+        let attributes = attributes
+            .into_iter()
+            .map(
+                |(attribute_name, attribute_start, value_start, value_end, quote)| {
+                    let declaration =
+                        attribute_name == "xmlns" || attribute_name.starts_with("xmlns:");
+                    let (namespace, name) = match attribute_name.split_once(':') {
+                        Some(_) if declaration => (None, attribute_name.to_string()),
+                        Some((prefix, name)) => (resolve(prefix)?, name.to_string()),
+                        None => (None, attribute_name.to_string()),
+                    };
+                    Ok(SourceAttribute {
+                        namespace,
+                        name,
+                        declaration,
+                        start: attribute_start,
+                        value_start,
+                        value_end,
+                        end: value_end + 1,
+                        quote,
+                    })
+                },
+            )
+            .collect::<std::result::Result<Vec<_>, String>>()?;
+
+        let mut children = Vec::new();
+        let content_end = if self_closing {
+            start_tag_end
+        } else {
+            loop {
+                let index = self
+                    .rest()
+                    .find('<')
+                    .ok_or_else(|| format!("missing end tag of <{qualified_name}>"))?;
+                self.position += index;
+                if self.skip_markup()? {
+                    continue;
+                }
+                if self.rest().starts_with("</") {
+                    let content_end = self.position;
+                    self.skip_past(">")?;
+                    break content_end;
+                }
+                children.push(self.element(&scope)?);
+            }
+        };
+
+        Ok(SourceElement {
+            qualified_name,
+            namespace,
+            name,
+            start,
+            name_end,
+            start_tag_end,
+            self_closing,
+            content_end,
+            end: self.position,
+            attributes,
+            children,
+            scope,
+        })
+    }
+}
+
+/// A replacement of the source text between `start` and `end`.
+#[derive(Debug)]
+struct Edit {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+/// Collects the edits that turn the source text into the new manifest.
+struct Editor<'a> {
+    source: &'a str,
+    newline: &'static str,
+    indent_unit: String,
+    /// Most common way empty-element tags are closed in the source.
+    empty_element_end: &'static str,
+    edits: Vec<Edit>,
+}
+
+impl<'a> Editor<'a> {
+    fn new(source: &'a str, root: &SourceElement) -> Self {
+        let mut editor = Editor {
+            source,
+            newline: if source.contains("\r\n") {
+                "\r\n"
+            } else {
+                "\n"
+            },
+            indent_unit: String::new(),
+            empty_element_end: if source.matches(" />").count() * 2 >= source.matches("/>").count()
+            {
+                " />"
+            } else {
+                "/>"
+            },
+            edits: Vec::new(),
+        };
+        editor.indent_unit = editor
+            .detect_indent_unit(root)
+            .unwrap_or_else(|| "    ".to_string());
+        editor
+    }
+
+    fn detect_indent_unit(&self, element: &SourceElement) -> Option<String> {
+        let indent = self.indentation(element.start)?;
+        element.children.iter().find_map(|child| {
+            self.indentation(child.start)
+                .and_then(|child_indent| child_indent.strip_prefix(indent))
+                .filter(|unit| !unit.is_empty())
+                .map(str::to_string)
+                .or_else(|| self.detect_indent_unit(child))
+        })
+    }
+
+    /// Whitespace between the start of the line and `position`, if there is nothing
+    /// else on the line before it.
+    fn indentation(&self, position: usize) -> Option<&'a str> {
+        let line_start = self.source[..position]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let prefix = &self.source[line_start..position];
+        prefix
+            .chars()
+            .all(|c| c == ' ' || c == '\t')
+            .then_some(prefix)
+    }
+
+    fn child_indentation(&self, parent: &SourceElement) -> String {
+        format!(
+            "{}{}",
+            self.indentation(parent.start).unwrap_or_default(),
+            self.indent_unit
+        )
+    }
+
+    fn replace(&mut self, start: usize, end: usize, text: String) {
+        self.edits.push(Edit { start, end, text });
+    }
+
+    fn finish(mut self) -> String {
+        self.edits.sort_by_key(|edit| edit.start);
+        let mut output = String::with_capacity(self.source.len());
+        let mut cursor = 0;
+        for edit in &self.edits {
+            if edit.start > cursor {
+                output.push_str(&self.source[cursor..edit.start]);
+            }
+            output.push_str(&edit.text);
+            cursor = cursor.max(edit.end);
+        }
+        output.push_str(&self.source[cursor..]);
+        output
+    }
+
+    fn edit_element(&mut self, source: &SourceElement, old: &ExtraElement, new: &ExtraElement) {
+        self.edit_attributes(source, old, new);
+        if has_element_children(old) || has_element_children(new) {
+            self.edit_children(source, old, new);
+        } else if text_content(old) != text_content(new) && source.children.is_empty() {
+            self.replace_text(source, &text_content(new));
+        }
+    }
+
+    fn edit_attributes(&mut self, source: &SourceElement, old: &ExtraElement, new: &ExtraElement) {
+        let mut scope = source.scope.clone();
+        for attribute in &new.attributes {
+            let namespace = attribute.namespace.as_deref();
+            let previous = find_attribute(old, namespace, &attribute.name);
+            if previous.is_some_and(|previous| previous.value == attribute.value) {
+                continue;
+            }
+            match source.attribute(namespace, &attribute.name) {
+                Some(index) => {
+                    let existing = &source.attributes[index];
+                    let value = escape_attribute(&attribute.value, existing.quote);
+                    self.replace(existing.value_start, existing.value_end, value);
+                }
+                None => {
+                    let separator = self.attribute_separator(source);
+                    let mut declarations = Vec::new();
+                    let name = qualify(
+                        &mut scope,
+                        &mut declarations,
+                        namespace,
+                        attribute.prefix.as_deref(),
+                        &attribute.name,
+                    );
+                    let mut text = String::new();
+                    for (prefix, uri) in declarations {
+                        text += &format!(
+                            "{separator}xmlns:{prefix}=\"{}\"",
+                            escape_attribute(&uri, '"')
+                        );
+                    }
+                    text += &format!(
+                        "{separator}{name}=\"{}\"",
+                        escape_attribute(&attribute.value, '"')
+                    );
+                    let position = source.attributes_end();
+                    self.replace(position, position, text);
+                }
+            }
+        }
+        for attribute in &old.attributes {
+            let namespace = attribute.namespace.as_deref();
+            if find_attribute(new, namespace, &attribute.name).is_some() {
+                continue;
+            }
+            if let Some(index) = source.attribute(namespace, &attribute.name) {
+                let start = source.attribute_preceding_end(index);
+                self.replace(start, source.attributes[index].end, String::new());
+            }
+        }
+    }
+
+    /// Whitespace used between attributes of the start tag.
+    fn attribute_separator(&self, source: &SourceElement) -> String {
+        match source.attributes.len() {
+            0 => " ".to_string(),
+            length => {
+                let start = source.attribute_preceding_end(length - 1);
+                self.source[start..source.attributes[length - 1].start].to_string()
+            }
+        }
+    }
+
+    fn edit_children(&mut self, source: &SourceElement, old: &ExtraElement, new: &ExtraElement) {
+        let mut groups: Vec<(Option<&str>, &str)> = Vec::new();
+        for child in child_elements(new).chain(child_elements(old)) {
+            let key = (child.namespace.as_deref(), child.name.as_str());
+            if !groups.contains(&key) {
+                groups.push(key);
+            }
+        }
+
+        // Insertions into a parent without any child elements are collected and
+        // written at once.
+        let mut appended = Vec::new();
+        let mut previous_group_end: Option<&SourceElement> = None;
+        for (namespace, name) in groups {
+            let olds: Vec<&ExtraElement> = child_elements(old)
+                .filter(|child| child.namespace.as_deref() == namespace && child.name == name)
+                .collect();
+            let news: Vec<&ExtraElement> = child_elements(new)
+                .filter(|child| child.namespace.as_deref() == namespace && child.name == name)
+                .collect();
+            let sources = source.children_named(namespace, name);
+
+            let mut anchor: Option<&SourceElement> = None;
+            for (old_index, new_index) in match_siblings(&olds, &news) {
+                let existing = old_index.and_then(|index| sources.get(index).copied());
+                match (old_index, new_index) {
+                    (Some(old_index), Some(new_index)) => {
+                        if olds[old_index] != news[new_index] {
+                            match existing {
+                                Some(existing) => {
+                                    self.edit_element(existing, olds[old_index], news[new_index])
+                                }
+                                None => self.insert_element(
+                                    source,
+                                    anchor.or(previous_group_end),
+                                    sources.first().copied(),
+                                    news[new_index],
+                                    &mut appended,
+                                ),
+                            }
+                        }
+                    }
+                    (Some(_), None) => {
+                        if let Some(existing) = existing {
+                            self.delete_element(existing);
+                        }
+                    }
+                    (None, Some(new_index)) => self.insert_element(
+                        source,
+                        anchor.or(previous_group_end),
+                        sources.first().copied(),
+                        news[new_index],
+                        &mut appended,
+                    ),
+                    (None, None) => {}
+                }
+                if existing.is_some() {
+                    anchor = existing;
+                }
+            }
+            if let Some(last) = sources.last() {
+                previous_group_end = Some(last);
+            }
+        }
+
+        if !appended.is_empty() {
+            self.append_children(source, &appended);
+        }
+    }
+
+    /// Inserts `element` after `after`, before `before` or, if the parent has no
+    /// child elements yet, into the parent.
+    fn insert_element(
+        &mut self,
+        parent: &SourceElement,
+        after: Option<&SourceElement>,
+        before: Option<&SourceElement>,
+        element: &ExtraElement,
+        appended: &mut Vec<String>,
+    ) {
+        let sibling = after.or(before).or_else(|| parent.children.first());
+        let Some(sibling) = sibling else {
+            let indent = self.child_indentation(parent);
+            appended.push(self.render(element, &parent.scope, &indent, self.empty_element_end));
+            return;
+        };
+        let indent = self
+            .indentation(sibling.start)
+            .map(str::to_string)
+            .unwrap_or_else(|| self.child_indentation(parent));
+        // New elements are closed like their sibling.
+        let empty_element_end = if !sibling.self_closing {
+            self.empty_element_end
+        } else if self.source[..sibling.start_tag_end].ends_with(" />") {
+            " />"
+        } else {
+            "/>"
+        };
+        let text = self.render(element, &parent.scope, &indent, empty_element_end);
+        match after {
+            Some(after) => self.replace(
+                after.end,
+                after.end,
+                format!("{}{indent}{text}", self.newline),
+            ),
+            None => self.replace(
+                sibling.start,
+                sibling.start,
+                format!("{text}{}{indent}", self.newline),
+            ),
+        }
+    }
+
+    fn append_children(&mut self, parent: &SourceElement, children: &[String]) {
+        let indent = self.child_indentation(parent);
+        let parent_indent = self.indentation(parent.start).unwrap_or_default();
+        let mut text = String::new();
+        for child in children {
+            text += &format!("{}{indent}{child}", self.newline);
+        }
+        text += &format!("{}{parent_indent}", self.newline);
+        if parent.self_closing {
+            let start = parent.attributes_end();
+            self.replace(
+                start,
+                parent.start_tag_end,
+                format!(">{text}</{}>", parent.qualified_name),
+            );
+        } else if self.source[parent.start_tag_end..parent.content_end]
+            .trim()
+            .is_empty()
+        {
+            self.replace(parent.start_tag_end, parent.content_end, text);
+        } else {
+            self.replace(parent.content_end, parent.content_end, text);
+        }
+    }
+
+    fn delete_element(&mut self, element: &SourceElement) {
+        let mut start = element.start;
+        if self.indentation(start).is_some() {
+            start = self.source[..start].rfind('\n').unwrap_or(0);
+            if self.source[..start].ends_with('\r') {
+                start -= 1;
+            }
+        }
+        self.replace(start, element.end, String::new());
+    }
+
+    fn replace_text(&mut self, element: &SourceElement, text: &str) {
+        let text = escape_text(text);
+        if element.self_closing {
+            let start = element.attributes_end();
+            self.replace(
+                start,
+                element.start_tag_end,
+                format!(">{text}</{}>", element.qualified_name),
+            );
+        } else {
+            self.replace(element.start_tag_end, element.content_end, text);
+        }
+    }
+
+    /// Formats a new element, indenting its children relative to `indent`.
+    fn render(
+        &self,
+        element: &ExtraElement,
+        scope: &Scope,
+        indent: &str,
+        empty_element_end: &str,
+    ) -> String {
+        let mut scope = scope.clone();
+        let mut declarations = Vec::new();
+        let name = qualify(
+            &mut scope,
+            &mut declarations,
+            element.namespace.as_deref(),
+            element.prefix.as_deref(),
+            &element.name,
+        );
+        let attributes: Vec<(String, &str)> = element
+            .attributes
+            .iter()
+            .map(|attribute| {
+                let name = qualify(
+                    &mut scope,
+                    &mut declarations,
+                    attribute.namespace.as_deref(),
+                    attribute.prefix.as_deref(),
+                    &attribute.name,
+                );
+                (name, attribute.value.as_str())
+            })
+            .collect();
+
+        let mut text = format!("<{name}");
+        for (prefix, uri) in &declarations {
+            text += &format!(" xmlns:{prefix}=\"{}\"", escape_attribute(uri, '"'));
+        }
+        for (attribute, value) in attributes {
+            text += &format!(" {attribute}=\"{}\"", escape_attribute(value, '"'));
+        }
+
+        if element.children.is_empty() {
+            text += empty_element_end;
+        } else if !has_element_children(element) {
+            text += &format!(">{}</{name}>", escape_text(&text_content(element)));
+        } else {
+            text.push('>');
+            let child_indent = format!("{indent}{}", self.indent_unit);
+            for child in &element.children {
+                let child = match child {
+                    ExtraNode::Element(child) => {
+                        self.render(child, &scope, &child_indent, empty_element_end)
+                    }
+                    ExtraNode::Text(child) => escape_text(child),
+                };
+                text += &format!("{}{child_indent}{child}", self.newline);
+            }
+            text += &format!("{}{indent}</{name}>", self.newline);
+        }
+        text
+    }
+}
+
+/// Pairs up same-named siblings of the old and new tree, keeping unchanged
+/// elements matched so that inserting or removing one doesn't touch the others.
+fn match_siblings(
+    olds: &[&ExtraElement],
+    news: &[&ExtraElement],
+) -> Vec<(Option<usize>, Option<usize>)> {
+    // Longest common subsequence of equal elements.
+    let mut lengths = vec![vec![0usize; news.len() + 1]; olds.len() + 1];
+    for old in (0..olds.len()).rev() {
+        for new in (0..news.len()).rev() {
+            lengths[old][new] = if olds[old] == news[new] {
+                lengths[old + 1][new + 1] + 1
+            } else {
+                lengths[old + 1][new].max(lengths[old][new + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut old, mut new) = (0, 0);
+    // Unmatched elements between two common ones are paired up in order and
+    // treated as modified.
+    let mut pending_olds = Vec::new();
+    let mut pending_news = Vec::new();
+    let flush = |pairs: &mut Vec<_>, olds: &mut Vec<usize>, news: &mut Vec<usize>| {
+        let length = olds.len().max(news.len());
+        for index in 0..length {
+            pairs.push((olds.get(index).copied(), news.get(index).copied()));
+        }
+        olds.clear();
+        news.clear();
+    };
+    while old < olds.len() || new < news.len() {
+        if old < olds.len() && new < news.len() && olds[old] == news[new] {
+            flush(&mut pairs, &mut pending_olds, &mut pending_news);
+            pairs.push((Some(old), Some(new)));
+            old += 1;
+            new += 1;
+        } else if new == news.len()
+            || (old < olds.len() && lengths[old + 1][new] >= lengths[old][new + 1])
+        {
+            pending_olds.push(old);
+            old += 1;
+        } else {
+            pending_news.push(new);
+            new += 1;
+        }
+    }
+    flush(&mut pairs, &mut pending_olds, &mut pending_news);
+    pairs
+}
+
+/// Returns the qualified name for `name` in `namespace`, reusing a prefix that is
+/// already in scope or declaring `prefix` otherwise.
+fn qualify(
+    scope: &mut Scope,
+    declarations: &mut Vec<(String, String)>,
+    namespace: Option<&str>,
+    prefix: Option<&str>,
+    name: &str,
+) -> String {
+    let Some(uri) = namespace else {
+        return name.to_string();
+    };
+    if prefix.is_none() && scope.get("").is_some_and(|default| default == uri) {
+        return name.to_string();
+    }
+    let bound = prefix
+        .filter(|prefix| scope.get(*prefix).is_some_and(|bound| bound == uri))
+        .map(str::to_string)
+        .or_else(|| {
+            scope
+                .iter()
+                .find(|(prefix, bound)| !prefix.is_empty() && bound.as_str() == uri)
+                .map(|(prefix, _)| prefix.clone())
+        });
+    let prefix = bound.unwrap_or_else(|| {
+        let prefix = prefix.unwrap_or("ns").to_string();
+        scope.insert(prefix.clone(), uri.to_string());
+        declarations.push((prefix.clone(), uri.to_string()));
+        prefix
+    });
+    format!("{prefix}:{name}")
+}
+
+fn find_attribute<'e>(
+    element: &'e ExtraElement,
+    namespace: Option<&str>,
+    name: &str,
+) -> Option<&'e ExtraAttribute> {
+    element
+        .attributes
+        .iter()
+        .find(|attribute| attribute.namespace.as_deref() == namespace && attribute.name == name)
+}
+
+fn child_elements(element: &ExtraElement) -> impl Iterator<Item = &ExtraElement> {
+    element.children.iter().filter_map(|child| match child {
+        ExtraNode::Element(child) => Some(child),
+        ExtraNode::Text(_) => None,
+    })
+}
+
+fn has_element_children(element: &ExtraElement) -> bool {
+    child_elements(element).next().is_some()
+}
+
+fn text_content(element: &ExtraElement) -> String {
+    element
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            ExtraNode::Text(text) => Some(text.as_str()),
+            ExtraNode::Element(_) => None,
+        })
+        .collect()
+}
+
+fn escape_attribute(value: &str, quote: char) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '"' if quote == '"' => escaped.push_str("&quot;"),
+            '\'' if quote == '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\t' => escaped.push_str("&#9;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
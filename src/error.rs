@@ -1,3 +1,4 @@
+use std::fmt;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -7,5 +8,173 @@ pub enum Error {
     #[error("Failed to serialize AndroidManifest.xml. Error: {0}")]
     FailedToSerialize(String),
     #[error("Failed to deserialize AndroidManifest.xml. Error: {0}")]
-    FailedToDeserialize(String),
+    FailedToDeserialize(Box<DeserializeError>),
+    #[error("Failed to read AndroidManifest.xml from archive. Error: {0}")]
+    FailedToReadArchive(String),
+}
+
+impl Error {
+    pub(crate) fn deserialize(error: impl Into<DeserializeError>) -> Error {
+        Error::FailedToDeserialize(Box::new(error.into()))
+    }
+}
+
+impl From<DeserializeError> for Error {
+    fn from(error: DeserializeError) -> Self {
+        Error::deserialize(error)
+    }
+}
+
+/// The kind of problem a [`DeserializeError`] describes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The document is not well-formed XML.
+    Syntax,
+    /// An attribute or element value can't be converted to the type of its field.
+    InvalidValue,
+    /// A required attribute or element is missing.
+    MissingField,
+    /// An attribute or element the model does not recognise, reported in
+    /// strict mode.
+    UnknownField,
+    /// A value refers to something the manifest doesn't declare, or something is
+    /// declared more than once, reported by
+    /// [`AndroidManifest::validate`](crate::AndroidManifest::validate).
+    Inconsistent,
+    /// Any other problem, such as a malformed binary manifest.
+    Other,
+}
+
+/// Line and column in the XML text, both counted from 1.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Describes why and where deserializing a manifest failed.
+///
+/// ## Example
+/// ```rust
+/// use android_manifest::error::{Error, ErrorKind};
+///
+/// let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android">
+///   <application>
+///     <activity android:name=".Main" android:exported="maybe" />
+///   </application>
+/// </manifest>"#;
+///
+/// let Err(Error::FailedToDeserialize(error)) = android_manifest::from_str(xml) else {
+///     panic!("expected a deserialization error");
+/// };
+/// assert_eq!(error.kind, ErrorKind::InvalidValue);
+/// assert_eq!(error.path.as_deref(), Some("manifest/application/activity[0]"));
+/// assert_eq!(error.attribute.as_deref(), Some("android:exported"));
+/// assert_eq!(error.value.as_deref(), Some("maybe"));
+/// assert_eq!(error.position.map(|position| position.line), Some(3));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DeserializeError {
+    pub kind: ErrorKind,
+    pub message: String,
+    /// Path of the element the error occurred in, for example
+    /// `manifest/application/activity[2]/intent-filter[0]`. Elements that may
+    /// occur more than once carry their index among same-named siblings.
+    pub path: Option<String>,
+    /// Qualified name of the offending attribute.
+    pub attribute: Option<String>,
+    /// The offending value.
+    pub value: Option<String>,
+    /// Where the offending element starts in the XML text.
+    pub position: Option<Position>,
+}
+
+impl DeserializeError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        DeserializeError {
+            kind,
+            message: message.into(),
+            path: None,
+            attribute: None,
+            value: None,
+            position: None,
+        }
+    }
+}
+
+impl From<String> for DeserializeError {
+    fn from(message: String) -> Self {
+        DeserializeError::new(ErrorKind::Other, message)
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(position) = self.position {
+            write!(f, "{position}: ")?;
+        }
+        match (&self.attribute, &self.value, self.kind) {
+            (Some(attribute), Some(value), ErrorKind::InvalidValue) => {
+                write!(f, "invalid value {value:?} of {attribute}: ")?;
+            }
+            (Some(attribute), Some(value), ErrorKind::UnknownField) => {
+                write!(f, "unknown attribute {attribute}={value:?}: ")?;
+            }
+            (Some(attribute), _, ErrorKind::MissingField) => {
+                write!(f, "missing {attribute}: ")?;
+            }
+            (Some(attribute), Some(value), _) => write!(f, "{attribute}={value:?}: ")?,
+            (Some(attribute), None, _) => write!(f, "{attribute}: ")?,
+            (None, _, _) => {}
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(path) = &self.path {
+            write!(f, " (in {path})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+/// How serious a [`Diagnostic`] is.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Severity {
+    /// The value could not be used and was left unset or at its default.
+    Error,
+    /// The manifest was read as written, but likely contains a mistake, such
+    /// as an unknown attribute that was kept in `extras`.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A problem found by [`from_str_recovering`](crate::from_str_recovering) that
+/// did not stop the parse, or by
+/// [`AndroidManifest::validate`](crate::AndroidManifest::validate).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// What went wrong and where.
+    pub error: DeserializeError,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.error)
+    }
 }
@@ -0,0 +1,270 @@
+use crate::error::{DeserializeError, ErrorKind};
+use crate::xml::de::Deserializer;
+use crate::xml::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use xml::attribute::OwnedAttribute;
+use xml::name::{Name, OwnedName};
+use xml::namespace::Namespace;
+use xml::reader::XmlEvent as ReaderEvent;
+use xml::writer::XmlEvent;
+
+/// Attributes and child elements of a manifest element that this crate does not
+/// model.
+///
+/// Every manifest element keeps its unrecognised content here in document order, so
+/// that vendor meta-data, attributes introduced by newer platform versions and
+/// elements from third-party namespaces survive a parse-then-serialize round-trip.
+///
+/// Unknown child elements remember the sibling they followed in their
+/// [`position`](ExtraElement::position) and are written back right after it, even if
+/// the known children are written in a different order than they were parsed in.
+/// Unknown attributes are written after the known ones, as attribute order has no
+/// meaning in XML.
+///
+/// ## Example
+/// ```rust
+/// let manifest = android_manifest::from_str(
+///     r#"<manifest package="com.example" xmlns:vendor="https://example.com/vendor">
+///          <application vendor:flavor="free">
+///            <vendor:tracking enabled="false"/>
+///          </application>
+///        </manifest>"#,
+/// )
+/// .unwrap();
+///
+/// let extras = &manifest.application.extras;
+/// assert_eq!(extras.attributes[0].name, "flavor");
+/// assert_eq!(extras.elements[0].name, "tracking");
+///
+/// let xml = android_manifest::to_string(&manifest).unwrap();
+/// assert!(xml.contains(r#"vendor:flavor="free""#));
+/// ```
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default, Clone)]
+pub struct Extras {
+    /// Unknown attributes of the element.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<ExtraAttribute>,
+    /// Unknown child elements of the element.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub elements: Vec<ExtraElement>,
+}
+
+/// An attribute that is not modelled by this crate.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default, Clone)]
+pub struct ExtraAttribute {
+    /// Prefix of the attribute name, for example `vendor` in `vendor:flavor`.
+    pub prefix: Option<String>,
+    /// Namespace URI bound to the prefix.
+    pub namespace: Option<String>,
+    /// Local name of the attribute.
+    pub name: String,
+    /// Raw attribute value.
+    pub value: String,
+}
+
+/// An element that is not modelled by this crate, including everything inside it.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default, Clone)]
+pub struct ExtraElement {
+    /// Prefix of the element name.
+    pub prefix: Option<String>,
+    /// Namespace URI bound to the prefix.
+    pub namespace: Option<String>,
+    /// Local name of the element.
+    pub name: String,
+    /// Where the element sat among the children of its parent. Elements without a
+    /// position, or whose preceding sibling is no longer written, are written after
+    /// the known children. Only set on the elements of [`Extras`], not on their
+    /// descendants.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<ExtraPosition>,
+    /// Attributes of the element.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<ExtraAttribute>,
+    /// Child elements and text of the element.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<ExtraNode>,
+}
+
+/// Where an [`ExtraElement`] sat among the child elements of its parent.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub enum ExtraPosition {
+    /// Before any other child element.
+    First,
+    /// Right after another child element, known or not: the `index`-th one, counting
+    /// from zero, with the local name `name`.
+    After { name: String, index: usize },
+}
+
+/// Content of an [`ExtraElement`].
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub enum ExtraNode {
+    Element(ExtraElement),
+    Text(String),
+}
+
+impl Extras {
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.elements.is_empty()
+    }
+
+    /// Keeps an attribute the element does not model, unless strict parsing
+    /// rejects it.
+    pub(crate) fn push_attribute<R: Read>(
+        &mut self,
+        reader: &mut Deserializer<R>,
+        attribute: &OwnedAttribute,
+    ) -> Result<(), DeserializeError> {
+        reader.check_unknown_attribute(attribute)?;
+        self.attributes.push(attribute.into());
+        Ok(())
+    }
+
+    /// Reads the rest of an unknown element whose start tag was just consumed,
+    /// unless strict parsing rejects it.
+    pub(crate) fn read_element<R: Read>(
+        &mut self,
+        reader: &mut Deserializer<R>,
+        start: &ReaderEvent,
+    ) -> Result<(), DeserializeError> {
+        if let ReaderEvent::StartElement {
+            name, attributes, ..
+        } = start
+        {
+            reader.check_unknown_element(name)?;
+            let position = match reader.previous_sibling() {
+                Some((name, index)) => ExtraPosition::After { name, index },
+                None => ExtraPosition::First,
+            };
+            let mut element = ExtraElement::read(reader, name, attributes)?;
+            element.position = Some(position);
+            self.elements.push(element);
+            Ok(())
+        } else {
+            Err(reader.error(ErrorKind::Syntax, "expected a start element"))
+        }
+    }
+
+    pub(crate) fn write_attributes(
+        &self,
+        attributes: &mut Vec<OwnedAttribute>,
+        namespace: &mut Namespace,
+    ) {
+        for attribute in &self.attributes {
+            attribute.declare_namespace(namespace);
+            attributes.push(attribute.into());
+        }
+    }
+
+    /// Hands the unknown child elements to the serializer, which writes each one
+    /// after the sibling it followed once that has been written.
+    pub(crate) fn write_elements<W: Write>(
+        &self,
+        writer: &mut Serializer<W>,
+    ) -> Result<(), String> {
+        writer.insert_elements(&self.elements)
+    }
+}
+
+impl ExtraAttribute {
+    fn declare_namespace(&self, namespace: &mut Namespace) {
+        if let (Some(prefix), Some(uri)) = (&self.prefix, &self.namespace) {
+            namespace.put(prefix.as_str(), uri.as_str());
+        }
+    }
+}
+
+impl From<&OwnedAttribute> for ExtraAttribute {
+    fn from(attribute: &OwnedAttribute) -> Self {
+        ExtraAttribute {
+            prefix: attribute.name.prefix.clone(),
+            namespace: attribute.name.namespace.clone(),
+            name: attribute.name.local_name.clone(),
+            value: attribute.value.clone(),
+        }
+    }
+}
+
+impl From<&ExtraAttribute> for OwnedAttribute {
+    fn from(attribute: &ExtraAttribute) -> Self {
+        OwnedAttribute {
+            name: OwnedName {
+                local_name: attribute.name.clone(),
+                namespace: attribute.namespace.clone(),
+                prefix: attribute.prefix.clone(),
+            },
+            value: attribute.value.clone(),
+        }
+    }
+}
+
+impl ExtraElement {
+    pub(crate) fn read<R: Read>(
+        reader: &mut Deserializer<R>,
+        name: &OwnedName,
+        attributes: &[OwnedAttribute],
+    ) -> Result<Self, DeserializeError> {
+        let mut element = ExtraElement {
+            prefix: name.prefix.clone(),
+            namespace: name.namespace.clone(),
+            name: name.local_name.clone(),
+            position: None,
+            attributes: attributes.iter().map(ExtraAttribute::from).collect(),
+            children: Vec::new(),
+        };
+        loop {
+            match reader.next_event()? {
+                ReaderEvent::StartElement {
+                    name, attributes, ..
+                } => element.children.push(ExtraNode::Element(ExtraElement::read(
+                    reader,
+                    &name,
+                    &attributes,
+                )?)),
+                ReaderEvent::Characters(text) => element.children.push(ExtraNode::Text(text)),
+                ReaderEvent::EndElement { .. } => return Ok(element),
+                ReaderEvent::EndDocument => {
+                    return Err(reader.error(
+                        ErrorKind::Syntax,
+                        format!("unexpected end of document in <{}>", element.name),
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut Serializer<W>) -> xml::writer::Result<()> {
+        let mut start = XmlEvent::start_element(Name {
+            local_name: &self.name,
+            namespace: self.namespace.as_deref(),
+            prefix: self.prefix.as_deref(),
+        });
+        if let (Some(prefix), Some(uri)) = (&self.prefix, &self.namespace) {
+            start = start.ns(prefix.as_str(), uri.as_str());
+        }
+        for attribute in &self.attributes {
+            if let (Some(prefix), Some(uri)) = (&attribute.prefix, &attribute.namespace) {
+                start = start.ns(prefix.as_str(), uri.as_str());
+            }
+        }
+        for attribute in &self.attributes {
+            start = start.attr(
+                Name {
+                    local_name: &attribute.name,
+                    namespace: attribute.namespace.as_deref(),
+                    prefix: attribute.prefix.as_deref(),
+                },
+                &attribute.value,
+            );
+        }
+        writer.write(start)?;
+        for child in &self.children {
+            match child {
+                ExtraNode::Element(element) => element.write(writer)?,
+                ExtraNode::Text(text) => writer.write(XmlEvent::characters(text))?,
+            }
+        }
+        writer.write(XmlEvent::end_element())
+    }
+}
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Specifies the subsets of app data that parent content provider can access.
@@ -12,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// is `"false"` permission can be granted only to data subsets that are specified
 /// by this element. A provider can contain any number of
 /// `<grant-uri-permission>` elements. Each one can specify only one path (only
-/// one of the three possible attributes).
+/// one of the five possible attributes).
 ///
 /// For information on how permission is granted, see the [`<intent-filter>`] element's
 /// [`grantUriPermissions`] attribute.
@@ -21,7 +22,9 @@ use serde::{Deserialize, Serialize};
 /// ```xml
 /// <grant-uri-permission android:path="string"
 ///                       android:pathPattern="string"
-///                       android:pathPrefix="string" />
+///                       android:pathPrefix="string"
+///                       android:pathSuffix="string"
+///                       android:pathAdvancedPattern="string" />
 /// ```
 ///
 /// ## Contained in
@@ -69,4 +72,23 @@ pub struct GrantUriPermission {
     pub path_pattern: Option<String>,
     #[xml(attribute = true, prefix = "android", rename = "pathPrefix")]
     pub path_prefix: Option<String>,
+    /// The final part of a path; permission can be granted to all data subsets with
+    /// paths that share that final part.
+    ///
+    /// This attribute was introduced in API Level 31.
+    #[xml(attribute = true, prefix = "android", rename = "pathSuffix")]
+    pub path_suffix: Option<String>,
+    /// A complete path matched with an advanced pattern, which supports character
+    /// classes such as `[a-z]`, `+` and `{min,max}` ranges. See
+    /// [`PATTERN_ADVANCED_GLOB`].
+    ///
+    /// This attribute was introduced in API Level 31.
+    ///
+    /// [`PATTERN_ADVANCED_GLOB`]: https://developer.android.com/reference/android/os/PatternMatcher#PATTERN_ADVANCED_GLOB
+    #[xml(attribute = true, prefix = "android", rename = "pathAdvancedPattern")]
+    pub path_advanced_pattern: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use crate::VarOrBool;
 
 use super::resources::{MipmapOrDrawableResource, StringResourceOrString};
@@ -17,7 +18,14 @@ use serde::{Deserialize, Serialize};
 ///                  android:label="string resource"
 ///                  android:name="string"
 ///                  android:targetPackage="string"
-///                  android:targetProcesses="string" />
+///                  android:targetProcesses="string"
+///                  tools:replace="string"
+///                  tools:remove="string"
+///                  tools:node=["merge" | "replace" | "removeAll" | "merge-only" | "strict"]
+///                  tools:ignore="string"
+///                  tools:targetApi="string"
+///                  tools:selector="string"
+///                  tools:strict="string" />
 /// ```
 ///
 /// ## Contained in:
@@ -80,4 +88,46 @@ pub struct Instrumentation {
     /// [`android:targetPackage`]: crate::Instrumentation#structfield.target_package
     #[xml(attribute = true, prefix = "android", rename = "targetProcesses")]
     pub target_processes: Option<String>,
+    /// Specifies which attributes from lower priority manifest files should be replaced
+    /// by attributes from this manifest. This is a comma-separated list of attribute
+    /// names.
+    ///
+    /// Reference: [Merge manifest files - tools:replace](https://developer.android.com/studio/build/manage-manifests#merge-manifests)
+    #[xml(attribute = true, prefix = "tools")]
+    pub replace: Option<String>,
+    /// Specifies which attributes or child elements from lower priority manifest files
+    /// should be removed entirely.
+    ///
+    /// Reference: [Merge manifest files - tools:remove](https://developer.android.com/studio/build/manage-manifests#merge-manifests)
+    #[xml(attribute = true, prefix = "tools")]
+    pub remove: Option<String>,
+    /// Specifies the merge strategy for this element.
+    ///
+    /// Reference: [Merge manifest files - tools:node](https://developer.android.com/studio/build/manage-manifests#merge-manifests)
+    #[xml(attribute = true, prefix = "tools")]
+    pub node: Option<String>,
+    /// Lint issue IDs to ignore on this element.
+    ///
+    /// Reference: [Tools Attributes - tools:ignore](https://developer.android.com/studio/write/tool-attributes#tools-ignore)
+    #[xml(attribute = true, prefix = "tools")]
+    pub ignore: Option<String>,
+    /// Target API level for this element.
+    ///
+    /// Reference: [Tools Attributes - tools:targetApi](https://developer.android.com/studio/write/tool-attributes#toolstargetapi)
+    #[xml(attribute = true, prefix = "tools", rename = "targetApi")]
+    pub target_api: Option<String>,
+    /// Specifies library package names to apply the merge rule to.
+    ///
+    /// Reference: [Merge manifest files - tools:selector](https://developer.android.com/studio/build/manage-manifests#marker_selector)
+    #[xml(attribute = true, prefix = "tools")]
+    pub selector: Option<String>,
+    /// Generate a build failure if attributes don't exactly match.
+    ///
+    /// Reference: [Merge manifest files - tools:strict](https://developer.android.com/studio/build/manage-manifests#attribute_markers)
+    #[xml(attribute = true, prefix = "tools")]
+    pub strict: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
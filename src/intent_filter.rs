@@ -1,6 +1,7 @@
 use super::action::Action;
 use super::category::Category;
 use super::data::Data;
+use super::extras::Extras;
 use super::resources::{MipmapOrDrawableResource, StringResourceOrString};
 use serde::{Deserialize, Serialize};
 
@@ -155,6 +156,10 @@ pub struct IntentFilter {
     /// [`verify-android-applinks`]: https://developer.android.com/training/app-links/verify-android-applinks
     #[xml(attribute = true, prefix = "android", rename = "autoVerify")]
     pub auto_verify: Option<bool>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 #[cfg(test)]
@@ -167,9 +172,11 @@ mod tests {
             order: Some(100),
             action: vec![Action {
                 name: Some("android.intent.action.MAIN".to_string()),
+                ..Default::default()
             }],
             category: vec![Category {
                 name: Some("android.intent.category.LAUNCHER".to_string()),
+                ..Default::default()
             }],
             ..Default::default()
         };
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Affect how an activity behaves in multi-window mode.
@@ -65,6 +66,10 @@ pub struct Layout {
     /// ```
     #[xml(attribute = true, prefix = "android", rename = "minWidth")]
     pub min_width: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 /// Standard constants and tools for placing an object within a potentially
@@ -170,4 +175,10 @@ pub enum Gravity {
     /// Binary mask to get the vertical gravity of a gravity.
     #[xml(rename = "verticalGravityMask")]
     VerticalGravityMask,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(Gravity);
@@ -1,31 +1,66 @@
 #[macro_use]
 extern crate android_manifest_derive;
 
+/// Implements `is_known` for an attribute enum with an `Unknown` variant.
+macro_rules! impl_is_known {
+    ($name:ident) => {
+        impl $name {
+            /// Whether the value is one this version of the crate knows about,
+            /// rather than one captured by the `Unknown` variant.
+            pub fn is_known(&self) -> bool {
+                !matches!(self, $name::Unknown(_))
+            }
+        }
+
+        impl crate::xml::KnownValue for $name {
+            fn unknown_value(&self) -> Option<&str> {
+                match self {
+                    $name::Unknown(value) => Some(value),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
 mod action;
 mod activity;
 mod activity_alias;
+mod apex_system_service;
 mod application;
+mod archive;
 mod attribute_list;
+mod attribution;
 mod category;
+mod cert_digest;
 mod compatible_screens;
+mod compiled;
 mod data;
+pub mod dist;
+mod document;
 pub mod error;
+mod extras;
 mod grant_uri_permission;
 mod instrumentation;
 mod intent_filter;
 mod layout;
 mod manifest;
 mod meta_data;
+mod options;
 mod path_permission;
 mod permission;
 mod permission_group;
 mod permission_tree;
 mod profileable;
+mod property;
+mod protection_level;
 mod provider;
 mod queries;
 mod receiver;
 mod resources;
+mod sdk_library;
 mod service;
+mod static_library;
 mod supports_gl_texture;
 mod supports_screens;
 mod ui_options;
@@ -33,37 +68,53 @@ mod uses_configuration;
 mod uses_feature;
 mod uses_library;
 mod uses_native_library;
+mod uses_package;
 mod uses_permission;
 mod uses_permission_sdk_23;
 mod uses_sdk;
+mod uses_sdk_library;
+mod uses_split;
+mod uses_static_library;
+mod validate;
 mod var_or_bool;
 mod xml;
 
 pub use action::*;
 pub use activity::*;
 pub use activity_alias::*;
+pub use apex_system_service::*;
 pub use application::*;
 pub use attribute_list::*;
+pub use attribution::*;
 pub use category::*;
+pub use cert_digest::CertDigest;
 pub use compatible_screens::*;
+pub use compiled::ResourceIdMap;
 pub use data::*;
-use error::{Error, Result};
+pub use document::ManifestDocument;
+use error::{Diagnostic, Error, Result};
+pub use extras::*;
 pub use grant_uri_permission::*;
 pub use instrumentation::*;
 pub use intent_filter::*;
 pub use layout::*;
 pub use manifest::*;
 pub use meta_data::*;
+pub use options::ParseOptions;
 pub use path_permission::*;
 pub use permission::*;
 pub use permission_group::*;
 pub use permission_tree::*;
 pub use profileable::*;
+pub use property::*;
+pub use protection_level::*;
 pub use provider::*;
 pub use queries::*;
 pub use receiver::*;
 pub use resources::*;
+pub use sdk_library::*;
 pub use service::*;
+pub use static_library::*;
 pub use supports_gl_texture::*;
 pub use supports_screens::*;
 pub use ui_options::*;
@@ -71,21 +122,101 @@ pub use uses_configuration::*;
 pub use uses_feature::*;
 pub use uses_library::*;
 pub use uses_native_library::UsesNativeLibrary;
+pub use uses_package::*;
 pub use uses_permission::*;
 pub use uses_permission_sdk_23::*;
 pub use uses_sdk::*;
+pub use uses_sdk_library::*;
+pub use uses_split::*;
+pub use uses_static_library::*;
 pub use var_or_bool::*;
 
 /// Deserialize an instance of type [`AndroidManifest`] from a
 /// string of XML text.
 pub fn from_str(s: &str) -> Result<AndroidManifest> {
-    crate::xml::de::from_str(s).map_err(Error::FailedToDeserialize)
+    crate::xml::de::from_str(s).map_err(Error::from)
 }
 
 /// Deserialize an instance of type [`AndroidManifest`] from an IO
 /// stream of XML text.
 pub fn from_reader<R: std::io::Read>(reader: R) -> Result<AndroidManifest> {
-    crate::xml::de::from_reader(reader).map_err(Error::FailedToDeserialize)
+    crate::xml::de::from_reader(reader).map_err(Error::from)
+}
+
+/// Deserialize an instance of type [`AndroidManifest`] from a
+/// string of XML text, parsed according to `options`.
+pub fn from_str_with_options(s: &str, options: &ParseOptions) -> Result<AndroidManifest> {
+    from_reader_with_options(s.as_bytes(), options)
+}
+
+/// Deserialize an instance of type [`AndroidManifest`] from an IO
+/// stream of XML text, parsed according to `options`.
+pub fn from_reader_with_options<R: std::io::Read>(
+    reader: R,
+    options: &ParseOptions,
+) -> Result<AndroidManifest> {
+    crate::xml::de::from_reader_with_options(reader, options).map_err(Error::from)
+}
+
+/// Deserialize an instance of type [`AndroidManifest`] from a
+/// string of XML text, reporting every problem found instead of stopping at
+/// the first one.
+///
+/// Attributes with invalid values are left unset, and missing required
+/// attributes get their default value. Each of these is returned as a
+/// [`Diagnostic`] with the path of its element; unknown attributes and elements,
+/// and values kept in the `Unknown` variant of an attribute enum, are reported
+/// as warnings, or as errors in strict mode. Only malformed XML fails the whole
+/// parse.
+///
+/// ## Example
+/// ```rust
+/// let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android">
+///   <application>
+///     <activity android:name=".Main" android:launchMode="sometimes" android:exported="maybe" />
+///   </application>
+/// </manifest>"#;
+///
+/// let (manifest, diagnostics) =
+///     android_manifest::from_str_recovering(xml, &Default::default()).unwrap();
+/// assert_eq!(manifest.application.activity[0].name, ".Main");
+/// assert_eq!(manifest.application.activity[0].exported, None);
+/// assert_eq!(diagnostics.len(), 2);
+/// ```
+pub fn from_str_recovering(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<(AndroidManifest, Vec<Diagnostic>)> {
+    from_reader_recovering(s.as_bytes(), options)
+}
+
+/// Deserialize an instance of type [`AndroidManifest`] from an IO
+/// stream of XML text, reporting every problem found instead of stopping at
+/// the first one. See [`from_str_recovering`].
+pub fn from_reader_recovering<R: std::io::Read>(
+    reader: R,
+    options: &ParseOptions,
+) -> Result<(AndroidManifest, Vec<Diagnostic>)> {
+    crate::xml::de::from_reader_recovering(reader, options).map_err(Error::from)
+}
+
+/// Deserialize an instance of type [`AndroidManifest`] from a compiled
+/// binary XML (AXML) document, as stored in APK files.
+///
+/// Typed values are converted to their textual form: resource references
+/// become `@0x7f0c0001` style IDs, booleans `true`/`false` and enum or flag
+/// integers their symbolic names.
+pub fn from_binary(bytes: &[u8]) -> Result<AndroidManifest> {
+    let xml = crate::compiled::axml::decode(bytes).map_err(Error::deserialize)?;
+    from_str(&xml)
+}
+
+/// Deserialize an instance of type [`AndroidManifest`] from an `aapt2`
+/// protobuf `XmlNode`, the format manifests are stored in inside Android App
+/// Bundles.
+pub fn from_proto(bytes: &[u8]) -> Result<AndroidManifest> {
+    let xml = crate::compiled::proto::decode(bytes).map_err(Error::deserialize)?;
+    from_str(&xml)
 }
 
 /// Serialize the given [`AndroidManifest`] structure as a String
@@ -94,6 +225,29 @@ pub fn to_string(manifest: &AndroidManifest) -> Result<String> {
     crate::xml::ser::to_string(manifest).map_err(Error::FailedToSerialize)
 }
 
+/// Serialize the given [`AndroidManifest`] structure as a compiled binary XML
+/// (AXML) document, ready to be packaged into an APK.
+///
+/// Values of `android:` attributes are stored as typed values with their
+/// `android.R.attr` resource IDs, the way `aapt2` compiles them. Resource
+/// references such as `@string/app_name` are resolved through `resources` and
+/// fail to compile if missing.
+pub fn to_binary(manifest: &AndroidManifest, resources: &ResourceIdMap) -> Result<Vec<u8>> {
+    let xml = crate::xml::ser::to_string(manifest).map_err(Error::FailedToSerialize)?;
+    crate::compiled::axml::encode(&xml, resources).map_err(Error::FailedToSerialize)
+}
+
+/// Serialize the given [`AndroidManifest`] structure as an `aapt2` protobuf
+/// `XmlNode`, ready to be stored in an Android App Bundle module.
+///
+/// Every attribute keeps its source text; `android:` attributes also carry
+/// their resource ID and compiled value, with resource references resolved
+/// through `resources`.
+pub fn to_proto(manifest: &AndroidManifest, resources: &ResourceIdMap) -> Result<Vec<u8>> {
+    let xml = crate::xml::ser::to_string(manifest).map_err(Error::FailedToSerialize)?;
+    crate::compiled::proto::encode(&xml, resources).map_err(Error::FailedToSerialize)
+}
+
 /// Serialize the given [`AndroidManifest`] structure as a
 /// pretty-printed String of XML text.
 pub fn to_string_pretty(manifest: &AndroidManifest) -> Result<String> {
@@ -234,9 +388,11 @@ mod tests {
                     intent_filter: vec![IntentFilter {
                         action: vec![Action {
                             name: Some("android.intent.action.MAIN".to_string()),
+                            ..Default::default()
                         }],
                         category: vec![Category {
                             name: Some("android.intent.category.LAUNCHER".to_string()),
+                            ..Default::default()
                         }],
                         ..Default::default()
                     }],
@@ -255,6 +411,7 @@ mod tests {
                         intent_filter: vec![IntentFilter {
                             action: vec![Action {
                                 name: Some("com.google.android.c2dm.intent.RECEIVE".to_string()),
+                                ..Default::default()
                             }],
                             ..Default::default()
                         }],
@@ -266,6 +423,7 @@ mod tests {
                         intent_filter: vec![IntentFilter {
                             action: vec![Action {
                                 name: Some("com.google.android.gms.iid.InstanceID".to_string()),
+                                ..Default::default()
                             }],
                             ..Default::default()
                         }],
@@ -284,9 +442,11 @@ mod tests {
                     intent_filter: vec![IntentFilter {
                         action: vec![Action {
                             name: Some("com.google.android.c2dm.intent.RECEIVE".to_string()),
+                            ..Default::default()
                         }],
                         category: vec![Category {
                             name: Some("org.domokit.sky.shell".to_string()),
+                            ..Default::default()
                         }],
                         ..Default::default()
                     }],
@@ -301,7 +461,7 @@ mod tests {
             }),
             permission: vec![Permission {
                 name: Some("org.domokit.gcm.permission.C2D_MESSAGE".to_string()),
-                protection_level: Some(ProtectionLevel::Signature),
+                protection_level: Some(ProtectionLevelBase::Signature.into()),
                 ..Default::default()
             }],
             uses_permission: vec![
@@ -1,5 +1,9 @@
 use super::application::Application;
+use super::attribute_list::{AttributeList, Comma};
+use super::attribution::Attribution;
 use super::compatible_screens::CompatibleScreens;
+use super::dist;
+use super::extras::Extras;
 use super::instrumentation::Instrumentation;
 use super::permission::Permission;
 use super::permission_group::PermissionGroup;
@@ -10,9 +14,12 @@ use super::supports_gl_texture::SupportsGlTexture;
 use super::supports_screens::SupportsScreens;
 use super::uses_configuration::UsesConfiguration;
 use super::uses_feature::UsesFeature;
+use super::uses_package::UsesPackage;
 use super::uses_permission::UsesPermission;
 use super::uses_permission_sdk_23::UsesPermissionSdk23;
 use super::uses_sdk::UsesSdk;
+use super::uses_split::UsesSplit;
+use crate::VarOrBool;
 use serde::{Deserialize, Serialize};
 
 /// The root element of the `AndroidManifest.xml` file.
@@ -30,6 +37,12 @@ use serde::{Deserialize, Serialize};
 ///           android:versionCode="integer"
 ///           android:versionName="string"
 ///           android:installLocation=["auto" | "internalOnly" | "preferExternal"]
+///           split="string"
+///           configForSplit="string"
+///           android:isFeatureSplit=["true" | "false"]
+///           android:isolatedSplits=["true" | "false"]
+///           android:splitTypes="string"
+///           android:requiredSplitTypes="string"
 ///           tools:ignore="string"
 ///           tools:targetApi="string"
 ///           tools:locale="string"
@@ -44,7 +57,9 @@ use serde::{Deserialize, Serialize};
 /// * [`<application>`]
 ///
 /// ## Can contain
+/// * [`<attribution>`]
 /// * [`<compatible-screens>`]
+/// * [`<dist:module>`]
 /// * [`<instrumentation>`]
 /// * [`<permission>`]
 /// * [`<permission-group>`]
@@ -54,15 +69,19 @@ use serde::{Deserialize, Serialize};
 /// * [`<supports-screens>`]
 /// * [`<uses-configuration>`]
 /// * [`<uses-feature>`]
+/// * [`<uses-package>`]
 /// * [`<uses-permission>`]
 /// * [`<uses-permission-sdk-23>`]
 /// * [`<uses-sdk>`]
+/// * [`<uses-split>`]
 ///
 /// ## Introduced in
 /// API Level 1 for all attributes, unless noted otherwise in the attribute description.
 ///
 /// [`<application>`]: crate::Application
+/// [`<attribution>`]: crate::Attribution
 /// [`<compatible-screens>`]: crate::CompatibleScreens
+/// [`<dist:module>`]: crate::dist::Module
 /// [`<instrumentation>`]: crate::Instrumentation
 /// [`<permission>`]: crate::Permission
 /// [`<permission-group>`]: crate::PermissionGroup
@@ -71,9 +90,11 @@ use serde::{Deserialize, Serialize};
 /// [`<supports-screens>`]: crate::SupportsScreens
 /// [`<uses-configuration>`]: crate::UsesConfiguration
 /// [`<uses-feature>`]: crate::UsesFeature
+/// [`<uses-package>`]: crate::UsesPackage
 /// [`<uses-permission>`]: crate::UsesPermission
 /// [`<uses-permission-sdk-23>`]: crate::UsesPermissionSdk23
 /// [`<uses-sdk>`]: crate::UsesSdk
+/// [`<uses-split>`]: crate::UsesSplit
 /// [`<queries>`]: crate::Queries
 #[derive(
     Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Default, Clone,
@@ -238,6 +259,57 @@ pub struct AndroidManifest {
     /// [`App Install Location`]: https://developer.android.com/guide/topics/data/install-location
     #[xml(attribute = true, prefix = "android", rename = "installLocation")]
     pub install_location: Option<InstallLocation>,
+    /// The name of the split APK this manifest belongs to. Not set in the manifest of
+    /// the base APK.
+    ///
+    /// [`AndroidManifest::validate_splits`] checks a set of split manifests against
+    /// their base.
+    #[xml(attribute = true)]
+    pub split: Option<String>,
+    /// For a configuration split, such as one with the resources for a screen density,
+    /// the name of the feature split it provides configuration for. Empty or unset
+    /// if it belongs to the base APK.
+    #[xml(attribute = true, rename = "configForSplit")]
+    pub config_for_split: Option<String>,
+    /// Whether this split is a feature split, such as a dynamic feature module, rather
+    /// than a configuration split.
+    #[xml(attribute = true, prefix = "android", rename = "isFeatureSplit")]
+    pub is_feature_split: Option<VarOrBool>,
+    /// Whether the splits of the app are loaded in isolated class loaders, so that a
+    /// feature split only sees the code of the splits it depends on through
+    /// [`<uses-split>`]. Set in the manifest of the base APK.
+    ///
+    /// This attribute was added in API level 26.
+    ///
+    /// [`<uses-split>`]: crate::UsesSplit
+    #[xml(attribute = true, prefix = "android", rename = "isolatedSplits")]
+    pub isolated_splits: Option<VarOrBool>,
+    /// A comma-separated list of the split types this split provides, such as
+    /// `"language.base,density.base"`.
+    ///
+    /// This attribute was added in API level 31.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "splitTypes",
+        skip_serializing_if = "check_split_types",
+        default = "default_split_types"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub split_types: AttributeList<Comma, String>,
+    /// A comma-separated list of split types that must be provided by the installed
+    /// splits of the app, in addition to the base APK, for it to be installed.
+    ///
+    /// This attribute was added in API level 31.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "requiredSplitTypes",
+        skip_serializing_if = "check_split_types",
+        default = "default_split_types"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub required_split_types: AttributeList<Comma, String>,
     /// This attribute accepts a comma-separated list of lint issue IDs that you'd like
     /// the tools to ignore on this element or any of its descendants.
     ///
@@ -277,6 +349,15 @@ pub struct AndroidManifest {
     pub uses_configuration: Option<UsesConfiguration>,
     /// List of `<queries>` tags.
     pub queries: Option<Queries>,
+    /// Optional `<dist:module>` tag of an app bundle module.
+    #[xml(
+        prefix = "dist",
+        namespaces = { "dist" = "http://schemas.android.com/apk/distribution" }
+    )]
+    pub module: Option<dist::Module>,
+    /// List of `<attribution>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attribution: Vec<Attribution>,
     /// List of `<instrumentation>` tags.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub instrumentation: Vec<Instrumentation>,
@@ -303,6 +384,10 @@ pub struct AndroidManifest {
     #[xml(rename = "uses-feature")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub uses_feature: Vec<UsesFeature>,
+    /// List of `<uses-package>` tags.
+    #[xml(rename = "uses-package")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uses_package: Vec<UsesPackage>,
     /// List of `<uses-permission>` tags.
     #[xml(rename = "uses-permission")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -311,6 +396,10 @@ pub struct AndroidManifest {
     #[xml(rename = "uses-permission-sdk-23")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub uses_permission_sdk_23: Vec<UsesPermissionSdk23>,
+    /// List of `<uses-split>` tags.
+    #[xml(rename = "uses-split")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uses_split: Vec<UsesSplit>,
     /// This attribute lets you specify whether the build tools should use safe mode
     /// (keep all resources that are explicitly cited and that might be referenced
     /// dynamically) or strict mode (keep only the resources that are explicitly cited
@@ -339,6 +428,20 @@ pub struct AndroidManifest {
     /// Reference: [Tools Attributes - tools:discard](https://developer.android.com/studio/write/tool-attributes#toolsdiscard)
     #[xml(attribute = true, prefix = "tools")]
     pub discard: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+fn default_split_types() -> AttributeList<Comma, String> {
+    AttributeList::default()
+}
+
+impl AndroidManifest {
+    fn check_split_types(&self, value: &AttributeList<Comma, String>) -> bool {
+        value.is_empty()
+    }
 }
 
 /// The default install location for the app.
@@ -367,4 +470,10 @@ pub enum InstallLocation {
     /// through the system settings.
     #[xml(rename = "preferExternal")]
     PreferExternal,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(InstallLocation);
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use super::resources::*;
 use serde::{Deserialize, Serialize};
 
@@ -79,4 +80,8 @@ pub struct MetaData {
     /// following table: <https://developer.android.com/guide/topics/manifest/meta-data-element#val>
     #[xml(attribute = true, prefix = "android")]
     pub value: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
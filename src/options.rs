@@ -0,0 +1,45 @@
+/// Options that control how a manifest is parsed, used by
+/// [`from_str_with_options`](crate::from_str_with_options),
+/// [`from_str_recovering`](crate::from_str_recovering) and their reader
+/// counterparts.
+///
+/// ## Example
+/// ```rust
+/// use android_manifest::ParseOptions;
+///
+/// let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android">
+///   <application>
+///     <activity android:name=".Main" android:exproted="true" />
+///   </application>
+/// </manifest>"#;
+///
+/// assert!(android_manifest::from_str(xml).is_ok());
+///
+/// let options = ParseOptions {
+///     strict: true,
+///     ..Default::default()
+/// };
+/// let error = android_manifest::from_str_with_options(xml, &options).unwrap_err();
+/// assert!(error.to_string().contains("android:exproted"));
+/// ```
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct ParseOptions {
+    /// Reject attributes and elements in the `android`, `tools` or no namespace
+    /// that the model does not recognise, instead of keeping them in the
+    /// `extras` of their parent.
+    ///
+    /// Content in any other namespace is always kept.
+    ///
+    /// Attribute values that an enum doesn't recognise, which would otherwise be
+    /// kept in its `Unknown` variant, are rejected as well.
+    pub strict: bool,
+    /// Keep segments of `|` or `;` separated lists that can't be parsed as the
+    /// list's value type as raw strings, instead of failing on them. Lists of
+    /// attribute enums, such as `android:configChanges`, already keep unknown
+    /// values in their `Unknown` variant.
+    ///
+    /// They are available from
+    /// [`AttributeList::unrecognized`](crate::AttributeList::unrecognized) and
+    /// written back when serializing.
+    pub lenient_lists: bool,
+}
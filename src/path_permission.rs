@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Defines the path and required permissions for a specific subset of data
@@ -11,6 +12,8 @@ use serde::{Deserialize, Serialize};
 /// <path-permission android:path="string"
 ///                  android:pathPrefix="string"
 ///                  android:pathPattern="string"
+///                  android:pathSuffix="string"
+///                  android:pathAdvancedPattern="string"
 ///                  android:permission="string"
 ///                  android:readPermission="string"
 ///                  android:writePermission="string" />
@@ -61,6 +64,21 @@ pub struct PathPermission {
     /// [`PatternMatcher`]: https://developer.android.com/reference/android/os/PatternMatcher
     #[xml(attribute = true, prefix = "android", rename = "pathPattern")]
     pub path_pattern: Option<String>,
+    /// The final part of a URI path for a subset of content provider data. Permission
+    /// can be granted to all data subsets with paths that share this final part.
+    ///
+    /// This attribute was introduced in API Level 31.
+    #[xml(attribute = true, prefix = "android", rename = "pathSuffix")]
+    pub path_suffix: Option<String>,
+    /// A complete path matched with an advanced pattern, which supports character
+    /// classes such as `[a-z]`, `+` and `{min,max}` ranges. See
+    /// [`PATTERN_ADVANCED_GLOB`].
+    ///
+    /// This attribute was introduced in API Level 31.
+    ///
+    /// [`PATTERN_ADVANCED_GLOB`]: https://developer.android.com/reference/android/os/PatternMatcher#PATTERN_ADVANCED_GLOB
+    #[xml(attribute = true, prefix = "android", rename = "pathAdvancedPattern")]
+    pub path_advanced_pattern: Option<String>,
     /// The name of a permission that clients must have in order to read or write the
     /// content provider's data. This attribute is a convenient way of setting a
     /// single permission for both reading and writing. However, the `readPermission`
@@ -74,4 +92,8 @@ pub struct PathPermission {
     /// controlled by the content provider.
     #[xml(attribute = true, prefix = "android", rename = "writePermission")]
     pub write_permission: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
@@ -1,3 +1,6 @@
+use super::attribute_list::{AttributeList, VerticalBar};
+use super::extras::Extras;
+use super::protection_level::ProtectionLevel;
 use super::resources::{
     MipmapOrDrawableResource, Resource, StringResource, StringResourceOrString,
 };
@@ -11,13 +14,16 @@ use serde::{Deserialize, Serialize};
 ///
 /// ## XML Syntax
 /// ```xml
-/// <permission android:description="string resource"
+/// <permission android:backgroundPermission="string"
+///             android:description="string resource"
 ///             android:icon="drawable resource"
+///             android:knownCerts="string array resource | string"
 ///             android:label="string resource"
 ///             android:name="string"
+///             android:permissionFlags=["costsMoney" | "hardRestricted" | ...]
 ///             android:permissionGroup="string"
 ///             android:protectionLevel=["normal" | "dangerous" |
-///                         "signature" | ...] />
+///                         "signature" | ...]["|privileged" | "|appop" | ...] />
 /// ```
 ///
 /// ## Contained in
@@ -33,6 +39,11 @@ use serde::{Deserialize, Serialize};
     Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
 )]
 pub struct Permission {
+    /// The name of a permission that must also be granted for the app to use this
+    /// permission while it is in the background, such as
+    /// `android.permission.ACCESS_BACKGROUND_LOCATION` for the location permissions.
+    #[xml(attribute = true, prefix = "android", rename = "backgroundPermission")]
+    pub background_permission: Option<String>,
     /// A user-readable description of the permission, longer and more informative than
     /// the label. It may be displayed to explain the permission to the user — for
     /// example,  when the user is asked whether to grant the permission to another
@@ -45,6 +56,13 @@ pub struct Permission {
     /// A reference to a drawable resource for an icon that represents the permission.
     #[xml(attribute = true, prefix = "android")]
     pub icon: Option<MipmapOrDrawableResource>,
+    /// The SHA-256 digests of the signing certificates that are trusted by a
+    /// `"knownSigner"` permission, either as a reference to a string array resource or
+    /// as a single digest.
+    ///
+    /// Added in API level 31.
+    #[xml(attribute = true, prefix = "android", rename = "knownCerts")]
+    pub known_certs: Option<String>,
     /// A name for the permission, one that can be displayed to users. As a convenience,
     /// the label can be directly set as a raw string while you're developing the
     /// application. However, when the application is ready to be published, it should
@@ -77,6 +95,18 @@ pub struct Permission {
     /// [`<permission-group>`]: crate::PermissionGroup
     #[xml(attribute = true, prefix = "android", rename = "permissionGroup")]
     pub permission_group: Option<String>,
+    /// Flags that change how the system handles the permission, such as
+    /// `"costsMoney"` or `"hardRestricted"`, separated by `|`. Mostly used by
+    /// permissions that the platform defines.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "permissionFlags",
+        skip_serializing_if = "check_permission_flags",
+        default = "default_permission_flags"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub permission_flags: AttributeList<VerticalBar, PermissionFlags>,
     /// Characterizes the potential risk implied in the permission and indicates the
     /// procedure the system should follow when determining whether or not to grant
     /// the permission to an application requesting it.
@@ -87,51 +117,53 @@ pub struct Permission {
     /// `"signature"` base permission type and the `"privileged"` flag.
     #[xml(attribute = true, prefix = "android", rename = "protectionLevel")]
     pub protection_level: Option<ProtectionLevel>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
-/// The following table shows all base permission types. For a list of flags,
-/// see [`protectionLevel`].
-///
-/// [`protectionLevel`]: https://developer.android.com/reference/android/R.attr#protectionLevel
+fn default_permission_flags() -> AttributeList<VerticalBar, PermissionFlags> {
+    AttributeList::new()
+}
+
+impl Permission {
+    fn check_permission_flags(&self, value: &AttributeList<VerticalBar, PermissionFlags>) -> bool {
+        value.is_empty()
+    }
+}
+
+/// Flags that describe how a permission is handled, in addition to its
+/// [`ProtectionLevel`]. Mostly used by permissions defined by the platform.
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
-#[derive(Default)]
-pub enum ProtectionLevel {
-    /// The default value. A lower-risk permission that gives requesting applications
-    /// access to isolated application-level features, with minimal risk to other
-    /// applications, the system, or the user. The system automatically grants this
-    /// type of permission to a requesting application at installation, without asking
-    /// for the user's explicit approval (though the user always has the option to
-    /// review these permissions before installing).
-    #[xml(rename = "normal")]
-    #[default]
-    Normal,
-    /// A higher-risk permission that would give a requesting application access to
-    /// private user data or control over the device that can negatively impact the
-    /// user. Because this type of permission introduces potential risk, the system
-    /// may not automatically grant it to the requesting application. For example, any
-    /// dangerous permissions requested by an application may be displayed to the user
-    /// and require confirmation before proceeding, or some other approach may
-    /// be taken to avoid the user automatically allowing the use of such
-    /// facilities.
-    #[xml(rename = "dangerous")]
-    Dangerous,
-    /// A permission that the system grants only if the requesting application is signed
-    /// with the same certificate as the application that declared the permission. If
-    /// the certificates match, the system automatically grants the permission without
-    /// notifying the user or asking for the user's explicit approval.
-    #[xml(rename = "signature")]
-    Signature,
-    /// Old synonym for `"signature|privileged"`. Deprecated in API level 23. A permission
-    /// that the system grants only to applications that are in a dedicated folder on
-    /// the Android system image or that are signed with the same certificate as the
-    /// application that declared the permission. Avoid using this option, as the
-    /// signature protection level should be sufficient for most needs and works
-    /// regardless of exactly where apps are installed.
-    /// The "signatureOrSystem" permission is used for certain special
-    /// situations where multiple vendors have applications
-    /// built into a system image and need to share specific features
-    /// explicitly because they are being built together.
-    #[xml(rename = "signatureOrSystem")]
-    SignatureOrSystem,
+pub enum PermissionFlags {
+    /// Granting the permission may cost the user money, which is shown when the user
+    /// is asked to grant it.
+    #[xml(rename = "costsMoney")]
+    CostsMoney,
+    /// The permission is no longer supported and is not granted to anyone.
+    #[xml(rename = "removed")]
+    Removed,
+    /// The permission is hard restricted: it can only be granted to apps that the
+    /// installer has allowlisted.
+    #[xml(rename = "hardRestricted")]
+    HardRestricted,
+    /// The permission is soft restricted: apps that are not allowlisted get a limited
+    /// form of access.
+    #[xml(rename = "softRestricted")]
+    SoftRestricted,
+    /// The restriction state of the permission can't be changed once the app is
+    /// installed.
+    #[xml(rename = "immutablyRestricted")]
+    ImmutablyRestricted,
+    /// The installer's exemption from the restriction of the permission is ignored.
+    #[xml(rename = "installerExemptIgnored")]
+    InstallerExemptIgnored,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(PermissionFlags);
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use super::resources::{
     MipmapOrDrawableResource, Resource, StringResource, StringResourceOrString,
 };
@@ -56,4 +57,8 @@ pub struct PermissionGroup {
     /// [`<permissionGroup>`]: crate::Permission#structfield.permission_group
     #[xml(attribute = true, prefix = "android")]
     pub name: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use super::resources::{MipmapOrDrawableResource, StringResourceOrString};
 use serde::{Deserialize, Serialize};
 
@@ -55,4 +56,8 @@ pub struct PermissionTree {
     /// in its path — for example, `com.example.base` is OK, but `com.example` is not.
     #[xml(attribute = true, prefix = "android")]
     pub name: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 use crate::VarOrBool;
@@ -49,4 +50,8 @@ pub struct Profileable {
     /// [`android:shell`]: https://developer.android.com/guide/topics/manifest/profileable-element#shell
     #[xml(attribute = true, prefix = "android")]
     pub enable: VarOrBool,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
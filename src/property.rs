@@ -0,0 +1,344 @@
+use super::extras::Extras;
+use super::resources::*;
+use crate::xml::{XmlDeserialize, XmlSerialize};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Visitor},
+};
+use std::fmt;
+use std::io::{Read, Write};
+
+/// A name-value pair for a property of the parent component, which can be read by
+/// other apps and the system without starting the component.
+///
+/// Unlike [`<meta-data>`], properties are read with
+/// [`PackageManager.getProperty()`] as a typed [`PackageManager.Property`], so the
+/// system and other apps can read them without starting the app. Libraries such
+/// as Jetpack WindowManager use them to opt in to system behaviours.
+///
+/// As with `<meta-data>`, ordinary values are specified through the [`value`]
+/// attribute, and a resource ID through the [`resource`] attribute.
+///
+/// ## XML Syntax
+/// ```xml
+/// <property android:name="string"
+///           android:resource="resource specification"
+///           android:value="string" />
+/// ```
+///
+/// ## Contained in
+/// * [`<activity>`]
+/// * [`<activity-alias>`]
+/// * [`<application>`]
+/// * [`<service>`]
+/// * [`<receiver>`]
+/// * [`<provider>`]
+///
+/// ## Introduced in
+/// API Level 31
+///
+/// [`<meta-data>`]: crate::MetaData
+/// [`PackageManager.getProperty()`]: https://developer.android.com/reference/android/content/pm/PackageManager#getProperty(java.lang.String,%20java.lang.String)
+/// [`PackageManager.Property`]: https://developer.android.com/reference/android/content/pm/PackageManager.Property
+/// [`value`]: crate::Property#structfield.value
+/// [`resource`]: crate::Property#structfield.resource
+/// [`<activity>`]: crate::Activity
+/// [`<activity-alias>`]: crate::ActivityAlias
+/// [`<application>`]: crate::Application
+/// [`<service>`]: crate::Service
+/// [`<receiver>`]: crate::Receiver
+/// [`<provider>`]: crate::Provider
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct Property {
+    /// `Required`. A unique name for the property. To ensure that the name is unique,
+    /// use a Java-style naming convention — for example,
+    /// `"android.window.PROPERTY_ACTIVITY_EMBEDDING_SPLITS_ENABLED"`.
+    #[xml(attribute = true, prefix = "android")]
+    pub name: String,
+    /// A reference to a resource. The ID of the resource is the value of the property,
+    /// read with [`Property.getResourceId()`].
+    ///
+    /// [`Property.getResourceId()`]: https://developer.android.com/reference/android/content/pm/PackageManager.Property#getResourceId()
+    #[xml(attribute = true, prefix = "android")]
+    pub resource: Option<AnyResource>,
+    /// The value of the property, typed by its format the way the build tools
+    /// compile it.
+    #[xml(attribute = true, prefix = "android")]
+    pub value: Option<PropertyValue>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+impl Property {
+    /// The value of the property if it is a boolean, like [`Property.isBoolean()`].
+    ///
+    /// [`Property.isBoolean()`]: https://developer.android.com/reference/android/content/pm/PackageManager.Property#isBoolean()
+    pub fn bool_value(&self) -> Option<bool> {
+        match self.value.as_ref().map(PropertyValue::typed) {
+            Some(TypedPropertyValue::Boolean(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value of the property if it is an integer, like [`Property.isInteger()`].
+    ///
+    /// [`Property.isInteger()`]: https://developer.android.com/reference/android/content/pm/PackageManager.Property#isInteger()
+    pub fn int_value(&self) -> Option<i32> {
+        match self.value.as_ref().map(PropertyValue::typed) {
+            Some(TypedPropertyValue::Integer(value)) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// The value of a [`Property`]: the text written in the manifest together with
+/// its [`TypedPropertyValue`].
+///
+/// The original text is written back unchanged, so `1.0` stays a float and
+/// `#ff0000` stays a color. Two values are equal if their typed values are.
+///
+/// ## Example
+/// ```rust
+/// use android_manifest::{PropertyValue, TypedPropertyValue};
+///
+/// let color: PropertyValue = "#ff0000".parse().unwrap();
+/// assert_eq!(color.typed(), &TypedPropertyValue::Integer(0xffff0000_u32 as i32));
+/// assert_eq!(color.as_str(), "#ff0000");
+///
+/// let float = PropertyValue::from(TypedPropertyValue::Float(1.0));
+/// assert_eq!(float.as_str(), "1.0");
+/// ```
+#[derive(Debug, Eq, Clone)]
+pub struct PropertyValue {
+    text: String,
+    typed: TypedPropertyValue,
+}
+
+impl PropertyValue {
+    /// The value as it was written in the manifest.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn typed(&self) -> &TypedPropertyValue {
+        &self.typed
+    }
+
+    fn parse(v: &str) -> Result<Self, String> {
+        Ok(Self {
+            text: v.to_string(),
+            typed: TypedPropertyValue::parse(v)?,
+        })
+    }
+}
+
+impl From<TypedPropertyValue> for PropertyValue {
+    /// Writes integers in decimal and floats with a fractional part or exponent,
+    /// so that they are typed the same way when read back.
+    fn from(typed: TypedPropertyValue) -> Self {
+        let text = match &typed {
+            TypedPropertyValue::Boolean(value) => value.to_string(),
+            TypedPropertyValue::Integer(value) => value.to_string(),
+            TypedPropertyValue::Float(value) => format!("{value:?}"),
+            TypedPropertyValue::String(value) | TypedPropertyValue::Reference(value) => {
+                value.clone()
+            }
+        };
+        Self { text, typed }
+    }
+}
+
+impl PartialEq for PropertyValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.typed == other.typed
+    }
+}
+
+impl std::str::FromStr for PropertyValue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for PropertyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl Serialize for PropertyValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.text)
+    }
+}
+
+impl XmlSerialize for PropertyValue {
+    fn serialize<W: Write>(
+        &self,
+        writer: &mut crate::xml::ser::Serializer<W>,
+    ) -> Result<(), String> {
+        writer
+            .write(xml::writer::XmlEvent::characters(&self.text))
+            .map_err(|error| error.to_string())
+    }
+}
+
+struct PropertyValueVisitor;
+
+impl<'de> Visitor<'de> for PropertyValueVisitor {
+    type Value = PropertyValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a boolean, integer, float, string or resource reference")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        PropertyValue::parse(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for PropertyValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_string(PropertyValueVisitor)
+    }
+}
+
+impl XmlDeserialize for PropertyValue {
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
+        loop {
+            match reader.next_event()? {
+                xml::reader::XmlEvent::StartElement { .. } => {}
+                xml::reader::XmlEvent::Characters(ref v) => {
+                    return PropertyValue::parse(v).map_err(Into::into);
+                }
+                _ => {
+                    break;
+                }
+            }
+        }
+        Err("Unable to parse attribute".to_string().into())
+    }
+}
+
+/// The typed value of a [`Property`], matching the types of
+/// [`PackageManager.Property`].
+///
+/// Values are typed like the build tools type `android:value`: `true` and
+/// `false` are booleans, decimal or `0x` hexadecimal numbers and `#` colors are
+/// integers, other numbers are floats, and anything else is a string.
+///
+/// ## Example
+/// ```rust
+/// use android_manifest::{PropertyValue, TypedPropertyValue};
+///
+/// fn typed(text: &str) -> TypedPropertyValue {
+///     text.parse::<PropertyValue>().unwrap().typed().clone()
+/// }
+///
+/// assert_eq!(typed("true"), TypedPropertyValue::Boolean(true));
+/// assert_eq!(typed("-0X10"), TypedPropertyValue::Integer(-16));
+/// assert_eq!(typed("#ff0000"), TypedPropertyValue::Integer(0xffff0000_u32 as i32));
+/// assert_eq!(typed("2.5"), TypedPropertyValue::Float(2.5));
+/// assert_eq!(
+///     typed("@bool/enabled"),
+///     TypedPropertyValue::Reference("@bool/enabled".to_string())
+/// );
+/// assert_eq!(typed("sync"), TypedPropertyValue::String("sync".to_string()));
+/// ```
+///
+/// [`PackageManager.Property`]: https://developer.android.com/reference/android/content/pm/PackageManager.Property
+#[derive(Debug, Clone)]
+pub enum TypedPropertyValue {
+    Boolean(bool),
+    Integer(i32),
+    Float(f32),
+    String(String),
+    /// A reference to a resource or theme attribute that holds the value, as
+    /// written, such as `@bool/enabled`.
+    Reference(String),
+}
+
+impl TypedPropertyValue {
+    fn parse(v: &str) -> Result<Self, String> {
+        if v.is_empty() {
+            return Err("value of attribute is empty".to_string());
+        };
+        if v.starts_with('@') || v.starts_with('?') {
+            return Ok(TypedPropertyValue::Reference(v.to_string()));
+        }
+        match v {
+            "true" | "True" | "TRUE" => return Ok(TypedPropertyValue::Boolean(true)),
+            "false" | "False" | "FALSE" => return Ok(TypedPropertyValue::Boolean(false)),
+            _ => {}
+        }
+        if let Some((_, argb)) = crate::compiled::parse_color(v) {
+            return Ok(TypedPropertyValue::Integer(argb as i32));
+        }
+        if let Some(value) = parse_int(v) {
+            return Ok(TypedPropertyValue::Integer(value));
+        }
+        match v.parse::<f32>() {
+            Ok(value) if value.is_finite() => Ok(TypedPropertyValue::Float(value)),
+            _ => Ok(TypedPropertyValue::String(v.to_string())),
+        }
+    }
+}
+
+/// Parses a decimal or `0x` hexadecimal integer, either of which may be negative.
+/// Hexadecimal values above `i32::MAX` wrap around, as with colors.
+fn parse_int(v: &str) -> Option<i32> {
+    let (negative, digits) = match v.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, v),
+    };
+    let value = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()? as i32,
+        None if digits.starts_with(|c: char| c.is_ascii_digit()) => {
+            return v.parse().ok();
+        }
+        None => return None,
+    };
+    Some(if negative {
+        value.wrapping_neg()
+    } else {
+        value
+    })
+}
+
+impl PartialEq for TypedPropertyValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TypedPropertyValue::Boolean(a), TypedPropertyValue::Boolean(b)) => a == b,
+            (TypedPropertyValue::Integer(a), TypedPropertyValue::Integer(b)) => a == b,
+            // Compared by bits, so that equality stays reflexive.
+            (TypedPropertyValue::Float(a), TypedPropertyValue::Float(b)) => {
+                a.to_bits() == b.to_bits()
+            }
+            (TypedPropertyValue::String(a), TypedPropertyValue::String(b)) => a == b,
+            (TypedPropertyValue::Reference(a), TypedPropertyValue::Reference(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for TypedPropertyValue {}
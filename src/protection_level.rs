@@ -0,0 +1,308 @@
+use crate::xml::{KnownValue, XmlDeserialize, XmlSerialize};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Visitor},
+    ser::Error as _,
+};
+use std::fmt;
+use std::io::{Read, Write};
+
+/// The value of [`android:protectionLevel`]: at most one base permission type and zero
+/// or more flags, written as `"signature|privileged|installer"`.
+///
+/// The base type can appear anywhere in the `|`-separated list, but is always written
+/// first. If no base type is given, as in `"appop"`, `base` is `None` and Android treats
+/// the permission as [`Normal`](ProtectionLevelBase::Normal). A leading segment that is
+/// neither a known base type nor a known flag, when no known base type follows, is kept
+/// as an unknown base type.
+///
+/// ## Example
+/// ```rust
+/// use android_manifest::{ProtectionFlag, ProtectionLevel, ProtectionLevelBase};
+///
+/// let level: ProtectionLevel = serde_plain::from_str("signature|privileged").unwrap();
+/// assert_eq!(level.base, Some(ProtectionLevelBase::Signature));
+/// assert!(level.has_flag(&ProtectionFlag::Privileged));
+/// assert_eq!(
+///     level,
+///     ProtectionLevel::new(ProtectionLevelBase::Signature).with_flag(ProtectionFlag::Privileged)
+/// );
+/// assert_eq!(level.to_string(), "signature|privileged");
+/// ```
+///
+/// [`android:protectionLevel`]: https://developer.android.com/reference/android/R.attr#protectionLevel
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct ProtectionLevel {
+    pub base: Option<ProtectionLevelBase>,
+    pub flags: Vec<ProtectionFlag>,
+}
+
+impl From<ProtectionLevelBase> for ProtectionLevel {
+    fn from(base: ProtectionLevelBase) -> Self {
+        Self::new(base)
+    }
+}
+
+impl ProtectionLevel {
+    pub fn new(base: ProtectionLevelBase) -> Self {
+        Self {
+            base: Some(base),
+            flags: Vec::new(),
+        }
+    }
+
+    /// The base permission type Android applies, which is
+    /// [`Normal`](ProtectionLevelBase::Normal) when none is given.
+    pub fn effective_base(&self) -> ProtectionLevelBase {
+        self.base.clone().unwrap_or_default()
+    }
+
+    pub fn with_flag(mut self, flag: ProtectionFlag) -> Self {
+        self.flags.push(flag);
+        self
+    }
+
+    pub fn has_flag(&self, flag: &ProtectionFlag) -> bool {
+        self.flags.contains(flag)
+    }
+
+    fn segments(&self) -> Result<Vec<String>, serde_plain::Error> {
+        let mut segments = Vec::new();
+        if let Some(base) = &self.base {
+            segments.push(serde_plain::to_string(base)?);
+        }
+        for flag in &self.flags {
+            segments.push(serde_plain::to_string(flag)?);
+        }
+        Ok(segments)
+    }
+
+    fn parse(v: &str) -> Result<Self, String> {
+        if v.is_empty() {
+            return Err("value of attribute is empty".to_string());
+        };
+        let mut base = None;
+        let mut flags = Vec::new();
+        for segment in v.replace(' ', "").split('|') {
+            if segment.is_empty() {
+                return Err(format!("empty segment in protection level {v:?}"));
+            }
+            match serde_plain::from_str::<ProtectionLevelBase>(segment) {
+                Ok(value) if value.is_known() => {
+                    if base.replace(value).is_some() {
+                        return Err(format!(
+                            "protection level {v:?} has more than one base permission type"
+                        ));
+                    }
+                }
+                _ => {
+                    flags.push(
+                        serde_plain::from_str::<ProtectionFlag>(segment)
+                            .map_err(|error| error.to_string())?,
+                    );
+                }
+            }
+        }
+        // The base type is written first, so an unknown leading segment without
+        // a known base type is most likely a base type added after this crate.
+        if base.is_none() {
+            if let Some(ProtectionFlag::Unknown(value)) = flags.first() {
+                base = Some(ProtectionLevelBase::Unknown(value.clone()));
+                flags.remove(0);
+            }
+        }
+        Ok(Self { base, flags })
+    }
+}
+
+impl fmt::Display for ProtectionLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let segments = self.segments().map_err(|_| fmt::Error)?;
+        write!(f, "{}", segments.join("|"))
+    }
+}
+
+impl Serialize for ProtectionLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let segments = self.segments().map_err(S::Error::custom)?;
+        serializer.serialize_str(&segments.join("|"))
+    }
+}
+
+impl XmlSerialize for ProtectionLevel {
+    fn serialize<W: Write>(
+        &self,
+        writer: &mut crate::xml::ser::Serializer<W>,
+    ) -> Result<(), String> {
+        let segments = self.segments().map_err(|error| error.to_string())?;
+        writer
+            .write(xml::writer::XmlEvent::characters(&segments.join("|")))
+            .map_err(|error| error.to_string())
+    }
+}
+
+struct ProtectionLevelVisitor;
+
+impl<'de> Visitor<'de> for ProtectionLevelVisitor {
+    type Value = ProtectionLevel;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a protection level in format 'base' or 'base|flag1|flag2'")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        ProtectionLevel::parse(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtectionLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_string(ProtectionLevelVisitor)
+    }
+}
+
+impl XmlDeserialize for ProtectionLevel {
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
+        loop {
+            match reader.next_event()? {
+                xml::reader::XmlEvent::StartElement { .. } => {}
+                xml::reader::XmlEvent::Characters(ref v) => {
+                    return ProtectionLevel::parse(v).map_err(Into::into);
+                }
+                _ => {
+                    break;
+                }
+            }
+        }
+        Err("Unable to parse attribute".to_string().into())
+    }
+
+    fn unknown_values(&self) -> Vec<&str> {
+        self.base
+            .iter()
+            .filter_map(KnownValue::unknown_value)
+            .chain(self.flags.iter().filter_map(KnownValue::unknown_value))
+            .collect()
+    }
+}
+
+/// The base permission types of a [`ProtectionLevel`].
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ProtectionLevelBase {
+    /// The default value. A lower-risk permission that gives requesting applications
+    /// access to isolated application-level features, with minimal risk to other
+    /// applications, the system, or the user. The system automatically grants this
+    /// type of permission to a requesting application at installation, without asking
+    /// for the user's explicit approval (though the user always has the option to
+    /// review these permissions before installing).
+    #[default]
+    Normal,
+    /// A higher-risk permission that would give a requesting application access to
+    /// private user data or control over the device that can negatively impact the
+    /// user. Because this type of permission introduces potential risk, the system
+    /// may not automatically grant it to the requesting application. For example, any
+    /// dangerous permissions requested by an application may be displayed to the user
+    /// and require confirmation before proceeding, or some other approach may
+    /// be taken to avoid the user automatically allowing the use of such
+    /// facilities.
+    Dangerous,
+    /// A permission that the system grants only if the requesting application is signed
+    /// with the same certificate as the application that declared the permission. If
+    /// the certificates match, the system automatically grants the permission without
+    /// notifying the user or asking for the user's explicit approval.
+    Signature,
+    /// Old synonym for `"signature|privileged"`. Deprecated in API level 23. A permission
+    /// that the system grants only to applications that are in a dedicated folder on
+    /// the Android system image or that are signed with the same certificate as the
+    /// application that declared the permission. Avoid using this option, as the
+    /// signature protection level should be sufficient for most needs and works
+    /// regardless of exactly where apps are installed.
+    /// The "signatureOrSystem" permission is used for certain special
+    /// situations where multiple vendors have applications
+    /// built into a system image and need to share specific features
+    /// explicitly because they are being built together.
+    SignatureOrSystem,
+    /// A permission that is managed internally by the system and only granted
+    /// according to the protection flags.
+    ///
+    /// Added in API level 31.
+    Internal,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(ProtectionLevelBase);
+
+/// The flags that can be combined with a [`ProtectionLevelBase`].
+///
+/// Most of them are only honoured for permissions defined by the platform.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum ProtectionFlag {
+    /// Can also be granted to any applications installed as privileged apps on the
+    /// system image.
+    Privileged,
+    /// Can also be granted to applications that are installed as development tools
+    /// with `adb shell pm grant`.
+    Development,
+    /// Is closely associated with an app op for controlling access.
+    Appop,
+    /// Is automatically granted to apps that target API levels below 23.
+    Pre23,
+    /// Is automatically granted to the system package installer.
+    Installer,
+    /// Is automatically granted to the system package verifier.
+    Verifier,
+    /// Is automatically granted to any application pre-installed on the system image.
+    Preinstalled,
+    /// Is automatically granted to the setup wizard app.
+    Setup,
+    /// Can be granted to instant apps.
+    Instant,
+    /// Can only be granted to apps that target runtime permissions.
+    Runtime,
+    /// Can be granted only if its protection level is signature, the requesting app
+    /// resides on the OEM partition, and the OEM has allowlisted the app.
+    Oem,
+    /// Can be granted to privileged apps in the vendor partition.
+    VendorPrivileged,
+    /// Is automatically granted to the system default text classifier.
+    TextClassifier,
+    /// Is automatically granted to the system configurator.
+    Configurator,
+    /// Is automatically granted to the system incident report approver.
+    IncidentReportApprover,
+    /// Is automatically granted to the system app predictor.
+    AppPredictor,
+    /// Is granted to an APK in an APEX module.
+    Module,
+    /// Is automatically granted to the system companion device manager service.
+    Companion,
+    /// Is automatically granted to the retail demo app.
+    RetailDemo,
+    /// Is automatically granted to the recents app.
+    Recents,
+    /// Is managed by a role.
+    Role,
+    /// Can also be granted if the requesting application is signed by one of the
+    /// certificates listed in [`knownCerts`](crate::Permission::known_certs).
+    KnownSigner,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(ProtectionFlag);
@@ -1,10 +1,12 @@
+use super::extras::Extras;
 use crate::VarOrBool;
 
-use super::attribute_list::{AttributeList, Semicolon};
+use super::attribute_list::{AttributeList, Semicolon, VerticalBar};
 use super::grant_uri_permission::GrantUriPermission;
 use super::intent_filter::IntentFilter;
 use super::meta_data::MetaData;
 use super::path_permission::PathPermission;
+use super::property::Property;
 use super::resources::{MipmapOrDrawableResource, StringResourceOrString};
 use serde::{Deserialize, Serialize};
 
@@ -44,10 +46,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// ## XML Syntax
 /// ```xml
-/// <provider android:authorities="list"
+/// <provider android:attributionTags="string"
+///           android:authorities="list"
 ///           android:directBootAware=["true" | "false"]
 ///           android:enabled=["true" | "false"]
 ///           android:exported=["true" | "false"]
+///           android:forceUriPermissions=["true" | "false"]
 ///           android:grantUriPermissions=["true" | "false"]
 ///           android:icon="drawable resource"
 ///           android:initOrder="integer"
@@ -68,6 +72,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// ## Can contain
 /// * [`<meta-data>`]
+/// * [`<property>`]
 /// * [`<grant-uri-permission>`]
 /// * [`<intent-filter>`]
 /// * [`<path-permission>`]
@@ -80,6 +85,7 @@ use serde::{Deserialize, Serialize};
 /// [`Content Providers`]: https://developer.android.com/guide/topics/providers/content-providers
 /// [`<application>`]: crate::Application
 /// [`<meta-data>`]: crate::MetaData
+/// [`<property>`]: crate::Property
 /// [`<grant-uri-permission>`]: crate::GrantUriPermission
 /// [`<intent-filter>`]: crate::IntentFilter
 /// [`<path-permission>`]: crate::PathPermission
@@ -104,6 +110,22 @@ pub struct Provider {
     )]
     #[serde(skip_serializing_if = "AttributeList::is_empty")]
     pub authorities: AttributeList<Semicolon, String>,
+    /// The [`attribution tags`] that the provider uses when it accesses private data,
+    /// separated by `|`. The tags must be declared with [`<attribution>`] elements.
+    ///
+    /// This attribute was added in API level 31.
+    ///
+    /// [`attribution tags`]: https://developer.android.com/guide/topics/data/audit-access#attribution-tags
+    /// [`<attribution>`]: crate::Attribution
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "attributionTags",
+        skip_serializing_if = "check_attribution_tags",
+        default = "default_attribution_tags"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub attribution_tags: AttributeList<VerticalBar, String>,
     /// Whether or not the service can be instantiated by the system — `"true"` if it can
     /// be, and `"false"` if not. The default value is `"true"`.
     ///
@@ -154,6 +176,15 @@ pub struct Provider {
     /// [`permission`]: crate::Provider#structfield.permission
     #[xml(attribute = true, prefix = "android")]
     pub exported: Option<VarOrBool>,
+    /// Whether the provider always checks the URI permissions of its callers, even
+    /// for URIs that a caller could otherwise access through the provider's own
+    /// permissions.
+    ///
+    /// The default value is "`false`".
+    ///
+    /// This attribute was added in API level 29.
+    #[xml(attribute = true, prefix = "android", rename = "forceUriPermissions")]
+    pub force_uri_permissions: Option<VarOrBool>,
     /// Whether or not those who ordinarily would not have permission to access the
     /// content provider's data can be granted permission to do so, temporarily
     /// overcoming the restriction imposed by the [`readPermission`], [`writePermission`],
@@ -316,7 +347,8 @@ pub struct Provider {
     #[xml(attribute = true, prefix = "android", rename = "writePermission")]
     pub write_permission: Option<String>,
     #[xml(rename = "grant-uri-permission")]
-    pub grant_uri_permission: Option<GrantUriPermission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grant_uri_permission: Vec<GrantUriPermission>,
     #[xml(rename = "path-permission")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub path_permission: Vec<PathPermission>,
@@ -326,6 +358,9 @@ pub struct Provider {
     #[xml(rename = "meta-data")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub meta_data: Vec<MetaData>,
+    /// List of `<property>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property: Vec<Property>,
     /// Specifies which attributes from lower priority manifest files should be replaced
     /// by attributes from this manifest. This is a comma-separated list of attribute
     /// names.
@@ -364,9 +399,21 @@ pub struct Provider {
     /// Reference: [Merge manifest files - tools:strict](https://developer.android.com/studio/build/manage-manifests#attribute_markers)
     #[xml(attribute = true, prefix = "tools")]
     pub strict: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+fn default_attribution_tags() -> AttributeList<VerticalBar, String> {
+    AttributeList::default()
 }
 
 impl Provider {
+    fn check_attribution_tags(&self, value: &AttributeList<VerticalBar, String>) -> bool {
+        value.is_empty()
+    }
+
     pub fn check_authorities(&self, value: &AttributeList<Semicolon, String>) -> bool {
         value.is_empty()
     }
@@ -1,6 +1,8 @@
 use super::action::Action;
 use super::attribute_list::{AttributeList, Semicolon};
+use super::category::Category;
 use super::data::Data;
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Specifies the set of other apps that an app intends to interact with.
@@ -36,15 +38,21 @@ use serde::{Deserialize, Serialize};
 /// [`<manifest>`]: crate::AndroidManifest
 /// [`package visibility filtering`]: https://developer.android.com/training/package-visibility
 /// [`visible automatically`]: https://developer.android.com/training/package-visibility/automatic
-#[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
 pub struct Queries {
-    /// Specifies a single app that your app intends to access. This other app might
-    /// integrate with your app, or your app might use services that the other app
-    /// provides.
-    pub package: Option<Package>,
-    /// Specifies an [`intent filter signature`]. Your app can discover other apps that
+    /// Specifies the apps that your app intends to access, one `<package>` element per
+    /// app. These other apps might integrate with your app, or your app might use
+    /// services that they provide.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub package: Vec<Package>,
+    /// Specifies [`intent filter signatures`]. Your app can discover other apps that
     /// have matching `<intent-filter>` elements.
-    pub intent: Option<Intent>,
+    ///
+    /// [`intent filter signatures`]: https://developer.android.com/training/basics/intents/filters
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub intent: Vec<Intent>,
     /// Specifies one or more [`content provider authorities`]. Your app can discover
     /// other apps whose content providers use the specified authorities.
     ///
@@ -57,6 +65,10 @@ pub struct Queries {
     /// [`content provider authorities`]: https://developer.android.com/guide/topics/providers/content-provider-basics#ContentURIs
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub provider: Vec<QueriesProvider>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 /// Specifies a single app that your app intends to access. This other app might integrate
@@ -66,6 +78,10 @@ pub struct Package {
     /// `Required`. Specifies the package name of the other app.
     #[xml(attribute = true, prefix = "android")]
     pub name: String,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 /// Specifies an [`intent filter signature`]. Your app can discover other apps that have
@@ -77,6 +93,17 @@ pub struct Package {
 /// restrictions in the "intent filter signature" section of the guide to [`declaring
 /// package visibility needs`].
 ///
+/// ## XML Syntax
+/// ```xml
+/// <intent>
+///     <action android:name="string" />
+///     <category android:name="string" />
+///     <data android:scheme="string"
+///           android:mimeType="string"
+///           ... />
+/// </intent>
+/// ```
+///
 /// [`<intent-filter>`]: crate::IntentFilter
 /// [`intent filter signature`]: https://developer.android.com/training/basics/intents/filters
 /// [`declaring package visibility needs`]: https://developer.android.com/training/package-visibility/declaring#intent-filter-signature
@@ -84,9 +111,19 @@ pub struct Package {
     Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
 )]
 pub struct Intent {
-    pub action: Action,
+    /// List of `<action>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub action: Vec<Action>,
+    /// List of `<category>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub category: Vec<Category>,
+    /// List of `<data>` tags.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub data: Vec<Data>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 /// Declares a content provider component used in [`<queries>`].
@@ -182,6 +219,10 @@ pub struct QueriesProvider {
     /// [`<manifest>`]: crate::AndroidManifest
     #[xml(attribute = true, prefix = "android")]
     pub name: String,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 impl QueriesProvider {
@@ -1,7 +1,10 @@
+use super::extras::Extras;
 use crate::VarOrBool;
 
+use super::attribute_list::{AttributeList, VerticalBar};
 use super::intent_filter::IntentFilter;
 use super::meta_data::MetaData;
+use super::property::Property;
 use super::resources::{MipmapOrDrawableResource, StringResourceOrString};
 use serde::{Deserialize, Serialize};
 
@@ -26,7 +29,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// ## XML Syntax
 /// ```xml
-/// <receiver android:directBootAware=["true" | "false"]
+/// <receiver android:attributionTags="string"
+///           android:directBootAware=["true" | "false"]
 ///           android:enabled=["true" | "false"]
 ///           android:exported=["true" | "false"]
 ///           android:icon="drawable resource"
@@ -44,6 +48,7 @@ use serde::{Deserialize, Serialize};
 /// ## Can contain
 /// * [`<intent-filter>`]
 /// * [`<meta-data>`]
+/// * [`<property>`]
 ///
 /// ## Introduced in
 /// API Level 1
@@ -53,10 +58,27 @@ use serde::{Deserialize, Serialize};
 /// [`<application>`]: crate::Application
 /// [`<intent-filter>`]: crate::IntentFilter
 /// [`<meta-data>`]: crate::MetaData
+/// [`<property>`]: crate::Property
 #[derive(
     Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
 )]
 pub struct Receiver {
+    /// The [`attribution tags`] that the receiver uses when it accesses private data,
+    /// separated by `|`. The tags must be declared with [`<attribution>`] elements.
+    ///
+    /// This attribute was added in API level 31.
+    ///
+    /// [`attribution tags`]: https://developer.android.com/guide/topics/data/audit-access#attribution-tags
+    /// [`<attribution>`]: crate::Attribution
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "attributionTags",
+        skip_serializing_if = "check_attribution_tags",
+        default = "default_attribution_tags"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub attribution_tags: AttributeList<VerticalBar, String>,
     /// Whether or not the broadcast `receiver` is direct-boot aware; that is,
     /// whether or not it can run before the user unlocks the device.
     ///
@@ -193,6 +215,9 @@ pub struct Receiver {
     #[serde(rename = "meta-data", skip_serializing_if = "Vec::is_empty", default)]
     #[xml(rename = "meta-data")]
     pub meta_data: Vec<MetaData>,
+    /// List of `<property>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property: Vec<Property>,
     /// Specifies which attributes from lower priority manifest files should be replaced
     /// by attributes from this manifest. This is a comma-separated list of attribute
     /// names.
@@ -231,4 +256,18 @@ pub struct Receiver {
     /// Reference: [Merge manifest files - tools:strict](https://developer.android.com/studio/build/manage-manifests#attribute_markers)
     #[xml(attribute = true, prefix = "tools")]
     pub strict: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+fn default_attribution_tags() -> AttributeList<VerticalBar, String> {
+    AttributeList::default()
+}
+
+impl Receiver {
+    fn check_attribution_tags(&self, value: &AttributeList<VerticalBar, String>) -> bool {
+        value.is_empty()
+    }
 }
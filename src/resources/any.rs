@@ -1,6 +1,6 @@
 use super::{
     DrawableResource, Resource, ResourceType, StringResource, StyleResource, XmlResource,
-    parse_resource,
+    parse_resource, parse_resource_id,
 };
 use crate::xml::{XmlDeserialize, XmlSerialize};
 use serde::{
@@ -11,6 +11,9 @@ use std::fmt;
 use std::io::{Read, Write};
 
 /// Enum used when the value can be any of available resources.
+///
+/// Resources referenced by numeric ID (`@0x7f0c0001`) carry no resource type
+/// and are represented as [`AnyResource::Xml`].
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum AnyResource {
     String(Resource<StringResource>),
@@ -51,6 +54,9 @@ fn parse_any_resource(v: &str) -> Result<AnyResource, String> {
     if v.is_empty() {
         return Err("value of attribute is empty".to_string());
     };
+    if let Some(id) = parse_resource_id(v) {
+        return Ok(AnyResource::Xml(Resource::from_id(id)));
+    }
     let (package, resource_type, name) = parse_resource(v)?;
     let any = if StringResource::resource_type() == resource_type {
         AnyResource::String(Resource::<StringResource>::new_with_package(&name, package))
@@ -95,18 +101,20 @@ impl<'de> Deserialize<'de> for AnyResource {
 }
 
 impl XmlDeserialize for AnyResource {
-    fn deserialize<R: Read>(reader: &mut crate::xml::de::Deserializer<R>) -> Result<Self, String> {
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
         loop {
             match reader.next_event()? {
                 xml::reader::XmlEvent::StartElement { .. } => {}
                 xml::reader::XmlEvent::Characters(ref v) => {
-                    return parse_any_resource(v);
+                    return parse_any_resource(v).map_err(Into::into);
                 }
                 _ => {
                     break;
                 }
             }
         }
-        Err("Unable to parse attribute".to_string())
+        Err("Unable to parse attribute".to_string().into())
     }
 }
@@ -10,7 +10,10 @@ use serde::{
 use std::fmt;
 use std::io::{Read, Write};
 
-/// Enum used when the value can be string resource or just a row string.
+/// Enum used when the value can be mipmap or drawable resource.
+///
+/// Resources referenced by numeric ID (`@0x7f0c0001`) carry no resource type
+/// and are represented as [`MipmapOrDrawableResource::Mipmap`].
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum MipmapOrDrawableResource {
     Mipmap(Resource<MipmapResource>),
@@ -77,7 +80,7 @@ impl<'de> Visitor<'de> for MipmapOrDrawableResourceVisitor {
         if v.is_empty() {
             return Err(E::custom("value of attribute is empty"));
         };
-        if v.starts_with("@mipmap") {
+        if v.starts_with("@mipmap") || v.starts_with("@0x") {
             Ok(MipmapOrDrawableResource::Mipmap(
                 ResourceVisitor::<MipmapResource>::new().visit_str(v)?,
             ))
@@ -101,15 +104,17 @@ impl<'de> Deserialize<'de> for MipmapOrDrawableResource {
 }
 
 impl XmlDeserialize for MipmapOrDrawableResource {
-    fn deserialize<R: Read>(reader: &mut crate::xml::de::Deserializer<R>) -> Result<Self, String> {
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
         loop {
             match reader.next_event()? {
                 xml::reader::XmlEvent::StartElement { .. } => {}
                 xml::reader::XmlEvent::Characters(text_content) => {
                     if text_content.is_empty() {
-                        return Err("value of attribute is empty".to_string());
+                        return Err("value of attribute is empty".to_string().into());
                     };
-                    if text_content.starts_with("@mipmap") {
+                    if text_content.starts_with("@mipmap") || text_content.starts_with("@0x") {
                         return Ok(MipmapOrDrawableResource::Mipmap(parse_resource_with_type(
                             &text_content,
                         )?));
@@ -118,7 +123,7 @@ impl XmlDeserialize for MipmapOrDrawableResource {
                             parse_resource_with_type(&text_content)?,
                         ));
                     } else {
-                        return Err(format!("wrong resource type: {}", text_content));
+                        return Err(format!("wrong resource type: {}", text_content).into());
                     }
                 }
                 _ => {
@@ -126,6 +131,6 @@ impl XmlDeserialize for MipmapOrDrawableResource {
                 }
             }
         }
-        Err("Unable to parse attribute".to_string())
+        Err("Unable to parse attribute".to_string().into())
     }
 }
@@ -27,6 +27,7 @@ pub trait ResourceType: FromStr {
         Resource {
             name: name.to_string(),
             package,
+            id: None,
             phantom: PhantomData,
         }
     }
@@ -35,10 +36,15 @@ pub trait ResourceType: FromStr {
 }
 
 /// Generic resource type.
+///
+/// Compiled manifests replace resource names with numeric resource IDs, so a
+/// resource is either named (`@[package:]resource_type/resource_name`) or an
+/// ID reference (`@0x7f0c0001`).
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Resource<T: ResourceType> {
     name: String,
     package: Option<String>,
+    id: Option<u32>,
     phantom: PhantomData<T>,
 }
 
@@ -47,6 +53,7 @@ impl<T: ResourceType> Resource<T> {
         Self {
             name: name.to_string(),
             package: None,
+            id: None,
             phantom: PhantomData,
         }
     }
@@ -55,14 +62,36 @@ impl<T: ResourceType> Resource<T> {
         Self {
             name: name.to_string(),
             package,
+            id: None,
             phantom: PhantomData,
         }
     }
 
+    /// Creates a reference to the resource with the given numeric ID, as found
+    /// in compiled manifests.
+    pub fn from_id(id: u32) -> Self {
+        Self {
+            name: String::new(),
+            package: None,
+            id: Some(id),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the resource name. Empty for resources referenced by ID.
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    pub fn package(&self) -> Option<&str> {
+        self.package.as_deref()
+    }
+
+    /// Returns the numeric resource ID, if the resource is referenced by ID.
+    pub fn id(&self) -> Option<u32> {
+        self.id
+    }
+
     pub fn resource_type(&self) -> &'static str {
         T::resource_type()
     }
@@ -70,7 +99,9 @@ impl<T: ResourceType> Resource<T> {
 
 impl<T: ResourceType> fmt::Display for Resource<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(package) = &self.package {
+        if let Some(id) = self.id {
+            write!(f, "@0x{:08x}", id)
+        } else if let Some(package) = &self.package {
             write!(f, "@{}:{}/{}", package, T::resource_type(), self.name)
         } else {
             write!(f, "@{}/{}", T::resource_type(), self.name)
@@ -92,13 +123,8 @@ impl<T: ResourceType> XmlSerialize for Resource<T> {
         &self,
         writer: &mut crate::xml::ser::Serializer<W>,
     ) -> Result<(), String> {
-        let value = if let Some(package) = &self.package {
-            format!("@{}:{}/{}", package, T::resource_type(), self.name)
-        } else {
-            format!("@{}/{}", T::resource_type(), self.name)
-        };
         writer
-            .write(xml::writer::XmlEvent::characters(&value))
+            .write(xml::writer::XmlEvent::characters(&self.to_string()))
             .map_err(|error| error.to_string())
     }
 }
@@ -144,19 +170,21 @@ impl<'de, T: ResourceType> Deserialize<'de> for Resource<T> {
 }
 
 impl<T: ResourceType> XmlDeserialize for Resource<T> {
-    fn deserialize<R: Read>(reader: &mut crate::xml::de::Deserializer<R>) -> Result<Self, String> {
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
         loop {
             match reader.next_event()? {
                 xml::reader::XmlEvent::StartElement { .. } => {}
                 xml::reader::XmlEvent::Characters(ref text_content) => {
-                    return parse_resource_with_type(text_content);
+                    return parse_resource_with_type(text_content).map_err(Into::into);
                 }
                 _ => {
                     break;
                 }
             }
         }
-        Err("Unable to parse attribute".to_string())
+        Err("Unable to parse attribute".to_string().into())
     }
 }
 
@@ -189,8 +217,17 @@ fn parse_resource(resource: &str) -> Result<(Option<String>, String, String), St
     ))
 }
 
+/// Parses a resource ID reference in format `@0x7f0c0001`.
+fn parse_resource_id(resource: &str) -> Option<u32> {
+    let hex = resource.strip_prefix("@0x")?;
+    u32::from_str_radix(hex, 16).ok()
+}
+
 /// Parses a resource string into given `Resource<ResourceType>`
 fn parse_resource_with_type<T: ResourceType>(resource: &str) -> Result<Resource<T>, String> {
+    if let Some(id) = parse_resource_id(resource) {
+        return Ok(Resource::from_id(id));
+    }
     let (package, resource_type, resource_name) = parse_resource(resource)?;
     if resource_type != T::resource_type() {
         return Err(format!(
@@ -203,6 +240,7 @@ fn parse_resource_with_type<T: ResourceType>(resource: &str) -> Result<Resource<
     Ok(Resource {
         name: resource_name,
         package,
+        id: None,
         phantom: PhantomData,
     })
 }
@@ -102,13 +102,15 @@ impl<'de> Deserialize<'de> for StringResourceOrString {
 }
 
 impl XmlDeserialize for StringResourceOrString {
-    fn deserialize<R: Read>(reader: &mut crate::xml::de::Deserializer<R>) -> Result<Self, String> {
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
         loop {
             match reader.next_event()? {
                 xml::reader::XmlEvent::StartElement { .. } => {}
                 xml::reader::XmlEvent::Characters(text_content) => {
                     if text_content.is_empty() {
-                        return Err("value of attribute is empty".to_string());
+                        return Err("value of attribute is empty".to_string().into());
                     };
                     if text_content.starts_with('@') {
                         return Ok(StringResourceOrString::StringResource(
@@ -123,6 +125,6 @@ impl XmlDeserialize for StringResourceOrString {
                 }
             }
         }
-        Err("Unable to parse attribute".to_string())
+        Err("Unable to parse attribute".to_string().into())
     }
 }
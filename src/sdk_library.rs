@@ -0,0 +1,38 @@
+use super::extras::Extras;
+use serde::{Deserialize, Serialize};
+
+/// Declares that the package is an SDK for the [`SDK Runtime`].
+///
+/// Applications depend on it with a [`<uses-sdk-library>`] that names the same major
+/// version.
+///
+/// ## XML Syntax
+/// ```xml
+/// <sdk-library android:name="string"
+///              android:versionMajor="integer" />
+/// ```
+///
+/// ## Contained in
+/// * [`<application>`]
+///
+/// ## Introduced in
+/// API Level 33
+///
+/// [`SDK Runtime`]: https://developer.android.com/design-for-safety/privacy-sandbox/sdk-runtime
+/// [`<uses-sdk-library>`]: crate::UsesSdkLibrary
+/// [`<application>`]: crate::Application
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct SdkLibrary {
+    /// The name of the SDK.
+    #[xml(attribute = true, prefix = "android")]
+    pub name: String,
+    /// The major version of the SDK. Required.
+    #[xml(attribute = true, prefix = "android", rename = "versionMajor")]
+    pub version_major: Option<u32>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
@@ -1,7 +1,10 @@
+use super::attribute_list::{AttributeList, VerticalBar};
+use super::extras::Extras;
 use crate::VarOrBool;
 
 use super::intent_filter::IntentFilter;
 use super::meta_data::MetaData;
+use super::property::Property;
 use super::resources::{
     MipmapOrDrawableResource, Resource, StringResource, StringResourceOrString,
 };
@@ -22,19 +25,27 @@ use serde::{Deserialize, Serialize};
 ///
 /// ## XML Syntax
 /// ```xml
-/// <service android:description="string resource"
+/// <service android:allowSharedIsolatedProcess=["true" | "false"]
+///          android:description="string resource"
 ///          android:directBootAware=["true" | "false"]
 ///          android:enabled=["true" | "false"]
 ///          android:exported=["true" | "false"]
+///          android:externalService=["true" | "false"]
 ///          android:foregroundServiceType=["camera" | "connectedDevice" |
-///                                        "dataSync" | "location" | "mediaPlayback" |
-///                                        "mediaProjection" | "microphone" | "phoneCall"]
+///                                        "dataSync" | "health" | "location" |
+///                                        "mediaPlayback" | "mediaProcessing" |
+///                                        "mediaProjection" | "microphone" |
+///                                        "phoneCall" | "remoteMessaging" |
+///                                        "shortService" | "specialUse" |
+///                                        "systemExempted"]
 ///          android:icon="drawable resource"
 ///          android:isolatedProcess=["true" | "false"]
 ///          android:label="string resource"
 ///          android:name="string"
 ///          android:permission="string"
-///          android:process="string" >
+///          android:process="string"
+///          android:stopWithTask=["true" | "false"]
+///          android:useAppZygote=["true" | "false"] >
 ///             ...
 /// </service>
 /// ```
@@ -45,6 +56,7 @@ use serde::{Deserialize, Serialize};
 /// ## Can contain
 /// * [`<intent-filter>`]
 /// * [`<meta-data>`]
+/// * [`<property>`]
 ///
 /// ## introduced in
 /// API Level 1
@@ -55,10 +67,27 @@ use serde::{Deserialize, Serialize};
 /// [`<application>`]: crate::Application
 /// [`<intent-filter>`]: crate::IntentFilter
 /// [`<meta-data>`]: crate::MetaData
+/// [`<property>`]: crate::Property
 #[derive(
     Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
 )]
 pub struct Service {
+    /// Whether the service runs in an isolated process that can be shared with other
+    /// isolated services of the app, which bind to it with
+    /// [`BIND_SHARED_ISOLATED_PROCESS`]. Requires [`isolatedProcess`] to be "`true`".
+    ///
+    /// The default value is "`false`".
+    ///
+    /// This attribute was added in API level 34.
+    ///
+    /// [`BIND_SHARED_ISOLATED_PROCESS`]: https://developer.android.com/reference/android/content/Context#BIND_SHARED_ISOLATED_PROCESS
+    /// [`isolatedProcess`]: crate::Service#structfield.isolated_process
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "allowSharedIsolatedProcess"
+    )]
+    pub allow_shared_isolated_process: Option<VarOrBool>,
     /// A string that describes the service to users. The label should be set as a
     /// reference to a string resource, so that it can be localized like other strings
     /// in the user interface.
@@ -110,17 +139,40 @@ pub struct Service {
     /// [`permission`]: crate::Service#structfield.enabled
     #[xml(attribute = true, prefix = "android")]
     pub exported: Option<VarOrBool>,
+    /// If set to true, this isolated service runs in the process of the app that binds
+    /// to it instead of a process of its own, with [`BIND_EXTERNAL_SERVICE`]. Requires
+    /// [`isolatedProcess`] to be "`true`".
+    ///
+    /// The default value is "`false`".
+    ///
+    /// This attribute was added in API level 24.
+    ///
+    /// [`BIND_EXTERNAL_SERVICE`]: https://developer.android.com/reference/android/content/Context#BIND_EXTERNAL_SERVICE
+    /// [`isolatedProcess`]: crate::Service#structfield.isolated_process
+    #[xml(attribute = true, prefix = "android", rename = "externalService")]
+    pub external_service: Option<VarOrBool>,
     /// Specify that the service is a [`foreground service`] that satisfies a particular
     /// use case. For example, a foreground service type of `"location"` indicates
     /// that an app is getting the device's current location, usually to [`continue a
     /// user-initiated action`] related to device location.
     ///
-    /// You can assign multiple foreground service types to a particular service.
+    /// You can assign multiple foreground service types to a particular service,
+    /// separated by `|`, for example `"camera|microphone"`. Apps targeting Android 14
+    /// (API level 34) must declare at least one type for each foreground service, along
+    /// with the permission each type requires (see
+    /// [`foreground_service_permissions`](Service::foreground_service_permissions)).
     ///
     /// [`foreground service`]: https://developer.android.com/guide/components/services
     /// [`continue a user-initiated action`]: https://developer.android.com/training/location/background#continue-user-initiated-action
-    #[xml(attribute = true, prefix = "android", rename = "foregroundServiceType")]
-    pub foreground_service_type: Option<ForegroundServiceType>,
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "foregroundServiceType",
+        skip_serializing_if = "check_foreground_service_type",
+        default = "default_foreground_service_type"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub foreground_service_type: AttributeList<VerticalBar, ForegroundServiceType>,
     /// An icon representing the service. This attribute must be set as a reference to a
     /// drawable resource containing the image definition. If it is not set, the icon
     /// specified for the application as a whole is used instead (see the
@@ -217,12 +269,34 @@ pub struct Service {
     /// [`process`]: crate::Application#structfield.process
     #[xml(attribute = true, prefix = "android")]
     pub process: Option<String>,
+    /// If set to true, the service is stopped automatically when the user removes a
+    /// task rooted in an activity of the app.
+    ///
+    /// The default value is "`false`".
+    #[xml(attribute = true, prefix = "android", rename = "stopWithTask")]
+    pub stop_with_task: Option<VarOrBool>,
+    /// If set to true, this isolated service is spawned from an app zygote, which
+    /// preloads code and resources with the [`zygotePreloadName`] class of the
+    /// application, instead of from the regular zygote. Requires [`isolatedProcess`] to
+    /// be "`true`".
+    ///
+    /// The default value is "`false`".
+    ///
+    /// This attribute was added in API level 29.
+    ///
+    /// [`zygotePreloadName`]: crate::Application#structfield.zygote_preload_name
+    /// [`isolatedProcess`]: crate::Service#structfield.isolated_process
+    #[xml(attribute = true, prefix = "android", rename = "useAppZygote")]
+    pub use_app_zygote: Option<VarOrBool>,
     #[xml(rename = "intent-filter")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub intent_filter: Vec<IntentFilter>,
     #[xml(rename = "meta-data")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub meta_data: Vec<MetaData>,
+    /// List of `<property>` tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property: Vec<Property>,
     /// Specifies which attributes from lower priority manifest files should be replaced
     /// by attributes from this manifest. This is a comma-separated list of attribute
     /// names.
@@ -263,27 +337,177 @@ pub struct Service {
     /// Reference: [Merge manifest files - tools:strict](https://developer.android.com/studio/build/manage-manifests#attribute_markers)
     #[xml(attribute = true, prefix = "tools")]
     pub strict: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
+fn default_foreground_service_type() -> AttributeList<VerticalBar, ForegroundServiceType> {
+    AttributeList::default()
+}
+
+impl Service {
+    fn check_foreground_service_type(
+        &self,
+        value: &AttributeList<VerticalBar, ForegroundServiceType>,
+    ) -> bool {
+        value.is_empty()
+    }
+
+    /// Lists each declared foreground service type together with the
+    /// `FOREGROUND_SERVICE_*` permission that Android 14 (API level 34) requires
+    /// for it, or `None` if the type needs no specific permission.
+    ///
+    /// Every foreground service additionally requires
+    /// `android.permission.FOREGROUND_SERVICE`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use android_manifest::{ForegroundServiceType, Service};
+    ///
+    /// let service = Service {
+    ///     name: ".RecorderService".to_string(),
+    ///     foreground_service_type: vec![
+    ///         ForegroundServiceType::Camera,
+    ///         ForegroundServiceType::ShortService,
+    ///     ]
+    ///     .into(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     service.foreground_service_permissions(),
+    ///     [
+    ///         (
+    ///             &ForegroundServiceType::Camera,
+    ///             Some("android.permission.FOREGROUND_SERVICE_CAMERA")
+    ///         ),
+    ///         (&ForegroundServiceType::ShortService, None),
+    ///     ]
+    /// );
+    /// ```
+    pub fn foreground_service_permissions(
+        &self,
+    ) -> Vec<(&ForegroundServiceType, Option<&'static str>)> {
+        self.foreground_service_type
+            .vec()
+            .iter()
+            .map(|service_type| (service_type, service_type.required_permission()))
+            .collect()
+    }
+}
+
+/// Use case of a foreground service.
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub enum ForegroundServiceType {
+    /// Continue to access the camera from the background, such as video chat apps
+    /// that allow for multitasking.
     #[xml(rename = "camera")]
     #[default]
     Camera,
+    /// Interactions with external devices that require a Bluetooth, NFC, IR, USB, or
+    /// network connection.
     #[xml(rename = "connectedDevice")]
     ConnectedDevice,
+    /// Data transfer operations, such as uploading or downloading data, backup and
+    /// restore, import or export, fetching or processing local files.
     #[xml(rename = "dataSync")]
     DataSync,
+    /// Long-running use cases to support apps in the fitness category such as
+    /// exercise trackers. Added in API level 34.
+    #[xml(rename = "health")]
+    Health,
+    /// Long-running use cases that require location access, such as navigation and
+    /// location sharing.
     #[xml(rename = "location")]
     Location,
+    /// Continue audio or video playback from the background.
     #[xml(rename = "mediaPlayback")]
     MediaPlayback,
+    /// Time-consuming operations on media assets, like converting media to
+    /// different formats. Added in API level 35.
+    #[xml(rename = "mediaProcessing")]
+    MediaProcessing,
+    /// Project content to a non-primary display or external device using the
+    /// `MediaProjection` APIs.
     #[xml(rename = "mediaProjection")]
     MediaProjection,
+    /// Continue microphone capture from the background, such as voice recorders or
+    /// communication apps.
     #[xml(rename = "microphone")]
     Microphone,
+    /// Continue an ongoing call using the `ConnectionService` APIs.
     #[xml(rename = "phoneCall")]
     PhoneCall,
+    /// Transfer text messages from one device to another to assist with continuity
+    /// of a user's messaging tasks when they switch devices. Added in API level 34.
+    #[xml(rename = "remoteMessaging")]
+    RemoteMessaging,
+    /// Quickly finish critical work that cannot be interrupted or postponed. Such a
+    /// service can't run longer than about three minutes. Added in API level 34.
+    #[xml(rename = "shortService")]
+    ShortService,
+    /// Any valid use case not covered by the other types. Should be accompanied by a
+    /// `PROPERTY_SPECIAL_USE_FGS_SUBTYPE` property describing the use case. Added in
+    /// API level 34.
+    #[xml(rename = "specialUse")]
+    SpecialUse,
+    /// Reserved for system applications and specific system integrations that need
+    /// to continue to use foreground services. Added in API level 34.
+    #[xml(rename = "systemExempted")]
+    SystemExempted,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(ForegroundServiceType);
+
+impl ForegroundServiceType {
+    /// The `FOREGROUND_SERVICE_*` permission that apps targeting Android 14 (API
+    /// level 34) must request to run a foreground service of this type, or `None`
+    /// if there is none or the type is unknown.
+    pub fn required_permission(&self) -> Option<&'static str> {
+        match self {
+            ForegroundServiceType::Camera => Some("android.permission.FOREGROUND_SERVICE_CAMERA"),
+            ForegroundServiceType::ConnectedDevice => {
+                Some("android.permission.FOREGROUND_SERVICE_CONNECTED_DEVICE")
+            }
+            ForegroundServiceType::DataSync => {
+                Some("android.permission.FOREGROUND_SERVICE_DATA_SYNC")
+            }
+            ForegroundServiceType::Health => Some("android.permission.FOREGROUND_SERVICE_HEALTH"),
+            ForegroundServiceType::Location => {
+                Some("android.permission.FOREGROUND_SERVICE_LOCATION")
+            }
+            ForegroundServiceType::MediaPlayback => {
+                Some("android.permission.FOREGROUND_SERVICE_MEDIA_PLAYBACK")
+            }
+            ForegroundServiceType::MediaProcessing => {
+                Some("android.permission.FOREGROUND_SERVICE_MEDIA_PROCESSING")
+            }
+            ForegroundServiceType::MediaProjection => {
+                Some("android.permission.FOREGROUND_SERVICE_MEDIA_PROJECTION")
+            }
+            ForegroundServiceType::Microphone => {
+                Some("android.permission.FOREGROUND_SERVICE_MICROPHONE")
+            }
+            ForegroundServiceType::PhoneCall => {
+                Some("android.permission.FOREGROUND_SERVICE_PHONE_CALL")
+            }
+            ForegroundServiceType::RemoteMessaging => {
+                Some("android.permission.FOREGROUND_SERVICE_REMOTE_MESSAGING")
+            }
+            ForegroundServiceType::SpecialUse => {
+                Some("android.permission.FOREGROUND_SERVICE_SPECIAL_USE")
+            }
+            ForegroundServiceType::SystemExempted => {
+                Some("android.permission.FOREGROUND_SERVICE_SYSTEM_EXEMPTED")
+            }
+            ForegroundServiceType::ShortService | ForegroundServiceType::Unknown(_) => None,
+        }
+    }
 }
@@ -0,0 +1,42 @@
+use super::extras::Extras;
+use serde::{Deserialize, Serialize};
+
+/// Declares that the package is a static shared library.
+///
+/// Applications depend on it with a [`<uses-static-library>`] that names the same
+/// version. Several versions of a static shared library can be installed at once.
+///
+/// ## XML Syntax
+/// ```xml
+/// <static-library android:name="string"
+///                 android:version="integer"
+///                 android:versionMajor="integer" />
+/// ```
+///
+/// ## Contained in
+/// * [`<application>`]
+///
+/// ## Introduced in
+/// API Level 26
+///
+/// [`<uses-static-library>`]: crate::UsesStaticLibrary
+/// [`<application>`]: crate::Application
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct StaticLibrary {
+    /// The name of the library.
+    #[xml(attribute = true, prefix = "android")]
+    pub name: String,
+    /// The version of the library. Required.
+    #[xml(attribute = true, prefix = "android")]
+    pub version: Option<u32>,
+    /// The upper 32 bits of the library's long version, for libraries with a version
+    /// that doesn't fit in 32 bits.
+    #[xml(attribute = true, prefix = "android", rename = "versionMajor")]
+    pub version_major: Option<u32>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
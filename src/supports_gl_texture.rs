@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Declares a single GL texture compression format that the app supports.
@@ -86,6 +87,10 @@ pub struct SupportsGlTexture {
     /// S, and Galaxy Tab; and others.
     #[xml(attribute = true, prefix = "android")]
     pub name: Option<SupportsGlTextureName>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
@@ -119,4 +124,10 @@ pub enum SupportsGlTextureName {
     /// such as Motorola DROID series; Samsung Galaxy S, Nexus S, and Galaxy Tab; and
     /// others.
     GL_IMG_texture_compression_pvrtc,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(SupportsGlTextureName);
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 use crate::VarOrBool;
@@ -260,4 +261,8 @@ pub struct SupportsScreens {
     /// crate::SupportsScreens#structfield.compatible_width_limit_dp
     #[xml(attribute = true, prefix = "android", rename = "largestWidthLimitDp")]
     pub largest_width_limit_dp: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
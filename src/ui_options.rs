@@ -20,4 +20,10 @@ pub enum UiOptions {
     /// across the two bars; they always appear together.
     #[xml(rename = "splitActionBarWhenNarrow")]
     SplitActionBarWhenNarrow,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(UiOptions);
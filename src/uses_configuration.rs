@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 use crate::VarOrBool;
@@ -78,6 +79,10 @@ pub struct UsesConfiguration {
     /// The type of touch screen the application requires, if any at all.
     #[xml(attribute = true, prefix = "android", rename = "reqTouchScreen")]
     pub req_touch_screen: Option<ReqTouchScreen>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
 
 /// The type of keyboard the application requires, if any at all.
@@ -101,8 +106,14 @@ pub enum ReqKeyboardType {
     /// keys.
     #[xml(rename = "twelvekey")]
     Twelvekey,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(ReqKeyboardType);
+
 /// The navigation device required by the application, if any.
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -125,8 +136,14 @@ pub enum ReqNavigation {
     /// The application requires a navigation wheel.
     #[xml(rename = "wheel")]
     Wheel,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+impl_is_known!(ReqNavigation);
+
 /// The type of touch screen the application requires, if any at all.
 #[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -155,4 +172,10 @@ pub enum ReqTouchScreen {
     /// [`<uses-feature>`]: crate::UsesFeature
     #[xml(rename = "finger")]
     Finger,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
 }
+
+impl_is_known!(ReqTouchScreen);
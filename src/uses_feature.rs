@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 use crate::VarOrBool;
@@ -146,4 +147,8 @@ pub struct UsesFeature {
     /// [`OpenGL ES API guide`]: https://developer.android.com/guide/topics/graphics/opengl
     #[xml(attribute = true, prefix = "android", rename = "glEsVersion")]
     pub gl_es_version: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
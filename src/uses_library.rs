@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 use crate::VarOrBool;
@@ -75,4 +76,8 @@ pub struct UsesLibrary {
     /// Introduced in: API Level 7.
     #[xml(attribute = true, prefix = "android")]
     pub required: Option<VarOrBool>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
@@ -1,3 +1,4 @@
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 use crate::VarOrBool;
@@ -66,4 +67,8 @@ pub struct UsesNativeLibrary {
     /// The default is `"true"`.
     #[xml(attribute = true, prefix = "android")]
     pub required: Option<VarOrBool>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
 }
@@ -0,0 +1,49 @@
+use super::cert_digest::CertDigest;
+use super::extras::Extras;
+use serde::{Deserialize, Serialize};
+
+/// Specifies another package that the application depends on.
+///
+/// The system doesn't enforce the dependency. It is meant for installers, which can
+/// install the package first or refuse to install the application without it.
+///
+/// ## XML Syntax
+/// ```xml
+/// <uses-package android:packageType="string"
+///               android:name="string"
+///               android:version="integer"
+///               android:versionMajor="integer"
+///               android:certDigest="string" />
+/// ```
+///
+/// ## Contained in
+/// * [`<manifest>`]
+///
+/// ## Introduced in
+/// API Level 28
+///
+/// [`<manifest>`]: crate::AndroidManifest
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct UsesPackage {
+    /// The kind of package, defined by the installer that reads it.
+    #[xml(attribute = true, prefix = "android", rename = "packageType")]
+    pub package_type: Option<String>,
+    /// The package name.
+    #[xml(attribute = true, prefix = "android")]
+    pub name: String,
+    /// The minimum version code of the package.
+    #[xml(attribute = true, prefix = "android")]
+    pub version: Option<u32>,
+    /// The upper 32 bits of the minimum long version code of the package.
+    #[xml(attribute = true, prefix = "android", rename = "versionMajor")]
+    pub version_major: Option<u32>,
+    /// The SHA-256 digest of the package's signing certificate.
+    #[xml(attribute = true, prefix = "android", rename = "certDigest")]
+    pub cert_digest: Option<CertDigest>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
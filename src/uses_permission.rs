@@ -1,3 +1,5 @@
+use super::attribute_list::{AttributeList, VerticalBar};
+use super::extras::Extras;
 use serde::{Deserialize, Serialize};
 
 /// Specifies a system permission that the user must grant in order for the app
@@ -30,7 +32,10 @@ use serde::{Deserialize, Serialize};
 /// ## XML Syntax
 /// ```xml
 /// <uses-permission android:name="string"
-///          android:maxSdkVersion="integer" />
+///          android:maxSdkVersion="integer"
+///          android:usesPermissionFlags=["neverForLocation"]
+///          android:requiredFeature="string"
+///          android:requiredNotFeature="string" />
 /// ```
 ///
 /// ## Contained in
@@ -84,4 +89,74 @@ pub struct UsesPermission {
     /// [`getExternalFilesDir()`]: https://developer.android.com/reference/android/content/Context#getExternalFilesDir(java.lang.String)
     #[xml(attribute = true, prefix = "android", rename = "maxSdkVersion")]
     pub max_sdk_version: Option<u32>,
+    /// Flags that describe how the app uses the permission.
+    ///
+    /// For example, an app that doesn't derive the physical location of the device
+    /// from Bluetooth scan results can declare `android:usesPermissionFlags=
+    /// "neverForLocation"` on [`BLUETOOTH_SCAN`], so that it doesn't need location
+    /// permissions to scan.
+    ///
+    /// This attribute was added in API level 31.
+    ///
+    /// [`BLUETOOTH_SCAN`]: https://developer.android.com/reference/android/Manifest.permission#BLUETOOTH_SCAN
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "usesPermissionFlags",
+        skip_serializing_if = "check_uses_permission_flags",
+        default = "default_uses_permission_flags"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub uses_permission_flags: AttributeList<VerticalBar, UsesPermissionFlags>,
+    /// The permission is only requested on devices that have this system feature, as
+    /// named by [`<uses-feature>`].
+    ///
+    /// This attribute was added in API level 31.
+    ///
+    /// [`<uses-feature>`]: crate::UsesFeature
+    #[xml(attribute = true, prefix = "android", rename = "requiredFeature")]
+    pub required_feature: Option<String>,
+    /// The permission is only requested on devices that don't have this system
+    /// feature.
+    ///
+    /// This attribute was added in API level 31.
+    #[xml(attribute = true, prefix = "android", rename = "requiredNotFeature")]
+    pub required_not_feature: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+pub(crate) fn default_uses_permission_flags() -> AttributeList<VerticalBar, UsesPermissionFlags> {
+    AttributeList::default()
+}
+
+impl UsesPermission {
+    fn check_uses_permission_flags(
+        &self,
+        value: &AttributeList<VerticalBar, UsesPermissionFlags>,
+    ) -> bool {
+        value.is_empty()
+    }
 }
+
+/// Flags of [`UsesPermission::uses_permission_flags`] and
+/// [`UsesPermissionSdk23::uses_permission_flags`].
+///
+/// [`UsesPermissionSdk23::uses_permission_flags`]: crate::UsesPermissionSdk23::uses_permission_flags
+#[derive(Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum UsesPermissionFlags {
+    /// The app never uses the permission to derive the physical location of the
+    /// device. Only honoured for some permissions, such as `BLUETOOTH_SCAN`, and
+    /// results that could reveal the location may be filtered out.
+    #[xml(rename = "neverForLocation")]
+    NeverForLocation,
+    /// A value this version of the crate does not know about, kept verbatim.
+    #[xml(other = true)]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl_is_known!(UsesPermissionFlags);
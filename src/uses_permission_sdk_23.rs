@@ -1,3 +1,6 @@
+use super::attribute_list::{AttributeList, VerticalBar};
+use super::extras::Extras;
+use super::uses_permission::{UsesPermissionFlags, default_uses_permission_flags};
 use serde::{Deserialize, Serialize};
 
 /// Specifies that an app wants a particular permission.
@@ -25,7 +28,10 @@ use serde::{Deserialize, Serialize};
 /// ## XML Syntax
 /// ```xml
 ///  <uses-permission-sdk-23 android:name="string"
-///                          android:maxSdkVersion="integer" />
+///                          android:maxSdkVersion="integer"
+///                          android:usesPermissionFlags=["neverForLocation"]
+///                          android:requiredFeature="string"
+///                          android:requiredNotFeature="string" />
 /// ```
 ///
 /// ## Contained in
@@ -61,4 +67,41 @@ pub struct UsesPermissionSdk23 {
     /// functionality.
     #[xml(attribute = true, prefix = "android", rename = "maxSdkVersion")]
     pub max_sdk_version: Option<u32>,
+    /// Flags that describe how the app uses the permission, as for
+    /// [`UsesPermission::uses_permission_flags`](crate::UsesPermission::uses_permission_flags).
+    ///
+    /// This attribute was added in API level 31.
+    #[xml(
+        attribute = true,
+        prefix = "android",
+        rename = "usesPermissionFlags",
+        skip_serializing_if = "check_uses_permission_flags",
+        default = "default_uses_permission_flags"
+    )]
+    #[serde(default, skip_serializing_if = "AttributeList::is_empty")]
+    pub uses_permission_flags: AttributeList<VerticalBar, UsesPermissionFlags>,
+    /// The permission is only requested on devices that have this system feature.
+    ///
+    /// This attribute was added in API level 31.
+    #[xml(attribute = true, prefix = "android", rename = "requiredFeature")]
+    pub required_feature: Option<String>,
+    /// The permission is only requested on devices that don't have this system
+    /// feature.
+    ///
+    /// This attribute was added in API level 31.
+    #[xml(attribute = true, prefix = "android", rename = "requiredNotFeature")]
+    pub required_not_feature: Option<String>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+impl UsesPermissionSdk23 {
+    fn check_uses_permission_flags(
+        &self,
+        value: &AttributeList<VerticalBar, UsesPermissionFlags>,
+    ) -> bool {
+        value.is_empty()
+    }
 }
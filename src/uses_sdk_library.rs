@@ -0,0 +1,45 @@
+use super::cert_digest::CertDigest;
+use super::extras::Extras;
+use serde::{Deserialize, Serialize};
+
+/// Specifies an SDK that the application uses from the [`SDK Runtime`].
+///
+/// Runtime-enabled SDKs are installed as separate packages and run in their own
+/// process. The application can't be installed unless an SDK with the same name
+/// and major version, signed with the given certificate, is available.
+///
+/// ## XML Syntax
+/// ```xml
+/// <uses-sdk-library android:name="string"
+///                   android:versionMajor="integer"
+///                   android:certDigest="string" />
+/// ```
+///
+/// ## Contained in
+/// * [`<application>`]
+///
+/// ## Introduced in
+/// API Level 33
+///
+/// [`SDK Runtime`]: https://developer.android.com/design-for-safety/privacy-sandbox/sdk-runtime
+/// [`<application>`]: crate::Application
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct UsesSdkLibrary {
+    /// The package name of the SDK.
+    #[xml(attribute = true, prefix = "android")]
+    pub name: String,
+    /// The major version of the SDK, as declared by its [`<sdk-library>`]. Required.
+    ///
+    /// [`<sdk-library>`]: crate::SdkLibrary
+    #[xml(attribute = true, prefix = "android", rename = "versionMajor")]
+    pub version_major: Option<u32>,
+    /// The SHA-256 digest of the SDK's signing certificate. Required.
+    #[xml(attribute = true, prefix = "android", rename = "certDigest")]
+    pub cert_digest: Option<CertDigest>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
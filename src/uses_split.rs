@@ -0,0 +1,33 @@
+use super::extras::Extras;
+use serde::{Deserialize, Serialize};
+
+/// Declares that a feature split depends on another feature split.
+///
+/// When the app uses [`isolated splits`], the code and resources of the split it
+/// names are loaded together with the ones of the split that declares it.
+///
+/// ## XML Syntax
+/// ```xml
+/// <uses-split android:name="string" />
+/// ```
+///
+/// ## Contained in
+/// * [`<manifest>`]
+///
+/// ## Introduced in
+/// API Level 26
+///
+/// [`isolated splits`]: crate::AndroidManifest#structfield.isolated_splits
+/// [`<manifest>`]: crate::AndroidManifest
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct UsesSplit {
+    /// The name of the feature split, as set by its `split` attribute.
+    #[xml(attribute = true, prefix = "android")]
+    pub name: String,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
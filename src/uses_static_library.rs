@@ -0,0 +1,101 @@
+use super::cert_digest::CertDigest;
+use super::extras::Extras;
+use serde::{Deserialize, Serialize};
+
+/// Specifies a static shared library that the application depends on.
+///
+/// Unlike a [`<uses-library>`], a static shared library is a separate package that is
+/// identified by its name, version and signing certificate, so the system can keep
+/// several versions of it installed at once. The application can't be installed
+/// unless a library with the same name and version, signed with a certificate whose
+/// digest is listed here, is installed first.
+///
+/// ## XML Syntax
+/// ```xml
+/// <uses-static-library android:name="string"
+///                      android:version="integer"
+///                      android:certDigest="string" >
+///     <additional-certificate android:certDigest="string" />
+///     ...
+/// </uses-static-library>
+/// ```
+///
+/// ## Contained in
+/// * [`<application>`]
+///
+/// ## Can contain
+/// * [`<additional-certificate>`]
+///
+/// ## Introduced in
+/// API Level 26
+///
+/// [`<uses-library>`]: crate::UsesLibrary
+/// [`<application>`]: crate::Application
+/// [`<additional-certificate>`]: crate::AdditionalCertificate
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct UsesStaticLibrary {
+    /// The package name of the library.
+    #[xml(attribute = true, prefix = "android")]
+    pub name: String,
+    /// The version of the library, as declared by its [`<static-library>`]. Required.
+    ///
+    /// [`<static-library>`]: crate::StaticLibrary
+    #[xml(attribute = true, prefix = "android")]
+    pub version: Option<u32>,
+    /// The SHA-256 digest of the library's signing certificate. Required.
+    #[xml(attribute = true, prefix = "android", rename = "certDigest")]
+    pub cert_digest: Option<CertDigest>,
+    /// List of `<additional-certificate>` tags.
+    #[xml(rename = "additional-certificate")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_certificate: Vec<AdditionalCertificate>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
+
+impl UsesStaticLibrary {
+    /// All the certificate digests the library may be signed with: `android:certDigest`
+    /// followed by those of the `<additional-certificate>` tags.
+    pub fn cert_digests(&self) -> Vec<&CertDigest> {
+        self.cert_digest
+            .iter()
+            .chain(
+                self.additional_certificate
+                    .iter()
+                    .filter_map(|certificate| certificate.cert_digest.as_ref()),
+            )
+            .collect()
+    }
+}
+
+/// Another certificate a [`<uses-static-library>`] may be signed with, for libraries
+/// signed with more than one certificate.
+///
+/// ## XML Syntax
+/// ```xml
+/// <additional-certificate android:certDigest="string" />
+/// ```
+///
+/// ## Contained in
+/// * [`<uses-static-library>`]
+///
+/// ## Introduced in
+/// API Level 27
+///
+/// [`<uses-static-library>`]: crate::UsesStaticLibrary
+#[derive(
+    Debug, Deserialize, Serialize, XmlSerialize, XmlDeserialize, PartialEq, Eq, Default, Clone,
+)]
+pub struct AdditionalCertificate {
+    /// The SHA-256 digest of the certificate. Required.
+    #[xml(attribute = true, prefix = "android", rename = "certDigest")]
+    pub cert_digest: Option<CertDigest>,
+    /// Attributes and child elements that this crate does not model.
+    #[xml(extras = true)]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    pub extras: Extras,
+}
@@ -0,0 +1,295 @@
+use crate::AndroidManifest;
+use crate::error::{DeserializeError, Diagnostic, ErrorKind, Severity};
+use std::collections::HashSet;
+
+/// The longest attribution tag the platform accepts.
+const MAX_ATTRIBUTION_TAG_LENGTH: usize = 50;
+
+impl AndroidManifest {
+    /// Checks the manifest for problems that parsing alone doesn't catch, such as
+    /// values that refer to something the manifest doesn't declare. Returns an empty
+    /// list if none are found.
+    ///
+    /// ## Example
+    /// ```rust
+    /// let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android">
+    ///   <attribution android:tag="sharing" android:label="@string/sharing" />
+    ///   <application>
+    ///     <receiver android:name=".Share" android:attributionTags="sharing|contacts" />
+    ///   </application>
+    /// </manifest>"#;
+    ///
+    /// let manifest = android_manifest::from_str(xml).unwrap();
+    /// let diagnostics = manifest.validate();
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].error.value.as_deref(), Some("contacts"));
+    /// ```
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        check_attribution_tags(self, &mut diagnostics);
+        check_dist_module(self, &mut diagnostics);
+        diagnostics
+    }
+
+    /// Checks the manifests of an app's split APKs against this manifest of its base
+    /// APK. Every split needs a unique `split` name and the package of the base, and
+    /// `<uses-split>` and `configForSplit` must name one of the splits.
+    ///
+    /// The path of a problem in `splits[index]` starts with `splits[index]/manifest`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// let base = android_manifest::from_str(
+    ///     r#"<manifest package="com.example"><application /></manifest>"#,
+    /// )
+    /// .unwrap();
+    /// let camera = android_manifest::from_str(
+    ///     r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android"
+    ///                  package="com.example" split="camera" android:isFeatureSplit="true">
+    ///       <uses-split android:name="media" />
+    ///       <application />
+    ///     </manifest>"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let diagnostics = base.validate_splits(&[camera]);
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(
+    ///     diagnostics[0].error.path.as_deref(),
+    ///     Some("splits[0]/manifest/uses-split[0]")
+    /// );
+    /// ```
+    pub fn validate_splits(&self, splits: &[AndroidManifest]) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        check_splits(self, splits, &mut diagnostics);
+        diagnostics
+    }
+}
+
+fn problem(
+    kind: ErrorKind,
+    path: String,
+    attribute: &str,
+    value: Option<&str>,
+    message: &str,
+) -> Diagnostic {
+    let mut error = DeserializeError::new(kind, message);
+    error.path = Some(path);
+    error.attribute = Some(attribute.to_string());
+    error.value = value.map(str::to_string);
+    Diagnostic {
+        severity: Severity::Error,
+        error,
+    }
+}
+
+/// Every attribution has a label and a tag that is declared once, and components only
+/// use declared tags.
+fn check_attribution_tags(manifest: &AndroidManifest, diagnostics: &mut Vec<Diagnostic>) {
+    let mut declared = HashSet::new();
+    for (index, attribution) in manifest.attribution.iter().enumerate() {
+        let path = format!("manifest/attribution[{index}]");
+        if attribution.label.is_none() {
+            diagnostics.push(problem(
+                ErrorKind::MissingField,
+                path.clone(),
+                "android:label",
+                None,
+                "attribution has no label",
+            ));
+        }
+        if attribution.tag.chars().count() > MAX_ATTRIBUTION_TAG_LENGTH {
+            diagnostics.push(problem(
+                ErrorKind::InvalidValue,
+                path.clone(),
+                "android:tag",
+                Some(&attribution.tag),
+                "attribution tag is longer than 50 characters",
+            ));
+        }
+        if !declared.insert(attribution.tag.as_str()) {
+            diagnostics.push(problem(
+                ErrorKind::Inconsistent,
+                path,
+                "android:tag",
+                Some(&attribution.tag),
+                "attribution tag is declared more than once",
+            ));
+        }
+    }
+
+    let application = &manifest.application;
+    let activities = application
+        .activity
+        .iter()
+        .enumerate()
+        .map(|(index, activity)| {
+            (
+                format!("manifest/application/activity[{index}]"),
+                &activity.attribution_tags,
+            )
+        });
+    let receivers = application
+        .receiver
+        .iter()
+        .enumerate()
+        .map(|(index, receiver)| {
+            (
+                format!("manifest/application/receiver[{index}]"),
+                &receiver.attribution_tags,
+            )
+        });
+    let providers = application
+        .provider
+        .iter()
+        .enumerate()
+        .map(|(index, provider)| {
+            (
+                format!("manifest/application/provider[{index}]"),
+                &provider.attribution_tags,
+            )
+        });
+    for (path, tags) in activities.chain(receivers).chain(providers) {
+        for tag in tags.vec() {
+            if !declared.contains(tag.as_str()) {
+                diagnostics.push(problem(
+                    ErrorKind::Inconsistent,
+                    path.clone(),
+                    "android:attributionTags",
+                    Some(tag),
+                    "attribution tag is not declared by an <attribution> element",
+                ));
+            }
+        }
+    }
+}
+
+/// The `<dist:module>` of an app bundle module has a title if it isn't always
+/// installed, and its install-time conditions can be met.
+fn check_dist_module(manifest: &AndroidManifest, diagnostics: &mut Vec<Diagnostic>) {
+    let Some(module) = &manifest.module else {
+        return;
+    };
+    if module.title.is_none()
+        && module.module_type.as_deref() != Some("asset-pack")
+        && module.is_conditional_or_on_demand()
+    {
+        diagnostics.push(problem(
+            ErrorKind::MissingField,
+            "manifest/module".to_string(),
+            "dist:title",
+            None,
+            "module that isn't always installed has no title",
+        ));
+    }
+
+    let Some(conditions) = module
+        .delivery
+        .as_ref()
+        .and_then(|delivery| delivery.install_time.as_ref())
+        .and_then(|install_time| install_time.conditions.as_ref())
+    else {
+        return;
+    };
+    let path = "manifest/module/delivery/install-time/conditions";
+    if let (Some(min_sdk), Some(max_sdk)) = (&conditions.min_sdk, &conditions.max_sdk) {
+        if min_sdk.value > max_sdk.value {
+            diagnostics.push(problem(
+                ErrorKind::InvalidValue,
+                format!("{path}/max-sdk"),
+                "dist:value",
+                Some(&max_sdk.value.to_string()),
+                "max-sdk condition is lower than the min-sdk condition",
+            ));
+        }
+    }
+    if let Some(user_countries) = &conditions.user_countries {
+        for (index, country) in user_countries.country.iter().enumerate() {
+            if country.code.len() != 2 || !country.code.chars().all(|c| c.is_ascii_alphabetic()) {
+                diagnostics.push(problem(
+                    ErrorKind::InvalidValue,
+                    format!("{path}/user-countries/country[{index}]"),
+                    "dist:code",
+                    Some(&country.code),
+                    "country code is not a two-letter ISO 3166-1 code",
+                ));
+            }
+        }
+    }
+}
+
+/// Split names are unique, and everything that names a split names an existing one.
+fn check_splits(
+    base: &AndroidManifest,
+    splits: &[AndroidManifest],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if let Some(name) = &base.split {
+        diagnostics.push(problem(
+            ErrorKind::Inconsistent,
+            "manifest".to_string(),
+            "split",
+            Some(name),
+            "the base manifest has a split name",
+        ));
+    }
+
+    let mut names = HashSet::new();
+    for (index, split) in splits.iter().enumerate() {
+        let path = format!("splits[{index}]/manifest");
+        match split.split.as_deref() {
+            None | Some("") => diagnostics.push(problem(
+                ErrorKind::MissingField,
+                path,
+                "split",
+                None,
+                "split manifest has no split name",
+            )),
+            Some(name) if !names.insert(name) => diagnostics.push(problem(
+                ErrorKind::Inconsistent,
+                path,
+                "split",
+                Some(name),
+                "split name is used by more than one split",
+            )),
+            Some(_) => {}
+        }
+    }
+
+    for (index, split) in splits.iter().enumerate() {
+        let path = format!("splits[{index}]/manifest");
+        if let (Some(package), Some(base_package)) = (&split.package, &base.package) {
+            if package != base_package {
+                diagnostics.push(problem(
+                    ErrorKind::Inconsistent,
+                    path.clone(),
+                    "package",
+                    Some(package),
+                    "split package differs from the package of the base manifest",
+                ));
+            }
+        }
+        if let Some(name) = split.config_for_split.as_deref() {
+            if !name.is_empty() && !names.contains(name) {
+                diagnostics.push(problem(
+                    ErrorKind::Inconsistent,
+                    path.clone(),
+                    "configForSplit",
+                    Some(name),
+                    "configuration split is for a split that doesn't exist",
+                ));
+            }
+        }
+        for (uses_index, uses_split) in split.uses_split.iter().enumerate() {
+            if !names.contains(uses_split.name.as_str()) {
+                diagnostics.push(problem(
+                    ErrorKind::Inconsistent,
+                    format!("{path}/uses-split[{uses_index}]"),
+                    "android:name",
+                    Some(&uses_split.name),
+                    "uses-split refers to a split that doesn't exist",
+                ));
+            }
+        }
+    }
+}
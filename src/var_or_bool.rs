@@ -116,13 +116,15 @@ impl<'de> Deserialize<'de> for VarOrBool {
 }
 
 impl XmlDeserialize for VarOrBool {
-    fn deserialize<R: Read>(reader: &mut crate::xml::de::Deserializer<R>) -> Result<Self, String> {
+    fn deserialize<R: Read>(
+        reader: &mut crate::xml::de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError> {
         loop {
             match reader.next_event()? {
                 xml::reader::XmlEvent::StartElement { .. } => {}
                 xml::reader::XmlEvent::Characters(text_content) => {
                     if text_content.is_empty() {
-                        return Err("value of attribute is empty".to_string());
+                        return Err("value of attribute is empty".to_string().into());
                     };
                     if text_content.starts_with("${") && text_content.ends_with('}') {
                         return Ok(VarOrBool::Var(text_content));
@@ -137,6 +139,6 @@ impl XmlDeserialize for VarOrBool {
                 }
             }
         }
-        Err("Unable to parse attribute".to_string())
+        Err("Unable to parse attribute".to_string().into())
     }
 }
@@ -1,22 +1,83 @@
 //! Internal Android manifest XML deserialization.
 
 use super::XmlDeserialize;
+use crate::ParseOptions;
+use crate::compiled::ANDROID_NAMESPACE;
+use crate::error::{DeserializeError, Diagnostic, ErrorKind, Position, Severity};
+use std::collections::HashMap;
 use std::io::Read;
+use xml::attribute::OwnedAttribute;
+use xml::common::{Position as _, TextPosition};
 use xml::name::OwnedName;
 use xml::reader::{EventReader, ParserConfig, XmlEvent};
 
-pub fn from_str<T: XmlDeserialize>(s: &str) -> Result<T, String> {
+pub fn from_str<T: XmlDeserialize>(s: &str) -> Result<T, DeserializeError> {
     from_reader(s.as_bytes())
 }
 
-pub fn from_reader<R: Read, T: XmlDeserialize>(reader: R) -> Result<T, String> {
-    <T as XmlDeserialize>::deserialize(&mut Deserializer::new_from_reader(reader))
+pub fn from_str_with_options<T: XmlDeserialize>(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<T, DeserializeError> {
+    from_reader_with_options(s.as_bytes(), options)
+}
+
+pub fn from_reader<R: Read, T: XmlDeserialize>(reader: R) -> Result<T, DeserializeError> {
+    from_reader_with_options(reader, &ParseOptions::default())
+}
+
+pub fn from_reader_with_options<R: Read, T: XmlDeserialize>(
+    reader: R,
+    options: &ParseOptions,
+) -> Result<T, DeserializeError> {
+    let mut deserializer = Deserializer::new_from_reader(reader);
+    deserializer.options = options.clone();
+    <T as XmlDeserialize>::deserialize(&mut deserializer)
+        .map_err(|error| deserializer.locate(error))
+}
+
+/// Deserializes a value, collecting field-level problems instead of failing on
+/// the first one. Only malformed XML still fails the whole parse.
+pub fn from_reader_recovering<R: Read, T: XmlDeserialize>(
+    reader: R,
+    options: &ParseOptions,
+) -> Result<(T, Vec<Diagnostic>), DeserializeError> {
+    let mut deserializer = Deserializer::new_from_reader(reader);
+    deserializer.options = options.clone();
+    deserializer.diagnostics = Some(Vec::new());
+    let value = <T as XmlDeserialize>::deserialize(&mut deserializer)
+        .map_err(|error| deserializer.locate(error))?;
+    Ok((value, deserializer.diagnostics.unwrap_or_default()))
+}
+
+/// `tools` namespace URI.
+const TOOLS_NAMESPACE: &str = "http://schemas.android.com/tools";
+
+/// An element the deserializer is currently inside of.
+struct PathSegment {
+    name: String,
+    index: usize,
+    repeated: bool,
+    /// Number of child elements seen so far, by name.
+    children: HashMap<String, usize>,
+    /// Name and index of the last child element seen so far.
+    last_child: Option<(String, usize)>,
+    /// Name and index of the element's previous sibling.
+    previous: Option<(String, usize)>,
 }
 
 pub struct Deserializer<R: Read> {
     depth: usize,
     reader: EventReader<R>,
-    peeked: Option<XmlEvent>,
+    peeked: Option<(XmlEvent, TextPosition)>,
+    /// Start of the last consumed event.
+    position: TextPosition,
+    path: Vec<PathSegment>,
+    /// Whether the next element to start is an item of a list field.
+    next_repeated: bool,
+    options: ParseOptions,
+    /// Problems collected so far when recovering from field-level errors.
+    diagnostics: Option<Vec<Diagnostic>>,
 }
 
 impl<R: Read> Deserializer<R> {
@@ -25,6 +86,11 @@ impl<R: Read> Deserializer<R> {
             depth: 0,
             reader,
             peeked: None,
+            position: TextPosition::new(),
+            path: Vec::new(),
+            next_repeated: false,
+            options: ParseOptions::default(),
+            diagnostics: None,
         }
     }
 
@@ -39,19 +105,19 @@ impl<R: Read> Deserializer<R> {
         Self::new(EventReader::new_with_config(reader, config))
     }
 
-    pub fn peek(&mut self) -> Result<&XmlEvent, String> {
+    pub fn peek(&mut self) -> Result<&XmlEvent, DeserializeError> {
         if self.peeked.is_none() {
             self.peeked = Some(self.inner_next()?);
         }
 
-        if let Some(ref next) = self.peeked {
+        if let Some((ref next, _)) = self.peeked {
             Ok(next)
         } else {
-            Err("unable to peek next item".into())
+            Err("unable to peek next item".to_string().into())
         }
     }
 
-    pub fn inner_next(&mut self) -> Result<XmlEvent, String> {
+    fn inner_next(&mut self) -> Result<(XmlEvent, TextPosition), DeserializeError> {
         loop {
             match self.reader.next() {
                 Ok(next) => {
@@ -59,31 +125,36 @@ impl<R: Read> Deserializer<R> {
                         XmlEvent::StartDocument { .. }
                         | XmlEvent::ProcessingInstruction { .. }
                         | XmlEvent::Comment(_) => { /* skip */ }
-                        other => return Ok(other),
+                        other => return Ok((other, self.reader.position())),
                     }
                 }
-                Err(msg) => {
-                    return Err(msg.msg().to_string());
+                Err(error) => {
+                    let mut error_value = self.error(ErrorKind::Syntax, error.msg());
+                    error_value.position = Some(to_position(error.position()));
+                    return Err(error_value);
                 }
             }
         }
     }
 
-    pub fn next_event(&mut self) -> Result<XmlEvent, String> {
-        let next_event = if let Some(peeked) = self.peeked.take() {
+    pub fn next_event(&mut self) -> Result<XmlEvent, DeserializeError> {
+        let (next_event, position) = if let Some(peeked) = self.peeked.take() {
             peeked
         } else {
             self.inner_next()?
         };
+        self.position = position;
         match next_event {
-            XmlEvent::StartElement { .. } => {
+            XmlEvent::StartElement { ref name, .. } => {
                 self.depth += 1;
+                self.enter(&name.local_name);
             }
             XmlEvent::EndElement { .. } => {
                 self.depth = self
                     .depth
                     .checked_sub(1)
-                    .ok_or_else(|| "unexpected closing element".to_string())?;
+                    .ok_or_else(|| self.error(ErrorKind::Syntax, "unexpected closing element"))?;
+                self.path.pop();
             }
             _ => {}
         }
@@ -91,7 +162,179 @@ impl<R: Read> Deserializer<R> {
         Ok(next_event)
     }
 
-    pub fn skip_element(&mut self, mut cb: impl FnMut(&XmlEvent)) -> Result<(), String> {
+    fn enter(&mut self, name: &str) {
+        let (index, previous) = self.path.last_mut().map_or((0, None), |parent| {
+            let count = parent.children.entry(name.to_string()).or_default();
+            *count += 1;
+            let previous = parent.last_child.replace((name.to_string(), *count - 1));
+            (*count - 1, previous)
+        });
+        self.path.push(PathSegment {
+            name: name.to_string(),
+            index,
+            repeated: std::mem::take(&mut self.next_repeated),
+            children: HashMap::new(),
+            last_child: None,
+            previous,
+        });
+    }
+
+    /// Name and index among its namesakes of the sibling element right before the
+    /// current one, if any.
+    pub fn previous_sibling(&self) -> Option<(String, usize)> {
+        self.path
+            .last()
+            .and_then(|segment| segment.previous.clone())
+    }
+
+    /// Marks the next element to start as an item of a list, so that its index
+    /// shows up in error paths.
+    pub fn set_next_repeated(&mut self) {
+        self.next_repeated = true;
+    }
+
+    /// Path of the current element, for example
+    /// `manifest/application/activity[2]/intent-filter[0]`.
+    pub fn path(&self) -> String {
+        self.path
+            .iter()
+            .map(|segment| {
+                if segment.repeated {
+                    format!("{}[{}]", segment.name, segment.index)
+                } else {
+                    segment.name.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Creates an error located at the current element.
+    pub fn error(&self, kind: ErrorKind, message: impl Into<String>) -> DeserializeError {
+        self.locate(DeserializeError::new(kind, message))
+    }
+
+    /// Creates an error for an attribute of the current element whose value can't
+    /// be parsed.
+    #[allow(dead_code)] // Used by generated code for models with attributes.
+    pub fn attribute_error(
+        &self,
+        name: &OwnedName,
+        value: &str,
+        message: impl Into<String>,
+    ) -> DeserializeError {
+        let mut error = self.error(ErrorKind::InvalidValue, message);
+        error.attribute = Some(name.borrow().to_repr());
+        error.value = Some(value.to_string());
+        error
+    }
+
+    /// Creates an error for a required field that is missing from the current
+    /// element.
+    pub fn missing_field(&self, message: &str, attribute: Option<&str>) -> DeserializeError {
+        let mut error = self.error(ErrorKind::MissingField, message);
+        error.attribute = attribute.map(str::to_string);
+        error
+    }
+
+    /// Options the document is parsed with, passed on to the visitors of
+    /// attribute values.
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// Records a field-level error when recovering, or returns it otherwise.
+    pub fn recover(&mut self, error: DeserializeError) -> Result<(), DeserializeError> {
+        let error = self.locate(error);
+        match self.diagnostics {
+            Some(ref mut diagnostics) => {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Error,
+                    error,
+                });
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    /// Records a warning when recovering.
+    fn warn(&mut self, error: DeserializeError) {
+        let error = self.locate(error);
+        if let Some(ref mut diagnostics) = self.diagnostics {
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                error,
+            });
+        }
+    }
+
+    /// Rejects an attribute that the current element does not model in strict
+    /// mode, or warns about it when recovering.
+    pub fn check_unknown_attribute(
+        &mut self,
+        attribute: &OwnedAttribute,
+    ) -> Result<(), DeserializeError> {
+        if !is_manifest_namespace(attribute.name.namespace.as_deref()) {
+            return Ok(());
+        }
+        let element = self.path.last().map_or("", |segment| segment.name.as_str());
+        let mut error = self.error(
+            ErrorKind::UnknownField,
+            format!("<{element}> has no such attribute"),
+        );
+        error.attribute = Some(attribute.name.borrow().to_repr());
+        error.value = Some(attribute.value.clone());
+        self.unknown_field(error)
+    }
+
+    /// Rejects the element that was just started because its parent does not
+    /// model it in strict mode, or warns about it when recovering.
+    pub fn check_unknown_element(&mut self, name: &OwnedName) -> Result<(), DeserializeError> {
+        if !is_manifest_namespace(name.namespace.as_deref()) {
+            return Ok(());
+        }
+        let error = self.error(
+            ErrorKind::UnknownField,
+            format!("unknown element <{}>", name.borrow().to_repr()),
+        );
+        self.unknown_field(error)
+    }
+
+    /// Rejects a value that an attribute enum captured in its `Unknown` variant
+    /// in strict mode, or warns about it when recovering.
+    #[allow(dead_code)] // Used by generated code for models with enum attributes.
+    pub fn check_unknown_value(
+        &mut self,
+        name: &OwnedName,
+        value: &str,
+    ) -> Result<(), DeserializeError> {
+        let error = self.attribute_error(name, value, "unknown value, kept as written");
+        self.unknown_field(error)
+    }
+
+    fn unknown_field(&mut self, error: DeserializeError) -> Result<(), DeserializeError> {
+        if self.options.strict {
+            self.recover(error)
+        } else {
+            self.warn(error);
+            Ok(())
+        }
+    }
+
+    /// Fills in the location of errors raised without one.
+    pub fn locate(&self, mut error: DeserializeError) -> DeserializeError {
+        if error.path.is_none() && !self.path.is_empty() {
+            error.path = Some(self.path());
+        }
+        if error.position.is_none() {
+            error.position = Some(to_position(self.position));
+        }
+        error
+    }
+
+    #[allow(dead_code)] // Used by generated code for models without an extras field.
+    pub fn skip_element(&mut self, mut cb: impl FnMut(&XmlEvent)) -> Result<(), DeserializeError> {
         let depth = self.depth;
 
         while self.depth >= depth {
@@ -106,28 +349,48 @@ impl<R: Read> Deserializer<R> {
     }
 
     #[allow(dead_code)] // Used by generated code for nested value forms.
-    pub fn read_inner_value<T, F: FnOnce(&mut Self) -> Result<T, String>>(
+    pub fn read_inner_value<T, F: FnOnce(&mut Self) -> Result<T, DeserializeError>>(
         &mut self,
         f: F,
-    ) -> Result<T, String> {
+    ) -> Result<T, DeserializeError> {
         if let XmlEvent::StartElement { name, .. } = self.next_event()? {
             let result = f(self)?;
             self.expect_end_element(&name)?;
             Ok(result)
         } else {
-            Err("expected a start element".to_string())
+            Err(self.error(ErrorKind::Syntax, "expected a start element"))
         }
     }
 
     #[allow(dead_code)] // Used by generated code for nested value forms.
-    pub fn expect_end_element(&mut self, start_name: &OwnedName) -> Result<(), String> {
+    pub fn expect_end_element(&mut self, start_name: &OwnedName) -> Result<(), DeserializeError> {
         match self.next_event()? {
             XmlEvent::EndElement { name } if name == *start_name => Ok(()),
-            XmlEvent::EndElement { name } => Err(format!(
-                "end tag </{}> did not match start tag <{}>",
-                name.local_name, start_name.local_name
+            XmlEvent::EndElement { name } => Err(self.error(
+                ErrorKind::Syntax,
+                format!(
+                    "end tag </{}> did not match start tag <{}>",
+                    name.local_name, start_name.local_name
+                ),
+            )),
+            _ => Err(self.error(
+                ErrorKind::Syntax,
+                format!("expected end tag </{}>", start_name.local_name),
             )),
-            _ => Err(format!("expected end tag </{}>", start_name.local_name)),
         }
     }
 }
+
+fn is_manifest_namespace(namespace: Option<&str>) -> bool {
+    matches!(
+        namespace,
+        None | Some("") | Some(ANDROID_NAMESPACE) | Some(TOOLS_NAMESPACE)
+    )
+}
+
+fn to_position(position: TextPosition) -> Position {
+    Position {
+        line: u32::try_from(position.row + 1).unwrap_or(u32::MAX),
+        column: u32::try_from(position.column + 1).unwrap_or(u32::MAX),
+    }
+}
@@ -5,7 +5,28 @@ pub(crate) mod de;
 pub(crate) mod ser;
 
 pub(crate) trait XmlDeserialize: Sized {
-    fn deserialize<R: Read>(reader: &mut de::Deserializer<R>) -> Result<Self, String>;
+    fn deserialize<R: Read>(
+        reader: &mut de::Deserializer<R>,
+    ) -> Result<Self, crate::error::DeserializeError>;
+
+    /// Values that were parsed into the `Unknown` variant of an attribute enum,
+    /// which the deserializer reports once the attribute is read.
+    fn unknown_values(&self) -> Vec<&str> {
+        Vec::new()
+    }
+}
+
+/// A value that can hold one the crate doesn't recognise, such as an attribute
+/// enum with an `Unknown` variant. Lets values parsed with serde, like the
+/// segments of a list, report unknown values too.
+pub(crate) trait KnownValue {
+    fn unknown_value(&self) -> Option<&str>;
+}
+
+impl KnownValue for String {
+    fn unknown_value(&self) -> Option<&str> {
+        None
+    }
 }
 
 pub(crate) trait XmlSerialize: Sized {
@@ -1,6 +1,8 @@
 //! Internal Android manifest XML serialization.
 
 use super::XmlSerialize;
+use crate::extras::{ExtraElement, ExtraPosition};
+use std::collections::HashMap;
 use std::io::{Cursor, Write};
 use xml::reader::XmlEvent as ReaderEvent;
 use xml::writer::XmlEvent;
@@ -63,10 +65,21 @@ pub fn serialize_with_writer_content<W: Write, T: XmlSerialize>(
     Ok(serializer.into_inner())
 }
 
+/// An element the serializer is currently inside of.
+#[derive(Default)]
+struct OpenElement {
+    name: String,
+    /// Number of child elements written so far, by name.
+    children: HashMap<String, usize>,
+    /// Unknown child elements still to be written, in document order.
+    pending: Vec<ExtraElement>,
+}
+
 pub struct Serializer<W: Write> {
     writer: EventWriter<W>,
     skip_start_end: bool,
     start_event_name: Option<String>,
+    open: Vec<OpenElement>,
 }
 
 impl<W: Write> Serializer<W> {
@@ -75,6 +88,7 @@ impl<W: Write> Serializer<W> {
             writer,
             skip_start_end: false,
             start_event_name: None,
+            open: Vec::new(),
         }
     }
 
@@ -121,7 +135,64 @@ impl<W: Write> Serializer<W> {
     where
         E: Into<XmlEvent<'a>>,
     {
-        self.writer.write(event)
+        let event = event.into();
+        match event {
+            XmlEvent::StartElement { ref name, .. } => {
+                self.open.push(OpenElement {
+                    name: name.local_name.to_string(),
+                    ..Default::default()
+                });
+                self.writer.write(event)
+            }
+            XmlEvent::EndElement { .. } => {
+                self.write_pending(true)?;
+                self.writer.write(event)?;
+                if let Some(closed) = self.open.pop() {
+                    if let Some(parent) = self.open.last_mut() {
+                        *parent.children.entry(closed.name).or_default() += 1;
+                        self.write_pending(false)?;
+                    }
+                }
+                Ok(())
+            }
+            event => self.writer.write(event),
+        }
+    }
+
+    /// Writes unknown child elements of the element being written once the sibling
+    /// each one followed has been written, or right away outside of any element.
+    pub(crate) fn insert_elements(&mut self, elements: &[ExtraElement]) -> Result<(), String> {
+        let result = match self.open.last_mut() {
+            Some(open) => {
+                open.pending.extend(elements.iter().cloned());
+                self.write_pending(false)
+            }
+            None => elements.iter().try_for_each(|element| element.write(self)),
+        };
+        result.map_err(|error| error.to_string())
+    }
+
+    /// Writes the pending unknown children of the current element whose preceding
+    /// sibling has been written, or all of them when it is about to end.
+    fn write_pending(&mut self, all: bool) -> xml::writer::Result<()> {
+        loop {
+            let Some(open) = self.open.last_mut() else {
+                return Ok(());
+            };
+            let ready = open.pending.iter().position(|element| {
+                all || match &element.position {
+                    Some(ExtraPosition::First) => true,
+                    Some(ExtraPosition::After { name, index }) => {
+                        open.children.get(name).is_some_and(|count| count > index)
+                    }
+                    None => false,
+                }
+            });
+            match ready {
+                Some(ready) => open.pending.remove(ready).write(self)?,
+                None => return Ok(()),
+            }
+        }
     }
 }
 
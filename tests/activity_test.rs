@@ -0,0 +1,77 @@
+use android_manifest::*;
+
+#[test]
+fn test_modern_activity_attributes_round_trip() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <activity android:name=".Game"
+                  android:showWhenLocked="true"
+                  android:turnScreenOn="true"
+                  android:rotationAnimation="seamless"
+                  android:minAspectRatio="1.5"
+                  android:preferMinimalPostProcessing="true"
+                  android:canDisplayOnRemoteDevices="false"
+                  android:enableOnBackInvokedCallback="true"
+                  android:requiredDisplayCategory="automotive"
+                  android:attributionTags="game|leaderboard"
+                  android:allowUntrustedActivityEmbedding="false"
+                  android:knownActivityEmbeddingCerts="@array/embedding_certs"
+                  android:recreateOnConfigChanges="mcc|mnc"
+                  android:configChanges="orientation|screenSize|colorMode|fontWeightAdjustment|grammaticalGender" />
+    </application>
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let activity = &manifest.application.activity[0];
+    assert!(activity.extras.is_empty());
+    assert_eq!(activity.show_when_locked, Some(true.into()));
+    assert_eq!(activity.turn_screen_on, Some(true.into()));
+    assert_eq!(
+        activity.rotation_animation,
+        Some(RotationAnimation::Seamless)
+    );
+    assert_eq!(activity.min_aspect_ratio, Some(1.5));
+    assert_eq!(
+        activity.required_display_category.as_deref(),
+        Some("automotive")
+    );
+    assert_eq!(activity.attribution_tags.vec(), &["game", "leaderboard"]);
+    assert_eq!(
+        activity.recreate_on_config_changes.vec(),
+        &[RecreateOnConfigChanges::Mcc, RecreateOnConfigChanges::Mnc]
+    );
+    assert!(
+        activity
+            .config_changes
+            .vec()
+            .iter()
+            .all(ConfigChanges::is_known)
+    );
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert!(written.contains(r#"android:attributionTags="game|leaderboard""#));
+    assert!(written.contains(r#"android:rotationAnimation="seamless""#));
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_empty_enum_attributes_are_an_error() {
+    for attribute in ["rotationAnimation", "recreateOnConfigChanges"] {
+        let xml = format!(
+            r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <activity android:name=".Main" android:{attribute}="" />
+    </application>
+</manifest>"#
+        );
+
+        let error = from_str(&xml).expect_err("Parse should fail");
+        assert!(
+            error.to_string().contains(&format!("android:{attribute}")),
+            "{error}"
+        );
+    }
+}
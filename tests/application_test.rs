@@ -0,0 +1,91 @@
+use android_manifest::*;
+
+#[test]
+fn test_modern_application_attributes_round_trip() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application android:localeConfig="@xml/locales_config"
+                 android:dataExtractionRules="@xml/data_extraction_rules"
+                 android:enableOnBackInvokedCallback="true"
+                 android:memtagMode="async"
+                 android:appCategory="productivity"
+                 android:allowAudioPlaybackCapture="false"
+                 android:preserveLegacyExternalStorage="true"
+                 android:requestRawExternalStorageAccess="true"
+                 android:crossProfile="true"
+                 android:autoRevokePermissions="discouraged"
+                 android:zygotePreloadName=".Preload"
+                 android:usesNonSdkApi="false"
+                 android:pageSizeCompat="enabled"
+                 android:allowCrossUidActivitySwitchFromBelow="false" />
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let application = &manifest.application;
+    assert!(application.extras.is_empty());
+    assert_eq!(
+        application.locale_config,
+        Some(Resource::new("locales_config"))
+    );
+    assert_eq!(
+        application.data_extraction_rules,
+        Some(Resource::new("data_extraction_rules"))
+    );
+    assert_eq!(application.memtag_mode, Some(MemtagMode::Async));
+    assert_eq!(application.app_category, Some(AppCategory::Productivity));
+    assert_eq!(
+        application.auto_revoke_permissions,
+        Some(AutoRevokePermissions::Discouraged)
+    );
+    assert_eq!(application.page_size_compat, Some(PageSizeCompat::Enabled));
+    assert_eq!(
+        application.enable_on_back_invoked_callback,
+        Some(true.into())
+    );
+    assert_eq!(
+        application.allow_cross_uid_activity_switch_from_below,
+        Some(false.into())
+    );
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert!(written.contains(r#"android:localeConfig="@xml/locales_config""#));
+    assert!(written.contains(r#"android:memtagMode="async""#));
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_locale_config_must_be_an_xml_resource() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application android:localeConfig="@string/locales" />
+</manifest>"#;
+
+    let error = from_str(xml).expect_err("Parse should fail");
+    assert!(
+        error.to_string().contains("android:localeConfig"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_empty_enum_attributes_are_an_error() {
+    for attribute in [
+        "appCategory",
+        "autoRevokePermissions",
+        "memtagMode",
+        "pageSizeCompat",
+    ] {
+        let xml = format!(
+            r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application android:{attribute}="" />
+</manifest>"#
+        );
+
+        let error = from_str(&xml).expect_err("Parse should fail");
+        assert!(
+            error.to_string().contains(&format!("android:{attribute}")),
+            "{error}"
+        );
+    }
+}
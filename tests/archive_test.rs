@@ -0,0 +1,111 @@
+use android_manifest::{AndroidManifest, error::Error, from_binary, from_proto, from_str};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+fn write_archive(file_name: &str, entries: &[(&str, &[u8])]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "android-manifest-{}-{file_name}",
+        std::process::id()
+    ));
+    let mut zip = ZipWriter::new(fs::File::create(&path).expect("Failed to create archive"));
+    for (name, bytes) in entries {
+        zip.start_file(*name, SimpleFileOptions::default())
+            .expect("Failed to start archive entry");
+        zip.write_all(bytes).expect("Failed to write archive entry");
+    }
+    zip.finish().expect("Failed to finish archive");
+    path
+}
+
+#[test]
+fn test_read_binary_manifest_from_apk() {
+    let axml = fs::read("tests/test_manifest_binary.axml").expect("Failed to read AXML fixture");
+    let path = write_archive(
+        "app.apk",
+        &[
+            ("classes.dex", b"dex\n035\0"),
+            ("AndroidManifest.xml", &axml),
+        ],
+    );
+
+    let manifest = AndroidManifest::from_archive(&path).expect("Failed to read APK");
+
+    assert_eq!(manifest, from_binary(&axml).unwrap());
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_read_text_manifest_from_aar() {
+    let xml = fs::read_to_string("tests/test_manifest_with_tools.xml")
+        .expect("Failed to read test manifest file");
+    let path = write_archive(
+        "library.aar",
+        &[
+            ("AndroidManifest.xml", xml.as_bytes()),
+            ("classes.jar", b""),
+        ],
+    );
+
+    let manifest = AndroidManifest::from_archive(&path).expect("Failed to read AAR");
+
+    assert_eq!(manifest, from_str(&xml).unwrap());
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_read_manifest_from_bundle_base_module() {
+    let axml = fs::read("tests/test_manifest_binary.axml").expect("Failed to read AXML fixture");
+    let path = write_archive(
+        "app.aab",
+        &[
+            ("BundleConfig.pb", b""),
+            ("base/manifest/AndroidManifest.xml", &axml),
+        ],
+    );
+
+    let manifest = AndroidManifest::from_archive(&path).expect("Failed to read AAB");
+
+    assert_eq!(manifest.package, Some("com.example.testapp".to_string()));
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_archive_without_manifest() {
+    let path = write_archive("empty.apk", &[("classes.dex", b"")]);
+
+    let error = AndroidManifest::from_archive(&path).unwrap_err();
+
+    assert!(matches!(error, Error::FailedToReadArchive(_)));
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_archive_with_oversized_manifest() {
+    let manifest = vec![b' '; 16 * 1024 * 1024 + 1];
+    let path = write_archive("huge.apk", &[("AndroidManifest.xml", &manifest)]);
+
+    let error = AndroidManifest::from_archive(&path).unwrap_err();
+
+    assert!(
+        matches!(error, Error::FailedToReadArchive(ref message) if message.contains("larger than")),
+        "{error}"
+    );
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_read_proto_manifest_from_bundle() {
+    let proto = fs::read("tests/test_manifest_proto.pb").expect("Failed to read proto fixture");
+    let path = write_archive(
+        "proto.aab",
+        &[("base/manifest/AndroidManifest.xml", &proto)],
+    );
+
+    let manifest = AndroidManifest::from_archive(&path).expect("Failed to read AAB");
+
+    assert_eq!(manifest, from_proto(&proto).unwrap());
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,105 @@
+use android_manifest::error::{Error, ErrorKind, Severity};
+use android_manifest::*;
+
+const MANIFEST_WITH_NEW_CONFIG_CHANGE: &str = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <activity android:name=".Main" android:configChanges="orientation|futureChange|screenSize" />
+    </application>
+</manifest>"#;
+
+#[test]
+fn test_unknown_list_segment_is_kept_as_unknown_value() {
+    let manifest = from_str(MANIFEST_WITH_NEW_CONFIG_CHANGE).expect("Failed to parse");
+
+    let config_changes = &manifest.application.activity[0].config_changes;
+    assert_eq!(
+        config_changes.vec(),
+        &[
+            ConfigChanges::Orientation,
+            ConfigChanges::Unknown("futureChange".to_string()),
+            ConfigChanges::ScreenSize
+        ]
+    );
+    assert!(config_changes.unrecognized().is_empty());
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert!(
+        xml.contains(r#"android:configChanges="orientation|futureChange|screenSize""#),
+        "{xml}"
+    );
+}
+
+#[test]
+fn test_empty_list_segment_is_an_error() {
+    let xml = MANIFEST_WITH_NEW_CONFIG_CHANGE.replace("futureChange", "");
+    let Err(Error::FailedToDeserialize(error)) = from_str(&xml) else {
+        panic!("expected a deserialization error");
+    };
+
+    assert_eq!(error.kind, ErrorKind::InvalidValue);
+    assert_eq!(error.attribute.as_deref(), Some("android:configChanges"));
+    assert!(
+        error
+            .message
+            .starts_with(r#"empty segment in "|"-separated list"#),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_empty_list_segment_is_an_error_even_when_lenient() {
+    let xml = MANIFEST_WITH_NEW_CONFIG_CHANGE.replace("futureChange", "");
+    let options = ParseOptions {
+        lenient_lists: true,
+        ..Default::default()
+    };
+
+    assert!(from_str_with_options(&xml, &options).is_err());
+}
+
+#[test]
+fn test_lenient_lists_keep_unknown_enum_values_in_their_variant() {
+    let options = ParseOptions {
+        lenient_lists: true,
+        ..Default::default()
+    };
+    let manifest =
+        from_str_with_options(MANIFEST_WITH_NEW_CONFIG_CHANGE, &options).expect("Failed to parse");
+
+    let config_changes = &manifest.application.activity[0].config_changes;
+    assert!(config_changes.unrecognized().is_empty());
+    assert!(
+        config_changes
+            .vec()
+            .contains(&ConfigChanges::Unknown("futureChange".to_string()))
+    );
+    assert_eq!(
+        from_str_with_options(
+            &to_string(&manifest).expect("Failed to serialize"),
+            &options
+        )
+        .expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_unknown_list_segment_is_reported() {
+    let (_, diagnostics) =
+        from_str_recovering(MANIFEST_WITH_NEW_CONFIG_CHANGE, &ParseOptions::default())
+            .expect("Failed to parse");
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(
+        diagnostics[0].error.attribute.as_deref(),
+        Some("android:configChanges")
+    );
+    assert_eq!(diagnostics[0].error.value.as_deref(), Some("futureChange"));
+
+    let options = ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+    assert!(from_str_with_options(MANIFEST_WITH_NEW_CONFIG_CHANGE, &options).is_err());
+}
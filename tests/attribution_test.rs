@@ -0,0 +1,116 @@
+use android_manifest::error::{ErrorKind, Severity};
+use android_manifest::*;
+
+const MANIFEST_WITH_ATTRIBUTIONS: &str = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <attribution android:tag="sharePhotos" android:label="@string/share_photos">
+        <inherit-from android:tag="photoSharing" />
+    </attribution>
+    <attribution android:tag="nearby" android:label="@string/nearby" />
+    <application>
+        <activity android:name=".Share" android:attributionTags="sharePhotos" />
+        <receiver android:name=".Location" android:attributionTags="nearby|location" />
+        <provider android:name=".Photos" android:authorities="com.example.photos" android:attributionTags="sharePhotos" />
+    </application>
+</manifest>"#;
+
+#[test]
+fn test_attributions_round_trip() {
+    let manifest = from_str(MANIFEST_WITH_ATTRIBUTIONS).expect("Failed to parse");
+
+    assert_eq!(manifest.attribution.len(), 2);
+    assert_eq!(manifest.attribution[0].tag, "sharePhotos");
+    assert_eq!(
+        manifest.attribution[0].label,
+        Some(Resource::new("share_photos"))
+    );
+    assert_eq!(manifest.attribution[0].inherit_from[0].tag, "photoSharing");
+    assert!(manifest.attribution[1].inherit_from.is_empty());
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert!(xml.contains(r#"<inherit-from android:tag="photoSharing""#));
+    assert_eq!(
+        from_str(&xml).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_validate_reports_undeclared_and_duplicate_attribution_tags() {
+    let xml = MANIFEST_WITH_ATTRIBUTIONS.replace(
+        r#"<attribution android:tag="nearby" android:label="@string/nearby" />"#,
+        r#"<attribution android:tag="nearby" android:label="@string/nearby" />
+    <attribution android:tag="nearby" android:label="@string/nearby_again" />"#,
+    );
+    let manifest = from_str(&xml).expect("Failed to parse");
+
+    let found: Vec<_> = manifest
+        .validate()
+        .into_iter()
+        .map(|diagnostic| {
+            assert_eq!(diagnostic.severity, Severity::Error);
+            (
+                diagnostic.error.kind,
+                diagnostic.error.path.unwrap_or_default(),
+                diagnostic.error.value.unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                ErrorKind::Inconsistent,
+                "manifest/attribution[2]".to_string(),
+                "nearby".to_string()
+            ),
+            (
+                ErrorKind::Inconsistent,
+                "manifest/application/receiver[0]".to_string(),
+                "location".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_accepts_consistent_attribution_tags() {
+    let xml = MANIFEST_WITH_ATTRIBUTIONS.replace("nearby|location", "nearby");
+    let manifest = from_str(&xml).expect("Failed to parse");
+
+    assert_eq!(manifest.validate(), []);
+}
+
+#[test]
+fn test_validate_reports_missing_label_and_long_tag() {
+    // 50 two-byte characters are within the limit.
+    let tag = "é".repeat(50);
+    let xml = MANIFEST_WITH_ATTRIBUTIONS
+        .replace("nearby|location", "nearby")
+        .replace(r#" android:label="@string/nearby""#, "")
+        .replace("sharePhotos", &tag);
+    let manifest = from_str(&xml).expect("Failed to parse");
+
+    let found: Vec<_> = manifest
+        .validate()
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.error.kind,
+                diagnostic.error.path.unwrap_or_default(),
+                diagnostic.error.attribute.unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [(
+            ErrorKind::MissingField,
+            "manifest/attribution[1]".to_string(),
+            "android:label".to_string()
+        )]
+    );
+
+    let xml = xml.replace(&tag, &format!("{tag}é"));
+    let manifest = from_str(&xml).expect("Failed to parse");
+    assert_eq!(manifest.validate()[0].error.kind, ErrorKind::InvalidValue);
+}
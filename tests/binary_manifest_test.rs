@@ -0,0 +1,190 @@
+use android_manifest::*;
+use std::fs;
+
+#[test]
+fn test_decode_binary_manifest() {
+    let bytes = fs::read("tests/test_manifest_binary.axml").expect("Failed to read AXML fixture");
+
+    let manifest = from_binary(&bytes).expect("Failed to decode binary manifest");
+
+    assert_eq!(manifest.package, Some("com.example.testapp".to_string()));
+    assert_eq!(manifest.version_code, Some(7));
+    assert_eq!(manifest.version_name, Some("1.7".to_string()));
+    assert_eq!(manifest.ignore, Some("MissingVersion".to_string()));
+
+    let uses_sdk = manifest.uses_sdk.as_ref().expect("uses-sdk not found");
+    assert_eq!(uses_sdk.min_sdk_version, Some(21));
+    assert_eq!(uses_sdk.target_sdk_version, Some(33));
+    assert_eq!(
+        manifest.uses_permission[0].name,
+        Some("android.permission.INTERNET".to_string())
+    );
+
+    let app = &manifest.application;
+    assert_eq!(app.name, Some(".MyApplication".to_string()));
+    assert_eq!(app.allow_backup, Some(true.into()));
+    assert_eq!(
+        app.icon,
+        Some(MipmapOrDrawableResource::Mipmap(Resource::from_id(
+            0x7f0d0000
+        )))
+    );
+    assert_eq!(
+        app.label,
+        Some(StringResourceOrString::StringResource(Resource::from_id(
+            0x7f100000
+        )))
+    );
+    assert_eq!(
+        app.theme.as_ref().and_then(|theme| theme.id()),
+        Some(0x7f110000)
+    );
+
+    let activity = &app.activity[0];
+    assert_eq!(activity.exported, Some(true.into()));
+    assert_eq!(
+        activity.screen_orientation,
+        Some(ScreenOrientation::Portrait)
+    );
+    assert_eq!(
+        activity.config_changes,
+        AttributeList::from(vec![
+            ConfigChanges::KeyboardHidden,
+            ConfigChanges::Orientation,
+            ConfigChanges::ScreenSize,
+        ])
+    );
+    assert_eq!(
+        activity.window_soft_input_mode,
+        AttributeList::from(vec![
+            WindowSoftInputMode::StateHidden,
+            WindowSoftInputMode::AdjustResize,
+        ])
+    );
+    assert_eq!(activity.intent_filter.len(), 1);
+
+    let provider = &app.provider[0];
+    assert_eq!(provider.exported, Some(false.into()));
+    assert_eq!(provider.grant_uri_permissions, Some(true.into()));
+    assert_eq!(
+        provider.meta_data[0].resource,
+        Some(AnyResource::Xml(Resource::from_id(0x7f130000)))
+    );
+    assert_eq!(app.meta_data[0].value, Some("@0x7f0a0000".to_string()));
+}
+
+#[test]
+fn test_decoded_resource_ids_serialize_as_references() {
+    let bytes = fs::read("tests/test_manifest_binary.axml").expect("Failed to read AXML fixture");
+    let manifest = from_binary(&bytes).expect("Failed to decode binary manifest");
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+
+    assert!(xml.contains(r#"android:icon="@0x7f0d0000""#));
+    assert_eq!(from_str(&xml).expect("Failed to parse XML"), manifest);
+}
+
+#[test]
+fn test_decode_rejects_truncated_binary_manifest() {
+    let bytes = fs::read("tests/test_manifest_binary.axml").expect("Failed to read AXML fixture");
+
+    assert!(from_binary(&bytes[..bytes.len() / 2]).is_err());
+    assert!(from_binary(b"<manifest/>").is_err());
+}
+
+#[test]
+fn test_decode_rejects_oversized_string_pool_count() {
+    let mut bytes = Vec::new();
+    // Document chunk: type, header size, total size.
+    bytes.extend_from_slice(&[0x03, 0x00, 0x08, 0x00, 48, 0, 0, 0]);
+    // String pool chunk claiming u32::MAX strings in a 12-byte body.
+    bytes.extend_from_slice(&[0x01, 0x00, 0x1c, 0x00, 40, 0, 0, 0]);
+    bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+    bytes.extend_from_slice(&[0; 4]); // style count
+    bytes.extend_from_slice(&[0; 4]); // flags
+    bytes.extend_from_slice(&28u32.to_le_bytes()); // strings start
+    bytes.extend_from_slice(&[0; 4]); // styles start
+    bytes.extend_from_slice(&[0; 12]);
+    assert_eq!(bytes.len(), 48);
+
+    let error = from_binary(&bytes).unwrap_err();
+    assert!(error.to_string().contains("string pool"), "{error}");
+}
+
+#[test]
+fn test_encode_binary_manifest_round_trip() {
+    let xml_content = fs::read_to_string("tests/test_manifest_with_tools.xml")
+        .expect("Failed to read test manifest file");
+    let manifest = from_str(&xml_content).expect("Failed to parse manifest");
+    let resources: ResourceIdMap = [
+        ("@mipmap/ic_launcher", 0x7f0d0000),
+        ("@string/app_name", 0x7f100000),
+        ("@style/AppTheme", 0x7f110000),
+        ("@integer/google_play_services_version", 0x7f0a0000),
+    ]
+    .into_iter()
+    .collect();
+
+    let bytes = to_binary(&manifest, &resources).expect("Failed to encode binary manifest");
+    let decoded = from_binary(&bytes).expect("Failed to decode binary manifest");
+
+    let mut expected = manifest.clone();
+    expected.application.icon = Some(MipmapOrDrawableResource::Mipmap(Resource::from_id(
+        0x7f0d0000,
+    )));
+    expected.application.label = Some(StringResourceOrString::StringResource(Resource::from_id(
+        0x7f100000,
+    )));
+    expected.application.theme = Some(Resource::from_id(0x7f110000));
+    expected.application.activity[0].theme = Some(Resource::from_id(0x7f110000));
+    expected.application.meta_data[0].value = Some("@0x7f0a0000".to_string());
+    assert_eq!(decoded, expected);
+}
+
+#[test]
+fn test_encode_typed_values_round_trip() {
+    let manifest = AndroidManifest {
+        package: Some("com.example.typed".to_string()),
+        version_code: Some(42),
+        application: Application {
+            debuggable: Some(false.into()),
+            activity: vec![Activity {
+                name: ".MainActivity".to_string(),
+                launch_mode: Some(LaunchMode::SingleTask),
+                max_aspect_ratio: Some(2.5),
+                config_changes: AttributeList::from(vec![
+                    ConfigChanges::Orientation,
+                    ConfigChanges::ScreenSize,
+                ]),
+                layout: Some(Layout {
+                    default_width: Some("600dip".to_string()),
+                    min_height: Some("50%".to_string()),
+                    gravity: Gravity::Top,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let bytes = to_binary(&manifest, &ResourceIdMap::new()).expect("Failed to encode");
+
+    assert_eq!(from_binary(&bytes).expect("Failed to decode"), manifest);
+}
+
+#[test]
+fn test_encode_rejects_unresolved_references() {
+    let manifest = AndroidManifest {
+        application: Application {
+            label: Some(StringResourceOrString::resource("app_name", None)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let error = to_binary(&manifest, &ResourceIdMap::new()).unwrap_err();
+
+    assert!(error.to_string().contains("@string/app_name"));
+}
@@ -0,0 +1,160 @@
+use android_manifest::dist::*;
+use android_manifest::*;
+
+const FEATURE_MODULE: &str = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android"
+          xmlns:dist="http://schemas.android.com/apk/distribution"
+          package="com.example" split="camera" android:isFeatureSplit="true">
+    <dist:module dist:instant="false" dist:title="@string/camera_title">
+        <dist:delivery>
+            <dist:install-time>
+                <dist:conditions>
+                    <dist:device-feature dist:name="android.hardware.camera.ar" />
+                    <dist:min-sdk dist:value="24" />
+                    <dist:user-countries dist:exclude="true">
+                        <dist:country dist:code="FR" />
+                        <dist:country dist:code="DE" />
+                    </dist:user-countries>
+                </dist:conditions>
+                <dist:removable dist:value="true" />
+            </dist:install-time>
+            <dist:on-demand />
+        </dist:delivery>
+        <dist:fusing dist:include="true" />
+    </dist:module>
+    <application android:hasCode="false" />
+</manifest>"#;
+
+#[test]
+fn test_dist_module_round_trip() {
+    let manifest = from_str(FEATURE_MODULE).expect("Failed to parse");
+    assert!(manifest.extras.is_empty());
+
+    let module = manifest.module.as_ref().expect("module should be parsed");
+    assert_eq!(module.instant, Some(false.into()));
+    assert_eq!(module.title, Some(Resource::new("camera_title")));
+    assert_eq!(module.fusing.as_ref().unwrap().include, Some(true.into()));
+    let delivery = module.delivery.as_ref().unwrap();
+    assert!(delivery.on_demand.is_some());
+    assert!(delivery.fast_follow.is_none());
+    let install_time = delivery.install_time.as_ref().unwrap();
+    assert_eq!(
+        install_time.removable.as_ref().unwrap().value,
+        Some(true.into())
+    );
+    let conditions = install_time.conditions.as_ref().unwrap();
+    assert_eq!(
+        conditions.device_feature[0].name,
+        "android.hardware.camera.ar"
+    );
+    assert_eq!(conditions.min_sdk.as_ref().unwrap().value, 24);
+    assert!(conditions.max_sdk.is_none());
+    let countries = conditions.user_countries.as_ref().unwrap();
+    assert_eq!(countries.exclude, Some(true.into()));
+    assert_eq!(
+        countries
+            .country
+            .iter()
+            .map(|country| country.code.as_str())
+            .collect::<Vec<_>>(),
+        ["FR", "DE"]
+    );
+    assert!(module.is_conditional_or_on_demand());
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert_eq!(
+        xml.matches(r#"xmlns:dist="http://schemas.android.com/apk/distribution""#)
+            .count(),
+        1,
+        "{xml}"
+    );
+    assert!(xml.contains(r#"<dist:min-sdk dist:value="24""#), "{xml}");
+    assert_eq!(
+        from_str(&xml).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_write_dist_module() {
+    let mut manifest = AndroidManifest {
+        package: Some("com.example".to_string()),
+        split: Some("maps".to_string()),
+        ..Default::default()
+    };
+    manifest.module = Some(Module {
+        title: Some(Resource::new("maps_title")),
+        delivery: Some(Delivery {
+            on_demand: Some(OnDemand::default()),
+            ..Default::default()
+        }),
+        fusing: Some(Fusing {
+            include: Some(false.into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert!(
+        xml.contains(
+            r#"<dist:module xmlns:dist="http://schemas.android.com/apk/distribution" dist:title="@string/maps_title">"#
+        ),
+        "{xml}"
+    );
+    assert!(xml.contains("<dist:on-demand />"), "{xml}");
+    assert_eq!(
+        from_str(&xml).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_editing_dist_module_in_document() {
+    let mut document = ManifestDocument::parse(FEATURE_MODULE).expect("Failed to parse");
+    let module = document.manifest_mut().module.as_mut().unwrap();
+    module.instant = Some(true.into());
+
+    let expected = FEATURE_MODULE.replace(r#"dist:instant="false""#, r#"dist:instant="true""#);
+    assert_eq!(document.to_string().expect("Failed to write"), expected);
+}
+
+#[test]
+fn test_validate_dist_module() {
+    let manifest = from_str(FEATURE_MODULE).expect("Failed to parse");
+    assert_eq!(manifest.validate(), []);
+
+    let xml = FEATURE_MODULE
+        .replace(r#" dist:title="@string/camera_title""#, "")
+        .replace(
+            r#"<dist:min-sdk dist:value="24" />"#,
+            r#"<dist:min-sdk dist:value="24" /><dist:max-sdk dist:value="23" />"#,
+        )
+        .replace(r#"dist:code="DE""#, r#"dist:code="Germany""#);
+    let manifest = from_str(&xml).expect("Failed to parse");
+
+    let found: Vec<_> = manifest
+        .validate()
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.error.path.unwrap_or_default(),
+                diagnostic.error.attribute.unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("manifest/module".to_string(), "dist:title".to_string()),
+            (
+                "manifest/module/delivery/install-time/conditions/max-sdk".to_string(),
+                "dist:value".to_string()
+            ),
+            (
+                "manifest/module/delivery/install-time/conditions/user-countries/country[1]"
+                    .to_string(),
+                "dist:code".to_string()
+            ),
+        ]
+    );
+}
@@ -0,0 +1,113 @@
+use android_manifest::*;
+
+const HAND_WRITTEN_MANIFEST: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<!-- Release builds bump versionCode -->
+<manifest xmlns:android="http://schemas.android.com/apk/res/android"
+    package="com.example.document"
+    android:versionName='1.0'
+    android:versionCode="41">
+
+    <uses-permission android:name="android.permission.INTERNET"/>
+    <uses-permission android:name="android.permission.VIBRATE"/>
+
+    <application android:label="Document"
+                 android:allowBackup="false">
+        <!-- Entry point -->
+        <activity android:name=".MainActivity" android:exported="true" />
+        <activity android:name=".SettingsActivity" />
+    </application>
+</manifest>
+"#;
+
+#[test]
+fn test_unmodified_document_is_written_unchanged() {
+    let document = ManifestDocument::parse(HAND_WRITTEN_MANIFEST).expect("Failed to parse");
+
+    assert_eq!(
+        document.to_string().expect("Failed to write"),
+        HAND_WRITTEN_MANIFEST
+    );
+}
+
+#[test]
+fn test_changed_attribute_only_touches_its_value() {
+    let mut document = ManifestDocument::parse(HAND_WRITTEN_MANIFEST).expect("Failed to parse");
+    let manifest = document.manifest_mut();
+    manifest.version_code = Some(42);
+    manifest.version_name = Some("1.1 \"beta\"".to_string());
+
+    let expected = HAND_WRITTEN_MANIFEST
+        .replace(r#"android:versionCode="41""#, r#"android:versionCode="42""#)
+        .replace(
+            "android:versionName='1.0'",
+            "android:versionName='1.1 \"beta\"'",
+        );
+    assert_eq!(document.to_string().expect("Failed to write"), expected);
+}
+
+#[test]
+fn test_added_and_removed_attributes_follow_layout() {
+    let mut document = ManifestDocument::parse(HAND_WRITTEN_MANIFEST).expect("Failed to parse");
+    let application = &mut document.manifest_mut().application;
+    application.allow_backup = None;
+    application.debuggable = Some(false.into());
+    application.activity[1].exported = Some(false.into());
+
+    let expected = HAND_WRITTEN_MANIFEST
+        .replace(
+            "android:allowBackup=\"false\">",
+            "android:debuggable=\"false\">",
+        )
+        .replace(
+            r#"<activity android:name=".SettingsActivity" />"#,
+            r#"<activity android:name=".SettingsActivity" android:exported="false" />"#,
+        );
+    assert_eq!(document.to_string().expect("Failed to write"), expected);
+}
+
+#[test]
+fn test_added_and_removed_elements_keep_surrounding_text() {
+    let mut document = ManifestDocument::parse(HAND_WRITTEN_MANIFEST).expect("Failed to parse");
+    let manifest = document.manifest_mut();
+    manifest.uses_permission.push(UsesPermission {
+        name: Some("android.permission.CAMERA".to_string()),
+        ..Default::default()
+    });
+    manifest.application.activity.remove(0);
+
+    let expected = HAND_WRITTEN_MANIFEST
+        .replace(
+            "VIBRATE\"/>\n",
+            "VIBRATE\"/>\n    <uses-permission android:name=\"android.permission.CAMERA\"/>\n",
+        )
+        .replace(
+            "\n        <activity android:name=\".MainActivity\" android:exported=\"true\" />",
+            "",
+        );
+    let written = document.to_string().expect("Failed to write");
+    assert_eq!(written, expected);
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        *document.manifest()
+    );
+}
+
+#[test]
+fn test_new_children_of_empty_element_are_indented() {
+    let mut document = ManifestDocument::parse(HAND_WRITTEN_MANIFEST).expect("Failed to parse");
+    document.manifest_mut().application.activity[1]
+        .meta_data
+        .push(MetaData {
+            name: Some("theme".to_string()),
+            value: Some("dark".to_string()),
+            ..Default::default()
+        });
+
+    let expected = HAND_WRITTEN_MANIFEST.replace(
+        r#"<activity android:name=".SettingsActivity" />"#,
+        "<activity android:name=\".SettingsActivity\">
+            <meta-data android:name=\"theme\" android:value=\"dark\" />
+        </activity>",
+    );
+    assert_eq!(document.to_string().expect("Failed to write"), expected);
+}
@@ -0,0 +1,138 @@
+use android_manifest::error::{DeserializeError, Error, ErrorKind};
+
+fn deserialize_error(xml: &str) -> android_manifest::error::DeserializeError {
+    match android_manifest::from_str(xml) {
+        Err(Error::FailedToDeserialize(error)) => *error,
+        other => panic!("expected a deserialization error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_invalid_attribute_reports_path_and_position() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <activity android:name=".First" />
+        <activity-alias android:name=".Alias" android:targetActivity=".First" />
+        <activity android:name=".Second" />
+        <activity android:name=".Third">
+            <intent-filter android:priority="high">
+                <action android:name="android.intent.action.VIEW" />
+            </intent-filter>
+        </activity>
+    </application>
+</manifest>"#;
+
+    let error = deserialize_error(xml);
+    assert_eq!(error.kind, ErrorKind::InvalidValue);
+    assert_eq!(
+        error.path.as_deref(),
+        Some("manifest/application/activity[2]/intent-filter[0]")
+    );
+    assert_eq!(error.attribute.as_deref(), Some("android:priority"));
+    assert_eq!(error.value.as_deref(), Some("high"));
+    assert_eq!(error.position.map(|position| position.line), Some(7));
+    assert!(
+        error
+            .to_string()
+            .starts_with("7:13: invalid value \"high\" of android:priority: "),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_malformed_xml_reports_syntax_position() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <activity android:name=".Main">
+    </application>
+</manifest>"#;
+
+    let error = deserialize_error(xml);
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    assert_eq!(error.position.map(|position| position.line), Some(4));
+}
+
+#[test]
+fn test_error_text_depends_on_kind() {
+    let error = |kind, attribute: Option<&str>, value: Option<&str>, message| {
+        let mut error = DeserializeError::new(kind, message);
+        error.attribute = attribute.map(str::to_string);
+        error.value = value.map(str::to_string);
+        error.path = Some("manifest/application".to_string());
+        error.to_string()
+    };
+
+    assert_eq!(
+        error(
+            ErrorKind::InvalidValue,
+            Some("android:exported"),
+            Some("maybe"),
+            "not a boolean"
+        ),
+        r#"invalid value "maybe" of android:exported: not a boolean (in manifest/application)"#
+    );
+    assert_eq!(
+        error(
+            ErrorKind::UnknownField,
+            Some("tools:node"),
+            Some("remove"),
+            "<application> has no such attribute"
+        ),
+        r#"unknown attribute tools:node="remove": <application> has no such attribute (in manifest/application)"#
+    );
+    assert_eq!(
+        error(
+            ErrorKind::MissingField,
+            Some("android:name"),
+            None,
+            "name is a required field of Service"
+        ),
+        "missing android:name: name is a required field of Service (in manifest/application)"
+    );
+    assert_eq!(
+        error(
+            ErrorKind::Inconsistent,
+            Some("android:tag"),
+            Some("nearby"),
+            "attribution tag is declared more than once"
+        ),
+        r#"android:tag="nearby": attribution tag is declared more than once (in manifest/application)"#
+    );
+    assert_eq!(
+        error(ErrorKind::Syntax, None, None, "unexpected end of stream"),
+        "unexpected end of stream (in manifest/application)"
+    );
+    assert_eq!(
+        error(
+            ErrorKind::Other,
+            Some("android:label"),
+            None,
+            "not a string"
+        ),
+        "android:label: not a string (in manifest/application)"
+    );
+}
+
+#[test]
+fn test_unknown_attribute_error_text() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application android:exproted="true" />
+</manifest>"#;
+    let options = android_manifest::ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+
+    let Err(Error::FailedToDeserialize(error)) =
+        android_manifest::from_str_with_options(xml, &options)
+    else {
+        panic!("expected a deserialization error");
+    };
+    assert_eq!(error.kind, ErrorKind::UnknownField);
+    assert!(
+        error.to_string().starts_with(
+            r#"2:5: unknown attribute android:exproted="true": <application> has no such attribute"#
+        ),
+        "{error}"
+    );
+}
@@ -0,0 +1,186 @@
+use android_manifest::*;
+
+const MANIFEST_WITH_UNKNOWN_CONTENT: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<manifest xmlns:android="http://schemas.android.com/apk/res/android"
+    xmlns:vendor="https://example.com/vendor"
+    package="com.example.extras"
+    android:futureManifestAttribute="1">
+    <vendor:signing scheme="v4">
+        <vendor:key alias="release">abc123</vendor:key>
+    </vendor:signing>
+    <application android:label="Extras" android:futureAppAttribute="true" vendor:flavor="free">
+        <activity android:name=".MainActivity" android:exported="true">
+            <intent-filter>
+                <action android:name="android.intent.action.MAIN" />
+                <category android:name="android.intent.category.LAUNCHER" />
+                <future-filter-element android:value="x" />
+            </intent-filter>
+        </activity>
+        <vendor:tracking enabled="false" />
+    </application>
+</manifest>"#;
+
+#[test]
+fn test_unknown_content_is_captured() {
+    let manifest = from_str(MANIFEST_WITH_UNKNOWN_CONTENT).expect("Failed to parse manifest");
+
+    assert_eq!(manifest.extras.attributes.len(), 1);
+    assert_eq!(
+        manifest.extras.attributes[0].name,
+        "futureManifestAttribute"
+    );
+    assert_eq!(
+        manifest.extras.attributes[0].prefix.as_deref(),
+        Some("android")
+    );
+
+    let signing = &manifest.extras.elements[0];
+    assert_eq!(signing.name, "signing");
+    assert_eq!(
+        signing.namespace.as_deref(),
+        Some("https://example.com/vendor")
+    );
+    let ExtraNode::Element(key) = &signing.children[0] else {
+        panic!("expected a child element");
+    };
+    assert_eq!(key.children, vec![ExtraNode::Text("abc123".to_string())]);
+
+    let app = &manifest.application;
+    assert_eq!(
+        app.extras
+            .attributes
+            .iter()
+            .map(|attribute| attribute.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["futureAppAttribute", "flavor"]
+    );
+    assert_eq!(app.extras.elements[0].name, "tracking");
+    assert_eq!(
+        app.activity[0].intent_filter[0].extras.elements[0].name,
+        "future-filter-element"
+    );
+    assert_eq!(app.activity[0].intent_filter[0].action.len(), 1);
+}
+
+#[test]
+fn test_unknown_content_survives_round_trip() {
+    let manifest = from_str(MANIFEST_WITH_UNKNOWN_CONTENT).expect("Failed to parse manifest");
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+
+    assert!(xml.contains(r#"android:futureManifestAttribute="1""#));
+    assert!(xml.contains(r#"vendor:flavor="free""#));
+    assert!(xml.contains(r#"<vendor:key alias="release">abc123</vendor:key>"#));
+    assert!(xml.contains(r#"<future-filter-element android:value="x""#));
+    assert_eq!(from_str(&xml).expect("Failed to parse XML"), manifest);
+}
+
+#[test]
+fn test_attributes_in_other_namespaces_are_not_mistaken_for_fields() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android"
+    xmlns:vendor="https://example.com/vendor"
+    package="com.example">
+    <application>
+        <activity android:name=".Main" vendor:name=".Vendor" />
+    </application>
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse manifest");
+    let activity = &manifest.application.activity[0];
+    assert_eq!(activity.name, ".Main");
+    assert_eq!(activity.extras.attributes.len(), 1);
+    assert_eq!(
+        activity.extras.attributes[0].prefix.as_deref(),
+        Some("vendor")
+    );
+    assert_eq!(activity.extras.attributes[0].value, ".Vendor");
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert!(xml.contains(r#"android:name=".Main""#), "{xml}");
+    assert!(xml.contains(r#"vendor:name=".Vendor""#), "{xml}");
+    assert_eq!(from_str(&xml).expect("Failed to parse XML"), manifest);
+}
+
+#[test]
+fn test_editing_a_field_keeps_unknown_content() {
+    let mut manifest = from_str(MANIFEST_WITH_UNKNOWN_CONTENT).expect("Failed to parse manifest");
+    let extras = manifest.application.extras.clone();
+
+    manifest.application.label = Some(StringResourceOrString::string("Renamed"));
+    let edited =
+        from_str(&to_string(&manifest).expect("Failed to serialize")).expect("Failed to parse XML");
+
+    assert_eq!(edited.application.extras, extras);
+}
+
+#[test]
+fn test_unknown_elements_are_written_in_place() {
+    let manifest = from_str(MANIFEST_WITH_UNKNOWN_CONTENT).expect("Failed to parse manifest");
+    assert_eq!(
+        manifest.extras.elements[0].position,
+        Some(ExtraPosition::First)
+    );
+    assert_eq!(
+        manifest.application.extras.elements[0].position,
+        Some(ExtraPosition::After {
+            name: "activity".to_string(),
+            index: 0
+        })
+    );
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+
+    let signing = xml.find("<vendor:signing").unwrap();
+    let application = xml.find("<application").unwrap();
+    assert!(signing < application, "{xml}");
+    let activity = xml.find("<activity").unwrap();
+    let tracking = xml.find("<vendor:tracking").unwrap();
+    assert!(activity < tracking, "{xml}");
+    assert_eq!(from_str(&xml).expect("Failed to parse XML"), manifest);
+}
+
+#[test]
+fn test_unknown_elements_between_known_siblings_stay_between_them() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android"
+    xmlns:vendor="https://example.com/vendor"
+    package="com.example">
+    <uses-permission android:name="android.permission.INTERNET" />
+    <vendor:first />
+    <uses-permission android:name="android.permission.CAMERA" />
+    <vendor:second />
+    <vendor:third />
+    <application />
+    <vendor:last />
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse manifest");
+    let written = to_string(&manifest).expect("Failed to serialize");
+
+    // Known children are written in model order, with each unknown element right
+    // after the sibling it followed.
+    for sequence in [
+        r#"<application /><vendor:last"#,
+        r#"android.permission.INTERNET" /><vendor:first"#,
+        r#"android.permission.CAMERA" /><vendor:second"#,
+        r#"" /><vendor:third"#,
+    ] {
+        assert!(written.contains(sequence), "{sequence} in {written}");
+    }
+    assert!(written.find("<vendor:second") < written.find("<vendor:third"));
+
+    let reparsed = from_str(&written).expect("Failed to parse XML");
+    assert_eq!(to_string(&reparsed).expect("Failed to serialize"), written);
+}
+
+#[test]
+fn test_unknown_elements_without_position_are_written_last() {
+    let mut manifest = from_str(MANIFEST_WITH_UNKNOWN_CONTENT).expect("Failed to parse manifest");
+    manifest.extras.elements.push(ExtraElement {
+        name: "added".to_string(),
+        ..Default::default()
+    });
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+
+    assert!(xml.ends_with("<added /></manifest>"), "{xml}");
+}
@@ -0,0 +1,127 @@
+use android_manifest::*;
+
+#[test]
+fn test_protection_level_with_flags_round_trip() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <permission android:name="com.example.permission.MANAGE"
+                android:protectionLevel="signature|privileged|development|appop|installer|preinstalled|role|knownSigner"
+                android:knownCerts="@array/known_certs" />
+    <permission android:name="com.example.permission.LOCATION"
+                android:protectionLevel="dangerous|instant"
+                android:backgroundPermission="com.example.permission.BACKGROUND_LOCATION"
+                android:permissionFlags="hardRestricted|immutablyRestricted" />
+    <permission android:name="com.example.permission.APPOP" android:protectionLevel="appop" />
+    <application />
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let permissions = &manifest.permission;
+    let manage = permissions[0].protection_level.as_ref().unwrap();
+    assert_eq!(manage.base, Some(ProtectionLevelBase::Signature));
+    assert_eq!(
+        manage.flags,
+        [
+            ProtectionFlag::Privileged,
+            ProtectionFlag::Development,
+            ProtectionFlag::Appop,
+            ProtectionFlag::Installer,
+            ProtectionFlag::Preinstalled,
+            ProtectionFlag::Role,
+            ProtectionFlag::KnownSigner
+        ]
+    );
+    assert_eq!(
+        permissions[0].known_certs.as_deref(),
+        Some("@array/known_certs")
+    );
+    assert_eq!(
+        permissions[1].background_permission.as_deref(),
+        Some("com.example.permission.BACKGROUND_LOCATION")
+    );
+    assert_eq!(
+        permissions[1].permission_flags.vec(),
+        &[
+            PermissionFlags::HardRestricted,
+            PermissionFlags::ImmutablyRestricted
+        ]
+    );
+    assert_eq!(
+        permissions[2].protection_level,
+        Some(ProtectionLevel {
+            base: None,
+            flags: vec![ProtectionFlag::Appop],
+        })
+    );
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert!(written.contains(r#"android:protectionLevel="signature|privileged|development|appop|installer|preinstalled|role|knownSigner""#));
+    assert!(written.contains(r#"android:protectionLevel="appop""#));
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_protection_level_keeps_unknown_flags() {
+    let level: ProtectionLevel =
+        serde_plain::from_str("signature|futureFlag").expect("Failed to parse");
+
+    assert_eq!(
+        level,
+        ProtectionLevel::new(ProtectionLevelBase::Signature)
+            .with_flag(ProtectionFlag::Unknown("futureFlag".to_string()))
+    );
+    assert_eq!(level.to_string(), "signature|futureFlag");
+}
+
+#[test]
+fn test_protection_level_without_base_type_round_trip() {
+    let level: ProtectionLevel = serde_plain::from_str("privileged").expect("Failed to parse");
+
+    assert_eq!(level.base, None);
+    assert_eq!(level.effective_base(), ProtectionLevelBase::Normal);
+    assert_eq!(level.flags, [ProtectionFlag::Privileged]);
+    assert_eq!(level.to_string(), "privileged");
+}
+
+#[test]
+fn test_protection_level_keeps_unknown_base_type() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <permission android:name="com.example.permission.FUTURE" android:protectionLevel="futureBase" />
+    <permission android:name="com.example.permission.FUTURE_FLAGGED" android:protectionLevel="futureBase|privileged" />
+    <application />
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let level = manifest.permission[0].protection_level.as_ref().unwrap();
+    assert_eq!(
+        level.base,
+        Some(ProtectionLevelBase::Unknown("futureBase".to_string()))
+    );
+    assert!(level.flags.is_empty());
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert!(
+        written.contains(r#"android:protectionLevel="futureBase""#),
+        "{written}"
+    );
+    assert!(
+        written.contains(r#"android:protectionLevel="futureBase|privileged""#),
+        "{written}"
+    );
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_protection_level_with_two_base_types_is_an_error() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <permission android:name="com.example.permission.MANAGE" android:protectionLevel="signature|dangerous" />
+</manifest>"#;
+
+    let error = from_str(xml).expect_err("Parse should fail");
+    assert!(error.to_string().contains("more than one base"), "{error}");
+}
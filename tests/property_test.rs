@@ -0,0 +1,138 @@
+use android_manifest::*;
+
+#[test]
+fn test_properties_round_trip_on_every_owner() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <property android:name="android.window.PROPERTY_ACTIVITY_EMBEDDING_SPLITS_ENABLED" android:value="true" />
+        <activity android:name=".Main">
+            <property android:name="android.window.PROPERTY_COMPAT_ALLOW_RESIZEABLE_ACTIVITY_OVERRIDES" android:value="false" />
+        </activity>
+        <activity-alias android:name=".Launcher" android:targetActivity=".Main">
+            <property android:name="com.example.ALIAS" android:value="0x10" />
+        </activity-alias>
+        <service android:name=".Sync" android:foregroundServiceType="specialUse">
+            <property android:name="android.app.PROPERTY_SPECIAL_USE_FGS_SUBTYPE" android:value="sync" />
+        </service>
+        <receiver android:name=".Boot">
+            <property android:name="com.example.RECEIVER" android:resource="@xml/receiver_config" />
+        </receiver>
+        <provider android:name=".Files" android:authorities="com.example.files">
+            <property android:name="com.example.PROVIDER" android:value="42" />
+        </provider>
+    </application>
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let application = &manifest.application;
+    assert_eq!(application.property[0].bool_value(), Some(true));
+    assert_eq!(
+        application.activity[0].property[0].bool_value(),
+        Some(false)
+    );
+    assert_eq!(
+        application.activity_alias[0].property[0].int_value(),
+        Some(16)
+    );
+    assert_eq!(
+        application.service[0].property[0]
+            .value
+            .as_ref()
+            .map(PropertyValue::typed),
+        Some(&TypedPropertyValue::String("sync".to_string()))
+    );
+    assert_eq!(application.service[0].property[0].int_value(), None);
+    assert!(application.receiver[0].property[0].resource.is_some());
+    assert_eq!(application.provider[0].property[0].int_value(), Some(42));
+    assert!(application.extras.is_empty());
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert_eq!(written.matches("<property ").count(), 6);
+    assert!(written.contains(r#"android:value="0x10""#), "{written}");
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_property_values_are_typed_like_the_build_tools() {
+    for (text, value) in [
+        ("false", TypedPropertyValue::Boolean(false)),
+        ("-12", TypedPropertyValue::Integer(-12)),
+        ("0X1F", TypedPropertyValue::Integer(31)),
+        ("-0x10", TypedPropertyValue::Integer(-16)),
+        ("0xffffffff", TypedPropertyValue::Integer(-1)),
+        (
+            "#80ff0000",
+            TypedPropertyValue::Integer(0x80ff0000_u32 as i32),
+        ),
+        ("0.75", TypedPropertyValue::Float(0.75)),
+        (
+            "@integer/max_items",
+            TypedPropertyValue::Reference("@integer/max_items".to_string()),
+        ),
+        ("0xg", TypedPropertyValue::String("0xg".to_string())),
+        (
+            "com.example",
+            TypedPropertyValue::String("com.example".to_string()),
+        ),
+    ] {
+        let parsed: PropertyValue = text.parse().expect("Failed to parse");
+        assert_eq!(parsed.typed(), &value, "{text}");
+    }
+}
+
+#[test]
+fn test_property_values_are_written_as_given() {
+    for (text, value) in [
+        ("1.0", TypedPropertyValue::Float(1.0)),
+        ("1e3", TypedPropertyValue::Float(1000.0)),
+        (
+            "#ff0000",
+            TypedPropertyValue::Integer(0xffff0000_u32 as i32),
+        ),
+        ("0x10", TypedPropertyValue::Integer(16)),
+    ] {
+        let xml = format!(
+            r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <property android:name="com.example.VALUE" android:value="{text}" />
+    </application>
+</manifest>"#
+        );
+
+        let manifest = from_str(&xml).expect("Failed to parse");
+        let property_value = manifest.application.property[0].value.as_ref().unwrap();
+        assert_eq!(property_value.typed(), &value, "{text}");
+
+        let written = to_string(&manifest).expect("Failed to serialize");
+        assert!(
+            written.contains(&format!(r#"android:value="{text}""#)),
+            "{written}"
+        );
+        let reparsed = from_str(&written).expect("Failed to parse written XML");
+        assert_eq!(
+            reparsed.application.property[0]
+                .value
+                .as_ref()
+                .unwrap()
+                .typed(),
+            &value
+        );
+    }
+}
+
+#[test]
+fn test_property_value_built_from_a_float_stays_a_float() {
+    let value = PropertyValue::from(TypedPropertyValue::Float(1.0));
+
+    assert_eq!(value.as_str(), "1.0");
+    assert_eq!(
+        value
+            .as_str()
+            .parse::<PropertyValue>()
+            .expect("Failed to parse"),
+        value
+    );
+}
@@ -0,0 +1,113 @@
+use android_manifest::*;
+use std::fs;
+
+#[test]
+fn test_decode_proto_manifest() {
+    let bytes = fs::read("tests/test_manifest_proto.pb").expect("Failed to read proto fixture");
+
+    let manifest = from_proto(&bytes).expect("Failed to decode proto manifest");
+
+    assert_eq!(manifest.package, Some("com.example.testapp".to_string()));
+    assert_eq!(manifest.version_code, Some(7));
+    assert_eq!(manifest.version_name, Some("1.7".to_string()));
+    assert_eq!(manifest.ignore, Some("MissingVersion".to_string()));
+    assert_eq!(
+        manifest
+            .uses_sdk
+            .as_ref()
+            .and_then(|sdk| sdk.min_sdk_version),
+        Some(21)
+    );
+
+    let app = &manifest.application;
+    assert_eq!(app.allow_backup, Some(true.into()));
+    assert_eq!(
+        app.icon,
+        Some(MipmapOrDrawableResource::mipmap("ic_launcher", None))
+    );
+    assert_eq!(
+        app.label,
+        Some(StringResourceOrString::resource("app_name", None))
+    );
+
+    let activity = &app.activity[0];
+    assert_eq!(activity.exported, Some(true.into()));
+    assert_eq!(
+        activity.screen_orientation,
+        Some(ScreenOrientation::Portrait)
+    );
+    assert_eq!(
+        activity.config_changes,
+        AttributeList::from(vec![
+            ConfigChanges::KeyboardHidden,
+            ConfigChanges::Orientation,
+            ConfigChanges::ScreenSize,
+        ])
+    );
+    assert_eq!(activity.intent_filter[0].action.len(), 1);
+    assert_eq!(app.provider[0].exported, Some(false.into()));
+}
+
+#[test]
+fn test_encode_proto_manifest_round_trip() {
+    let xml_content = fs::read_to_string("tests/test_manifest_with_tools.xml")
+        .expect("Failed to read test manifest file");
+    let mut manifest = from_str(&xml_content).expect("Failed to parse manifest");
+    manifest.version_code = Some(0);
+    manifest.application.activity[0].max_aspect_ratio = Some(-1.5);
+    let resources: ResourceIdMap = [
+        ("@mipmap/ic_launcher", 0x7f0d0000),
+        ("@string/app_name", 0x7f100000),
+        ("@style/AppTheme", 0x7f110000),
+        ("@integer/google_play_services_version", 0x7f0a0000),
+    ]
+    .into_iter()
+    .collect();
+
+    let bytes = to_proto(&manifest, &resources).expect("Failed to encode proto manifest");
+
+    assert_eq!(from_proto(&bytes).expect("Failed to decode"), manifest);
+}
+
+#[test]
+fn test_decode_rejects_malformed_proto_manifest() {
+    let bytes = fs::read("tests/test_manifest_proto.pb").expect("Failed to read proto fixture");
+
+    assert!(from_proto(&bytes[..bytes.len() / 2]).is_err());
+    assert!(from_proto(&[]).is_err());
+}
+
+#[test]
+fn test_decode_rejects_overflowing_proto_length() {
+    // A root element whose length varint decodes to u64::MAX.
+    let mut bytes = vec![0x0a];
+    bytes.extend([0xff; 9]);
+    bytes.push(0x01);
+
+    assert!(from_proto(&bytes).is_err());
+}
+
+#[test]
+fn test_decode_rejects_deeply_nested_proto_manifest() {
+    fn length_delimited(field: u8, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![field << 3 | 2];
+        let mut length = payload.len();
+        while length >= 0x80 {
+            bytes.push(length as u8 | 0x80);
+            length >>= 7;
+        }
+        bytes.push(length as u8);
+        bytes.extend(payload);
+        bytes
+    }
+
+    let mut element = length_delimited(3, b"a");
+    for _ in 0..10_000 {
+        let node = length_delimited(1, &element);
+        element = length_delimited(3, b"a");
+        element.extend(length_delimited(5, &node));
+    }
+    let error = from_proto(&length_delimited(1, &element)).unwrap_err();
+
+    assert!(error.to_string().contains("nested"));
+}
@@ -0,0 +1,54 @@
+use android_manifest::*;
+
+const MANIFEST_WITH_GRANTS: &str = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <provider android:name=".Files" android:authorities="com.example.files" android:grantUriPermissions="false">
+            <grant-uri-permission android:pathPrefix="/images/" />
+            <grant-uri-permission android:pathPrefix="/documents/" />
+            <grant-uri-permission android:path="/exports/latest.csv" />
+        </provider>
+        <provider android:name=".Settings" android:authorities="com.example.settings" />
+    </application>
+</manifest>"#;
+
+#[test]
+fn test_multiple_grant_uri_permissions_round_trip_through_xml() {
+    let manifest = from_str(MANIFEST_WITH_GRANTS).expect("Failed to parse");
+
+    let providers = &manifest.application.provider;
+    assert_eq!(
+        providers[0]
+            .grant_uri_permission
+            .iter()
+            .map(|grant| grant.path_prefix.as_deref().or(grant.path.as_deref()))
+            .collect::<Vec<_>>(),
+        [
+            Some("/images/"),
+            Some("/documents/"),
+            Some("/exports/latest.csv")
+        ]
+    );
+    assert!(providers[1].grant_uri_permission.is_empty());
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert_eq!(xml.matches("<grant-uri-permission").count(), 3);
+    assert_eq!(
+        from_str(&xml).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_multiple_grant_uri_permissions_round_trip_through_serde() {
+    let manifest = from_str(MANIFEST_WITH_GRANTS).expect("Failed to parse");
+
+    let json = serde_json::to_value(&manifest).expect("Failed to serialize JSON");
+    let providers = &json["application"]["provider"];
+    assert_eq!(
+        providers[0]["grant_uri_permission"][1]["path_prefix"],
+        "/documents/"
+    );
+    assert!(providers[1].get("grant_uri_permission").is_none());
+    let from_json: AndroidManifest = serde_json::from_value(json).expect("Failed to parse JSON");
+    assert_eq!(from_json, manifest);
+}
@@ -0,0 +1,49 @@
+use android_manifest::*;
+
+#[test]
+fn test_queries_with_repeated_packages_and_intents_round_trip() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <queries>
+        <package android:name="com.example.store" />
+        <package android:name="com.example.services" />
+        <intent>
+            <action android:name="android.intent.action.SEND" />
+            <data android:mimeType="image/jpeg" />
+        </intent>
+        <intent>
+            <action android:name="android.intent.action.VIEW" />
+            <category android:name="android.intent.category.BROWSABLE" />
+            <data android:scheme="https" />
+        </intent>
+        <provider android:authorities="com.example.settings.files" android:name=".Files" />
+    </queries>
+    <application />
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let queries = manifest.queries.as_ref().expect("queries should be parsed");
+    assert_eq!(
+        queries
+            .package
+            .iter()
+            .map(|package| package.name.as_str())
+            .collect::<Vec<_>>(),
+        ["com.example.store", "com.example.services"]
+    );
+    assert_eq!(queries.intent.len(), 2);
+    assert_eq!(
+        queries.intent[1].action[0].name.as_deref(),
+        Some("android.intent.action.VIEW")
+    );
+    assert_eq!(
+        queries.intent[1].category[0].name.as_deref(),
+        Some("android.intent.category.BROWSABLE")
+    );
+    assert!(queries.intent[0].category.is_empty());
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
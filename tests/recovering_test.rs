@@ -0,0 +1,114 @@
+use android_manifest::error::{ErrorKind, Severity};
+use android_manifest::*;
+
+const MANIFEST_WITH_MISTAKES: &str = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application android:label="@string">
+        <activity android:name=".Main" android:launchMode="sometimes" />
+        <activity android:name=".Settings" android:exported="maybe" android:exproted="true" />
+        <service android:exported="false" />
+    </application>
+</manifest>"#;
+
+#[test]
+fn test_recovering_parse_collects_every_problem() {
+    let (manifest, diagnostics) =
+        from_str_recovering(MANIFEST_WITH_MISTAKES, &ParseOptions::default())
+            .expect("Failed to parse");
+
+    let found: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.severity,
+                diagnostic.error.kind,
+                diagnostic.error.path.as_deref().unwrap_or_default(),
+                diagnostic.error.attribute.as_deref().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                Severity::Error,
+                ErrorKind::InvalidValue,
+                "manifest/application",
+                "android:label"
+            ),
+            (
+                Severity::Warning,
+                ErrorKind::InvalidValue,
+                "manifest/application/activity[0]",
+                "android:launchMode"
+            ),
+            (
+                Severity::Error,
+                ErrorKind::InvalidValue,
+                "manifest/application/activity[1]",
+                "android:exported"
+            ),
+            (
+                Severity::Warning,
+                ErrorKind::UnknownField,
+                "manifest/application/activity[1]",
+                "android:exproted"
+            ),
+            (
+                Severity::Error,
+                ErrorKind::MissingField,
+                "manifest/application/service[0]",
+                "android:name"
+            ),
+        ]
+    );
+
+    let application = &manifest.application;
+    assert_eq!(application.label, None);
+    assert_eq!(
+        application.activity[0].launch_mode,
+        Some(LaunchMode::Unknown("sometimes".to_string()))
+    );
+    assert_eq!(application.activity[1].name, ".Settings");
+    assert_eq!(application.activity[1].exported, None);
+    assert_eq!(application.service[0].name, "");
+    assert_eq!(application.service[0].exported, Some(false.into()));
+}
+
+#[test]
+fn test_recovering_parse_reports_unknown_fields_as_errors_in_strict_mode() {
+    let options = ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let (manifest, diagnostics) =
+        from_str_recovering(MANIFEST_WITH_MISTAKES, &options).expect("Failed to parse");
+
+    let unknown = diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic.error.kind == ErrorKind::UnknownField)
+        .expect("unknown attribute should be reported");
+    assert_eq!(unknown.severity, Severity::Error);
+    let launch_mode = diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic.error.attribute.as_deref() == Some("android:launchMode"))
+        .expect("unknown launch mode should be reported");
+    assert_eq!(launch_mode.severity, Severity::Error);
+    assert_eq!(
+        manifest.application.activity[1].extras.attributes[0].name,
+        "exproted"
+    );
+}
+
+#[test]
+fn test_non_recovering_parse_stops_at_first_problem() {
+    let error = from_str(MANIFEST_WITH_MISTAKES).expect_err("Parse should fail");
+
+    assert!(error.to_string().contains("android:label"), "{error}");
+}
+
+#[test]
+fn test_malformed_xml_fails_recovering_parse() {
+    let xml = r#"<manifest package="com.example"><application></manifest>"#;
+
+    assert!(from_str_recovering(xml, &ParseOptions::default()).is_err());
+}
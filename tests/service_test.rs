@@ -0,0 +1,102 @@
+use android_manifest::*;
+
+#[test]
+fn test_foreground_service_types_round_trip() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <uses-permission android:name="android.permission.FOREGROUND_SERVICE" />
+    <application>
+        <service android:name=".CallService" android:foregroundServiceType="camera|microphone|phoneCall" />
+        <service android:name=".SyncService" android:foregroundServiceType="shortService" />
+        <service android:name=".WorkerService" />
+    </application>
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let services = &manifest.application.service;
+    assert_eq!(
+        services[0].foreground_service_type.vec(),
+        &[
+            ForegroundServiceType::Camera,
+            ForegroundServiceType::Microphone,
+            ForegroundServiceType::PhoneCall
+        ]
+    );
+    assert_eq!(
+        services[0]
+            .foreground_service_permissions()
+            .into_iter()
+            .filter_map(|(_, permission)| permission)
+            .collect::<Vec<_>>(),
+        [
+            "android.permission.FOREGROUND_SERVICE_CAMERA",
+            "android.permission.FOREGROUND_SERVICE_MICROPHONE",
+            "android.permission.FOREGROUND_SERVICE_PHONE_CALL"
+        ]
+    );
+    assert_eq!(
+        services[1].foreground_service_permissions(),
+        [(&ForegroundServiceType::ShortService, None)]
+    );
+    assert!(services[2].foreground_service_type.is_empty());
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert!(written.contains(r#"android:foregroundServiceType="camera|microphone|phoneCall""#));
+    assert!(
+        !written
+            .contains(r#"<service android:name=".WorkerService" android:foregroundServiceType"#)
+    );
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_isolated_app_zygote_service_round_trip() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" xmlns:tools="http://schemas.android.com/tools" package="com.example">
+    <instrumentation android:name=".Runner" android:targetPackage="com.example" tools:node="remove" />
+    <application android:zygotePreloadName=".RendererPreload">
+        <activity android:name=".Main" />
+        <activity-alias android:name=".Launcher" android:targetActivity=".Main"
+                        android:banner="@drawable/banner" android:parentActivityName=".Home"
+                        tools:node="remove" />
+        <service android:name=".Renderer" android:isolatedProcess="true" android:useAppZygote="true"
+                 android:externalService="false" android:allowSharedIsolatedProcess="true"
+                 android:stopWithTask="true" />
+        <receiver android:name=".Alarm" android:attributionTags="alarms|reminders" />
+        <provider android:name=".Files" android:authorities="com.example.files"
+                  android:attributionTags="files" android:forceUriPermissions="true" />
+    </application>
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let application = &manifest.application;
+    let renderer = &application.service[0];
+    assert_eq!(renderer.use_app_zygote, Some(true.into()));
+    assert_eq!(renderer.external_service, Some(false.into()));
+    assert_eq!(renderer.allow_shared_isolated_process, Some(true.into()));
+    assert_eq!(renderer.stop_with_task, Some(true.into()));
+    assert!(renderer.extras.is_empty());
+    assert_eq!(
+        application.receiver[0].attribution_tags.vec(),
+        &["alarms", "reminders"]
+    );
+    assert_eq!(application.provider[0].attribution_tags.vec(), &["files"]);
+    assert_eq!(
+        application.provider[0].force_uri_permissions,
+        Some(true.into())
+    );
+    let alias = &application.activity_alias[0];
+    assert_eq!(alias.parent_activity_name.as_deref(), Some(".Home"));
+    assert_eq!(alias.node.as_deref(), Some("remove"));
+    assert!(alias.extras.is_empty());
+    assert_eq!(manifest.instrumentation[0].node.as_deref(), Some("remove"));
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert!(written.contains(r#"android:useAppZygote="true""#));
+    assert!(written.contains(r#"android:attributionTags="alarms|reminders""#));
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
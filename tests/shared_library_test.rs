@@ -0,0 +1,102 @@
+use android_manifest::error::{ErrorKind, Severity};
+use android_manifest::*;
+
+const DIGEST: &str = "9d:4f:11:8e:30:c5:7a:b2:61:0e:4d:f3:c8:92:57:1a:e6:04:bb:7d:28:c1:95:3f:40:da:16:8b:e2:73:5c:09";
+const OTHER_DIGEST: &str = "0A1B2C3D4E5F60718293A4B5C6D7E8F90A1B2C3D4E5F60718293A4B5C6D7E8F9";
+
+fn manifest_with_digest(digest: &str) -> String {
+    format!(
+        r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <uses-package android:packageType="com.example.plugin" android:name="com.example.plugin.maps" android:version="3" android:certDigest="{OTHER_DIGEST}" />
+    <application>
+        <uses-sdk-library android:name="com.example.ads" android:versionMajor="2" android:certDigest="{digest}" />
+        <uses-static-library android:name="com.example.crypto" android:version="7" android:certDigest="{digest}">
+            <additional-certificate android:certDigest="{OTHER_DIGEST}" />
+        </uses-static-library>
+        <static-library android:name="com.example.codecs" android:version="4" android:versionMajor="1" />
+        <apex-system-service android:name="com.android.server.example.ExampleService" android:path="/apex/com.android.example/javalib/service.jar" android:initOrder="-1" android:minSdkVersion="33" />
+    </application>
+</manifest>"#
+    )
+}
+
+#[test]
+fn test_shared_libraries_round_trip() {
+    let manifest = from_str(&manifest_with_digest(DIGEST)).expect("Failed to parse");
+    let application = &manifest.application;
+
+    let sdk = &application.uses_sdk_library[0];
+    assert_eq!(sdk.name, "com.example.ads");
+    assert_eq!(sdk.version_major, Some(2));
+    let digest = sdk.cert_digest.as_ref().expect("digest should be parsed");
+    assert_eq!(digest.as_str(), DIGEST);
+    assert_eq!(digest.to_hex(), DIGEST.replace(':', ""));
+    assert_eq!(digest.as_bytes()[..2], [0x9d, 0x4f]);
+
+    let library = &application.uses_static_library[0];
+    assert_eq!(library.version, Some(7));
+    assert_eq!(
+        library.cert_digests(),
+        [
+            &DIGEST.parse::<CertDigest>().unwrap(),
+            &OTHER_DIGEST.to_lowercase().parse::<CertDigest>().unwrap()
+        ]
+    );
+    let static_library = application.static_library.as_ref().unwrap();
+    assert_eq!(static_library.version_major, Some(1));
+    assert_eq!(application.apex_system_service[0].init_order, Some(-1));
+    assert_eq!(manifest.uses_package[0].version, Some(3));
+    assert!(application.sdk_library.is_none());
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert!(xml.contains(&format!(r#"android:certDigest="{DIGEST}""#)));
+    assert!(xml.contains(&format!(r#"android:certDigest="{OTHER_DIGEST}""#)));
+    assert_eq!(
+        from_str(&xml).expect("Failed to parse written XML"),
+        manifest
+    );
+
+    let json = serde_json::to_value(&manifest).expect("Failed to serialize JSON");
+    assert_eq!(
+        json["application"]["uses_sdk_library"][0]["cert_digest"],
+        DIGEST
+    );
+    let from_json: AndroidManifest = serde_json::from_value(json).expect("Failed to parse JSON");
+    assert_eq!(from_json, manifest);
+}
+
+#[test]
+fn test_invalid_cert_digests_are_rejected() {
+    for digest in [
+        "9d:4f:11",
+        "9d4f:118e:30c5:7ab2:610e:4df3:c892:571a:e604:bb7d:28c1:953f:40da:168b:e273:5c09",
+        &DIGEST.replace("9d", "zz"),
+        &DIGEST.replace(':', "")[1..],
+    ] {
+        let (_, diagnostics) =
+            from_str_recovering(&manifest_with_digest(digest), &ParseOptions::default())
+                .expect("Failed to parse");
+
+        assert_eq!(diagnostics.len(), 2, "{digest}");
+        for diagnostic in diagnostics {
+            assert_eq!(diagnostic.severity, Severity::Error);
+            assert_eq!(diagnostic.error.kind, ErrorKind::InvalidValue);
+            assert_eq!(
+                diagnostic.error.attribute.as_deref(),
+                Some("android:certDigest")
+            );
+        }
+    }
+}
+
+#[test]
+fn test_cert_digest_from_bytes() {
+    let digest = CertDigest::from_bytes(&[0xab; 32]).expect("32 bytes is a SHA-256 digest");
+
+    assert_eq!(digest.as_str(), "ab".repeat(32));
+    assert_eq!(
+        digest,
+        "AB:".repeat(32).trim_end_matches(':').parse().unwrap()
+    );
+    assert!(CertDigest::from_bytes(&[0xab; 20]).is_err());
+}
@@ -0,0 +1,114 @@
+use android_manifest::error::ErrorKind;
+use android_manifest::*;
+
+const BASE: &str = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example"
+          android:isolatedSplits="true" android:requiredSplitTypes="language.base,density.base">
+    <application />
+</manifest>"#;
+
+fn split(attributes: &str, children: &str) -> AndroidManifest {
+    from_str(&format!(
+        r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example" {attributes}>
+    {children}
+    <application />
+</manifest>"#
+    ))
+    .expect("Failed to parse split")
+}
+
+#[test]
+fn test_split_attributes_round_trip() {
+    let base = from_str(BASE).expect("Failed to parse");
+    assert_eq!(base.isolated_splits, Some(true.into()));
+    assert_eq!(
+        base.required_split_types.vec(),
+        &["language.base", "density.base"]
+    );
+    assert_eq!(base.split, None);
+
+    let feature = split(
+        r#"split="camera" android:isFeatureSplit="true""#,
+        r#"<uses-split android:name="media" />"#,
+    );
+    assert_eq!(feature.split.as_deref(), Some("camera"));
+    assert_eq!(feature.is_feature_split, Some(true.into()));
+    assert_eq!(feature.uses_split[0].name, "media");
+    assert!(feature.extras.is_empty());
+
+    let config = split(
+        r#"split="camera.config.xxhdpi" configForSplit="camera" android:splitTypes="density.base""#,
+        "",
+    );
+    assert_eq!(config.config_for_split.as_deref(), Some("camera"));
+    assert_eq!(config.split_types.vec(), &["density.base"]);
+
+    for manifest in [base, feature, config] {
+        let xml = to_string(&manifest).expect("Failed to serialize");
+        assert_eq!(
+            from_str(&xml).expect("Failed to parse written XML"),
+            manifest
+        );
+    }
+    let xml = to_string(&split(r#"split="camera""#, "")).expect("Failed to serialize");
+    assert!(xml.contains(r#" split="camera""#));
+    assert!(!xml.contains("splitTypes"));
+}
+
+#[test]
+fn test_validate_splits() {
+    let base = from_str(BASE).expect("Failed to parse");
+    let splits = [
+        split(
+            r#"split="camera" android:isFeatureSplit="true""#,
+            r#"<uses-split android:name="media" />"#,
+        ),
+        split(
+            r#"split="media" android:isFeatureSplit="true""#,
+            r#"<uses-split android:name="maps" />"#,
+        ),
+        split(r#"split="camera" android:isFeatureSplit="true""#, ""),
+        split(r#"split="maps.config.en" configForSplit="maps""#, ""),
+        split("", ""),
+    ];
+
+    let found: Vec<_> = base
+        .validate_splits(&splits)
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.error.kind,
+                diagnostic.error.path.unwrap_or_default(),
+                diagnostic.error.value.unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                ErrorKind::Inconsistent,
+                "splits[2]/manifest".to_string(),
+                "camera".to_string()
+            ),
+            (
+                ErrorKind::MissingField,
+                "splits[4]/manifest".to_string(),
+                String::new()
+            ),
+            (
+                ErrorKind::Inconsistent,
+                "splits[1]/manifest/uses-split[0]".to_string(),
+                "maps".to_string()
+            ),
+            (
+                ErrorKind::Inconsistent,
+                "splits[3]/manifest".to_string(),
+                "maps".to_string()
+            ),
+        ]
+    );
+
+    assert_eq!(base.validate_splits(&splits[..1]).len(), 1);
+    assert_eq!(base.validate_splits(&splits[..2]).len(), 1);
+    assert_eq!(base.validate_splits(&[]), []);
+}
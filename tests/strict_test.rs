@@ -0,0 +1,102 @@
+use android_manifest::error::{Error, ErrorKind};
+use android_manifest::*;
+
+fn strict() -> ParseOptions {
+    ParseOptions {
+        strict: true,
+        ..Default::default()
+    }
+}
+
+fn strict_error(xml: &str) -> android_manifest::error::DeserializeError {
+    match from_str_with_options(xml, &strict()) {
+        Err(Error::FailedToDeserialize(error)) => *error,
+        other => panic!("expected a deserialization error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_strict_mode_rejects_misspelled_attribute() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <activity android:name=".Main" />
+        <activity android:name=".Settings" android:exproted="true" />
+    </application>
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("lenient parsing should keep the attribute");
+    assert_eq!(
+        manifest.application.activity[1].extras.attributes[0].name,
+        "exproted"
+    );
+
+    let error = strict_error(xml);
+    assert_eq!(error.kind, ErrorKind::UnknownField);
+    assert_eq!(
+        error.path.as_deref(),
+        Some("manifest/application/activity[1]")
+    );
+    assert_eq!(error.attribute.as_deref(), Some("android:exproted"));
+}
+
+#[test]
+fn test_strict_mode_rejects_field_name_in_tools_namespace() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android"
+    xmlns:tools="http://schemas.android.com/tools" package="com.example">
+    <application>
+        <activity android:name=".Main" tools:name=".Other" />
+    </application>
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("lenient parsing should keep the attribute");
+    assert_eq!(manifest.application.activity[0].name, ".Main");
+
+    let error = strict_error(xml);
+    assert_eq!(error.kind, ErrorKind::UnknownField);
+    assert_eq!(error.attribute.as_deref(), Some("tools:name"));
+}
+
+#[test]
+fn test_strict_mode_rejects_misspelled_element() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <activity android:name=".Main">
+            <intent-filters>
+                <action android:name="android.intent.action.MAIN" />
+            </intent-filters>
+        </activity>
+    </application>
+</manifest>"#;
+
+    let error = strict_error(xml);
+    assert_eq!(error.kind, ErrorKind::UnknownField);
+    assert_eq!(
+        error.path.as_deref(),
+        Some("manifest/application/activity[0]/intent-filters")
+    );
+    assert!(error.message.contains("<intent-filters>"));
+}
+
+#[test]
+fn test_strict_mode_keeps_other_namespaces() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android"
+    xmlns:vendor="https://example.com/vendor" package="com.example">
+    <application android:label="Example" vendor:flavor="free">
+        <vendor:tracking enabled="false" />
+    </application>
+</manifest>"#;
+
+    let manifest = from_str_with_options(xml, &strict()).expect("Failed to parse");
+    assert_eq!(manifest.application.extras.attributes.len(), 1);
+    assert_eq!(manifest.application.extras.elements.len(), 1);
+}
+
+#[test]
+fn test_strict_mode_accepts_tools_attributes() {
+    let xml = include_str!("test_manifest_with_tools.xml");
+
+    assert_eq!(
+        from_str_with_options(xml, &strict()).expect("Failed to parse"),
+        from_str(xml).expect("Failed to parse")
+    );
+}
@@ -0,0 +1,126 @@
+use android_manifest::error::{ErrorKind, Severity};
+use android_manifest::*;
+
+const MANIFEST_FROM_THE_FUTURE: &str = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example" android:installLocation="preferCloud">
+    <application android:gwpAsanMode="sampled">
+        <activity android:name=".Main" android:launchMode="singleTaskPerDisplay" android:screenOrientation="fullUser" />
+    </application>
+</manifest>"#;
+
+#[test]
+fn test_unknown_enum_values_round_trip_through_xml() {
+    let manifest = from_str(MANIFEST_FROM_THE_FUTURE).expect("Failed to parse");
+
+    assert_eq!(
+        manifest.install_location,
+        Some(InstallLocation::Unknown("preferCloud".to_string()))
+    );
+    assert_eq!(
+        manifest.application.gwp_asan_mode,
+        Some(GwpAsanMode::Unknown("sampled".to_string()))
+    );
+    let activity = &manifest.application.activity[0];
+    assert_eq!(
+        activity.launch_mode,
+        Some(LaunchMode::Unknown("singleTaskPerDisplay".to_string()))
+    );
+    assert_eq!(
+        activity.screen_orientation,
+        Some(ScreenOrientation::FullUser)
+    );
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert!(xml.contains(r#"android:installLocation="preferCloud""#));
+    assert!(xml.contains(r#"android:gwpAsanMode="sampled""#));
+    assert!(xml.contains(r#"android:launchMode="singleTaskPerDisplay""#));
+    assert_eq!(
+        from_str(&xml).expect("Failed to parse written XML"),
+        manifest
+    );
+}
+
+#[test]
+fn test_unknown_enum_values_round_trip_through_serde() {
+    let manifest = from_str(MANIFEST_FROM_THE_FUTURE).expect("Failed to parse");
+
+    let json = serde_json::to_value(&manifest).expect("Failed to serialize JSON");
+    assert_eq!(json["install_location"], "preferCloud");
+    assert_eq!(
+        json["application"]["activity"][0]["launch_mode"],
+        "singleTaskPerDisplay"
+    );
+    let from_json: AndroidManifest = serde_json::from_value(json).expect("Failed to parse JSON");
+    assert_eq!(from_json, manifest);
+
+    let toml = toml::to_string(&manifest).expect("Failed to serialize TOML");
+    let from_toml: AndroidManifest = toml::from_str(&toml).expect("Failed to parse TOML");
+    assert_eq!(from_toml, manifest);
+}
+
+#[test]
+fn test_unknown_enum_values_are_warnings_when_recovering() {
+    let (manifest, diagnostics) =
+        from_str_recovering(MANIFEST_FROM_THE_FUTURE, &ParseOptions::default())
+            .expect("Failed to parse");
+
+    let found: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.severity,
+                diagnostic.error.kind,
+                diagnostic.error.attribute.as_deref().unwrap_or_default(),
+                diagnostic.error.value.as_deref().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                Severity::Warning,
+                ErrorKind::InvalidValue,
+                "android:installLocation",
+                "preferCloud"
+            ),
+            (
+                Severity::Warning,
+                ErrorKind::InvalidValue,
+                "android:gwpAsanMode",
+                "sampled"
+            ),
+            (
+                Severity::Warning,
+                ErrorKind::InvalidValue,
+                "android:launchMode",
+                "singleTaskPerDisplay"
+            ),
+        ]
+    );
+    assert_eq!(
+        manifest,
+        from_str(MANIFEST_FROM_THE_FUTURE).expect("Failed to parse")
+    );
+}
+
+#[test]
+fn test_unknown_enum_values_are_rejected_in_strict_mode() {
+    let options = ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let error = from_str_with_options(MANIFEST_FROM_THE_FUTURE, &options).unwrap_err();
+
+    assert!(
+        error.to_string().contains("android:installLocation"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_is_known() {
+    assert!(LaunchMode::SingleTask.is_known());
+    assert!(!LaunchMode::Unknown("singleTaskPerDisplay".to_string()).is_known());
+    assert!(ConfigChanges::Orientation.is_known());
+    assert!(!ProtectionFlag::Unknown("futureFlag".to_string()).is_known());
+}
@@ -0,0 +1,53 @@
+use android_manifest::*;
+
+#[test]
+fn test_uri_matching_attributes_round_trip() {
+    let xml = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example">
+    <application>
+        <activity android:name=".Dial" android:exported="true">
+            <intent-filter>
+                <action android:name="android.intent.action.VIEW" />
+                <data android:scheme="tel" android:sspPrefix="+1" android:sspAdvancedPattern="\+1[0-9]+" />
+                <data android:scheme="https" android:host="example.com" android:pathSuffix=".pdf"
+                      android:pathAdvancedPattern="/docs/[a-z]+\.pdf" android:query="download=1"
+                      android:fragmentPrefix="page" />
+            </intent-filter>
+        </activity>
+        <provider android:name=".Files" android:authorities="com.example.files">
+            <grant-uri-permission android:pathSuffix=".jpg" />
+            <path-permission android:pathAdvancedPattern="/shared/[0-9]+" android:readPermission="com.example.READ" />
+        </provider>
+    </application>
+</manifest>"#;
+
+    let manifest = from_str(xml).expect("Failed to parse");
+    let data = &manifest.application.activity[0].intent_filter[0].data;
+    assert_eq!(data[0].ssp_prefix.as_deref(), Some("+1"));
+    assert_eq!(data[0].ssp_advanced_pattern.as_deref(), Some(r"\+1[0-9]+"));
+    assert_eq!(data[1].path_suffix.as_deref(), Some(".pdf"));
+    assert_eq!(
+        data[1].path_advanced_pattern.as_deref(),
+        Some(r"/docs/[a-z]+\.pdf")
+    );
+    assert_eq!(data[1].query.as_deref(), Some("download=1"));
+    assert_eq!(data[1].fragment_prefix.as_deref(), Some("page"));
+    assert!(data[1].extras.is_empty());
+
+    let provider = &manifest.application.provider[0];
+    assert_eq!(
+        provider.grant_uri_permission[0].path_suffix.as_deref(),
+        Some(".jpg")
+    );
+    assert_eq!(
+        provider.path_permission[0].path_advanced_pattern.as_deref(),
+        Some("/shared/[0-9]+")
+    );
+
+    let written = to_string(&manifest).expect("Failed to serialize");
+    assert!(written.contains(r#"android:pathSuffix=".jpg""#));
+    assert!(written.contains(r#"android:sspAdvancedPattern="\+1[0-9]+""#));
+    assert_eq!(
+        from_str(&written).expect("Failed to parse written XML"),
+        manifest
+    );
+}
@@ -0,0 +1,63 @@
+use android_manifest::*;
+
+const BLE_MANIFEST: &str = r#"<manifest xmlns:android="http://schemas.android.com/apk/res/android" package="com.example.ble">
+    <uses-permission android:name="android.permission.BLUETOOTH" android:maxSdkVersion="30" />
+    <uses-permission android:name="android.permission.BLUETOOTH_SCAN" android:usesPermissionFlags="neverForLocation" />
+    <uses-permission android:name="android.permission.BLUETOOTH_CONNECT" android:requiredFeature="android.hardware.bluetooth_le" />
+    <uses-permission-sdk-23 android:name="android.permission.ACCESS_FINE_LOCATION" android:maxSdkVersion="30"
+                            android:requiredNotFeature="android.hardware.type.watch" />
+    <uses-permission-sdk-23 android:name="android.permission.NEARBY_WIFI_DEVICES" android:usesPermissionFlags="neverForLocation|futureFlag" />
+    <application />
+</manifest>"#;
+
+#[test]
+fn test_uses_permission_flags_and_features_round_trip() {
+    let manifest = from_str(BLE_MANIFEST).expect("Failed to parse");
+
+    let permissions = &manifest.uses_permission;
+    assert!(permissions[0].uses_permission_flags.is_empty());
+    assert_eq!(
+        permissions[1].uses_permission_flags.vec(),
+        &[UsesPermissionFlags::NeverForLocation]
+    );
+    assert_eq!(
+        permissions[2].required_feature.as_deref(),
+        Some("android.hardware.bluetooth_le")
+    );
+    assert!(
+        permissions
+            .iter()
+            .all(|permission| permission.extras.is_empty())
+    );
+
+    let sdk_23 = &manifest.uses_permission_sdk_23;
+    assert_eq!(
+        sdk_23[0].required_not_feature.as_deref(),
+        Some("android.hardware.type.watch")
+    );
+    assert_eq!(
+        sdk_23[1].uses_permission_flags.vec(),
+        &[
+            UsesPermissionFlags::NeverForLocation,
+            UsesPermissionFlags::Unknown("futureFlag".to_string())
+        ]
+    );
+    assert!(sdk_23.iter().all(|permission| permission.extras.is_empty()));
+
+    let xml = to_string(&manifest).expect("Failed to serialize");
+    assert!(xml.contains(r#"android:usesPermissionFlags="neverForLocation""#));
+    assert!(xml.contains(r#"android:usesPermissionFlags="neverForLocation|futureFlag""#));
+    assert_eq!(xml.matches("usesPermissionFlags").count(), 2);
+    assert_eq!(
+        from_str(&xml).expect("Failed to parse written XML"),
+        manifest
+    );
+
+    let json = serde_json::to_value(&manifest).expect("Failed to serialize JSON");
+    assert_eq!(
+        json["uses_permission"][1]["uses_permission_flags"],
+        "neverForLocation"
+    );
+    let from_json: AndroidManifest = serde_json::from_value(json).expect("Failed to parse JSON");
+    assert_eq!(from_json, manifest);
+}